@@ -1,10 +1,30 @@
+use aoc2020::input::Sources;
 use aoc2020::*;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use std::fs;
 
+/// Input of the day of the year from the input directory. Set
+/// $AOC_INPUT_DIR to bench other inputs, e.g. the ones from
+/// `generate --output-dir`.
+fn read_input(year: u16, day: u8) -> String {
+    let file = Sources::from_env(None).input_file(year, day).unwrap();
+    fs::read_to_string(file).unwrap()
+}
+
+/// Name of the day in bench ids. Days of the first year keep their names
+/// from before there were other years, so their earlier results still
+/// compare.
+fn day_name(year: u16, day: u8) -> String {
+    if year == FIRST_YEAR {
+        format!("Day {:02}", day)
+    } else {
+        format!("{} Day {:02}", year, day)
+    }
+}
+
 pub fn day01(c: &mut Criterion) {
-    let input = fs::read_to_string("../input/day-01.input").unwrap();
-    let day = Day01 {};
+    let input = read_input(2020, 1);
+    let day = year_2020::Day01 {};
     c.bench_function("Day 01 first task", |b| {
         b.iter(|| day.first_task(black_box(&input)))
     });
@@ -14,8 +34,8 @@ pub fn day01(c: &mut Criterion) {
 }
 
 pub fn day02(c: &mut Criterion) {
-    let input = fs::read_to_string("../input/day-02.input").unwrap();
-    let day = Day02 {};
+    let input = read_input(2020, 2);
+    let day = year_2020::Day02 {};
     c.bench_function("Day 02 first task", |b| {
         b.iter(|| day.first_task(black_box(&input)))
     });
@@ -25,8 +45,8 @@ pub fn day02(c: &mut Criterion) {
 }
 
 pub fn day03(c: &mut Criterion) {
-    let input = fs::read_to_string("../input/day-03.input").unwrap();
-    let day = Day03 {};
+    let input = read_input(2020, 3);
+    let day = year_2020::Day03 {};
     c.bench_function("Day 03 first task", |b| {
         b.iter(|| day.first_task(black_box(&input)))
     });
@@ -36,8 +56,8 @@ pub fn day03(c: &mut Criterion) {
 }
 
 pub fn day04(c: &mut Criterion) {
-    let input = fs::read_to_string("../input/day-04.input").unwrap();
-    let day = Day04 {};
+    let input = read_input(2020, 4);
+    let day = year_2020::Day04 {};
     c.bench_function("Day 04 first task", |b| {
         b.iter(|| day.first_task(black_box(&input)))
     });
@@ -47,8 +67,8 @@ pub fn day04(c: &mut Criterion) {
 }
 
 pub fn day05(c: &mut Criterion) {
-    let input = fs::read_to_string("../input/day-05.input").unwrap();
-    let day = Day05 {};
+    let input = read_input(2020, 5);
+    let day = year_2020::Day05 {};
     c.bench_function("Day 05 first task", |b| {
         b.iter(|| day.first_task(black_box(&input)))
     });
@@ -58,8 +78,8 @@ pub fn day05(c: &mut Criterion) {
 }
 
 pub fn day06(c: &mut Criterion) {
-    let input = fs::read_to_string("../input/day-06.input").unwrap();
-    let day = Day06 {};
+    let input = read_input(2020, 6);
+    let day = year_2020::Day06 {};
     c.bench_function("Day 06 first task", |b| {
         b.iter(|| day.first_task(black_box(&input)))
     });
@@ -69,8 +89,8 @@ pub fn day06(c: &mut Criterion) {
 }
 
 pub fn day07(c: &mut Criterion) {
-    let input = fs::read_to_string("../input/day-07.input").unwrap();
-    let day = Day07 {};
+    let input = read_input(2020, 7);
+    let day = year_2020::Day07 {};
     c.bench_function("Day 07 first task", |b| {
         b.iter(|| day.first_task(black_box(&input)))
     });
@@ -80,8 +100,8 @@ pub fn day07(c: &mut Criterion) {
 }
 
 pub fn day08(c: &mut Criterion) {
-    let input = fs::read_to_string("../input/day-08.input").unwrap();
-    let day = Day08 {};
+    let input = read_input(2020, 8);
+    let day = year_2020::Day08 {};
     c.bench_function("Day 08 first task", |b| {
         b.iter(|| day.first_task(black_box(&input)))
     });
@@ -91,8 +111,8 @@ pub fn day08(c: &mut Criterion) {
 }
 
 pub fn day09(c: &mut Criterion) {
-    let input = fs::read_to_string("../input/day-09.input").unwrap();
-    let day = Day09 {};
+    let input = read_input(2020, 9);
+    let day = year_2020::Day09 {};
     c.bench_function("Day 09 first task", |b| {
         b.iter(|| day.first_task(black_box(&input)))
     });
@@ -102,8 +122,8 @@ pub fn day09(c: &mut Criterion) {
 }
 
 pub fn day10(c: &mut Criterion) {
-    let input = fs::read_to_string("../input/day-10.input").unwrap();
-    let day = Day10 {};
+    let input = read_input(2020, 10);
+    let day = year_2020::Day10 {};
     c.bench_function("Day 10 first task", |b| {
         b.iter(|| day.first_task(black_box(&input)))
     });
@@ -113,8 +133,8 @@ pub fn day10(c: &mut Criterion) {
 }
 
 pub fn day11(c: &mut Criterion) {
-    let input = fs::read_to_string("../input/day-11.input").unwrap();
-    let day = Day11 {};
+    let input = read_input(2020, 11);
+    let day = year_2020::Day11 {};
     c.bench_function("Day 11 first task", |b| {
         b.iter(|| day.first_task(black_box(&input)))
     });
@@ -124,8 +144,8 @@ pub fn day11(c: &mut Criterion) {
 }
 
 pub fn day12(c: &mut Criterion) {
-    let input = fs::read_to_string("../input/day-12.input").unwrap();
-    let day = Day12 {};
+    let input = read_input(2020, 12);
+    let day = year_2020::Day12 {};
     c.bench_function("Day 12 first task", |b| {
         b.iter(|| day.first_task(black_box(&input)))
     });
@@ -135,8 +155,8 @@ pub fn day12(c: &mut Criterion) {
 }
 
 pub fn day13(c: &mut Criterion) {
-    let input = fs::read_to_string("../input/day-13.input").unwrap();
-    let day = Day13 {};
+    let input = read_input(2020, 13);
+    let day = year_2020::Day13 {};
     c.bench_function("Day 13 first task", |b| {
         b.iter(|| day.first_task(black_box(&input)))
     });
@@ -146,8 +166,8 @@ pub fn day13(c: &mut Criterion) {
 }
 
 pub fn day14(c: &mut Criterion) {
-    let input = fs::read_to_string("../input/day-14.input").unwrap();
-    let day = Day14 {};
+    let input = read_input(2020, 14);
+    let day = year_2020::Day14 {};
     c.bench_function("Day 14 first task", |b| {
         b.iter(|| day.first_task(black_box(&input)))
     });
@@ -157,8 +177,8 @@ pub fn day14(c: &mut Criterion) {
 }
 
 pub fn day15(c: &mut Criterion) {
-    let input = fs::read_to_string("../input/day-15.input").unwrap();
-    let day = Day15 {};
+    let input = read_input(2020, 15);
+    let day = year_2020::Day15 {};
     c.bench_function("Day 15 first task", |b| {
         b.iter(|| day.first_task(black_box(&input)))
     });
@@ -168,8 +188,8 @@ pub fn day15(c: &mut Criterion) {
 }
 
 pub fn day16(c: &mut Criterion) {
-    let input = fs::read_to_string("../input/day-16.input").unwrap();
-    let day = Day16 {};
+    let input = read_input(2020, 16);
+    let day = year_2020::Day16 {};
     c.bench_function("Day 16 first task", |b| {
         b.iter(|| day.first_task(black_box(&input)))
     });
@@ -179,8 +199,8 @@ pub fn day16(c: &mut Criterion) {
 }
 
 pub fn day17(c: &mut Criterion) {
-    let input = fs::read_to_string("../input/day-17.input").unwrap();
-    let day = Day17 {};
+    let input = read_input(2020, 17);
+    let day = year_2020::Day17 {};
     c.bench_function("Day 17 first task", |b| {
         b.iter(|| day.first_task(black_box(&input)))
     });
@@ -190,8 +210,8 @@ pub fn day17(c: &mut Criterion) {
 }
 
 pub fn day18(c: &mut Criterion) {
-    let input = fs::read_to_string("../input/day-18.input").unwrap();
-    let day = Day18 {};
+    let input = read_input(2020, 18);
+    let day = year_2020::Day18 {};
     c.bench_function("Day 18 first task", |b| {
         b.iter(|| day.first_task(black_box(&input)))
     });
@@ -201,8 +221,8 @@ pub fn day18(c: &mut Criterion) {
 }
 
 pub fn day19(c: &mut Criterion) {
-    let input = fs::read_to_string("../input/day-19.input").unwrap();
-    let day = Day19 {};
+    let input = read_input(2020, 19);
+    let day = year_2020::Day19 {};
     c.bench_function("Day 19 first task", |b| {
         b.iter(|| day.first_task(black_box(&input)))
     });
@@ -212,8 +232,8 @@ pub fn day19(c: &mut Criterion) {
 }
 
 pub fn day20(c: &mut Criterion) {
-    let input = fs::read_to_string("../input/day-20.input").unwrap();
-    let day = Day20 {};
+    let input = read_input(2020, 20);
+    let day = year_2020::Day20 {};
     c.bench_function("Day 20 first task", |b| {
         b.iter(|| day.first_task(black_box(&input)))
     });
@@ -223,8 +243,8 @@ pub fn day20(c: &mut Criterion) {
 }
 
 pub fn day21(c: &mut Criterion) {
-    let input = fs::read_to_string("../input/day-21.input").unwrap();
-    let day = Day21 {};
+    let input = read_input(2020, 21);
+    let day = year_2020::Day21 {};
     c.bench_function("Day 21 first task", |b| {
         b.iter(|| day.first_task(black_box(&input)))
     });
@@ -234,8 +254,8 @@ pub fn day21(c: &mut Criterion) {
 }
 
 pub fn day22(c: &mut Criterion) {
-    let input = fs::read_to_string("../input/day-22.input").unwrap();
-    let day = Day22 {};
+    let input = read_input(2020, 22);
+    let day = year_2020::Day22 {};
     c.bench_function("Day 22 first task", |b| {
         b.iter(|| day.first_task(black_box(&input)))
     });
@@ -245,8 +265,8 @@ pub fn day22(c: &mut Criterion) {
 }
 
 pub fn day23(c: &mut Criterion) {
-    let input = fs::read_to_string("../input/day-23.input").unwrap();
-    let day = Day23 {};
+    let input = read_input(2020, 23);
+    let day = year_2020::Day23 {};
     c.bench_function("Day 23 first task", |b| {
         b.iter(|| day.first_task(black_box(&input)))
     });
@@ -256,8 +276,8 @@ pub fn day23(c: &mut Criterion) {
 }
 
 pub fn day24(c: &mut Criterion) {
-    let input = fs::read_to_string("../input/day-24.input").unwrap();
-    let day = Day24 {};
+    let input = read_input(2020, 24);
+    let day = year_2020::Day24 {};
     c.bench_function("Day 24 first task", |b| {
         b.iter(|| day.first_task(black_box(&input)))
     });
@@ -267,8 +287,8 @@ pub fn day24(c: &mut Criterion) {
 }
 
 pub fn day25(c: &mut Criterion) {
-    let input = fs::read_to_string("../input/day-25.input").unwrap();
-    let day = Day25 {};
+    let input = read_input(2020, 25);
+    let day = year_2020::Day25 {};
     c.bench_function("Day 25 first task", |b| {
         b.iter(|| day.first_task(black_box(&input)))
     });
@@ -277,8 +297,46 @@ pub fn day25(c: &mut Criterion) {
     });
 }
 
+/// Parse step of every day on its own, without solving any part.
+pub fn parse(c: &mut Criterion) {
+    for year in YEARS {
+        for puzzle in year.puzzles {
+            let input = read_input(year.year, puzzle.day);
+            let name = day_name(year.year, puzzle.day);
+            c.bench_function(&format!("{} parse", name), |b| {
+                b.iter(|| puzzle.solution.prepare(black_box(&input)))
+            });
+        }
+    }
+}
+
+/// Both parts of every day on a generated input of the default size, so
+/// days can be compared on inputs of known size.
+pub fn generated(c: &mut Criterion) {
+    let mut group = c.benchmark_group("generated");
+    group.sample_size(10);
+
+    for year in YEARS {
+        for puzzle in year.puzzles {
+            let generator = puzzle.generator;
+            let input = generator.input(2020, generator.default_size).unwrap();
+            let solution = puzzle.solution;
+            let name = day_name(year.year, puzzle.day);
+
+            group.bench_function(format!("{} first task", name), |b| {
+                b.iter(|| solution.first_task(black_box(&input)))
+            });
+            group.bench_function(format!("{} second task", name), |b| {
+                b.iter(|| solution.second_task(black_box(&input)))
+            });
+        }
+    }
+
+    group.finish();
+}
+
 criterion_group!(
-    benches,
+    benches, parse, generated,
     day01,
     // day02,
     // day03,
@@ -0,0 +1,11 @@
+#![no_main]
+use aoc2020::year_2020::Day13;
+use aoc2020::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let input = String::from_utf8_lossy(data);
+
+    let _ = Day13 {}.first_task(&input);
+    let _ = Day13 {}.second_task(&input);
+});
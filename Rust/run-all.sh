@@ -1,6 +1,3 @@
 #!/bin/sh
 
-for day in $(seq -w 1 25)
-do
-    ./target/release/aoc2020 $day "../input/day-$day.input"
-done
\ No newline at end of file
+./target/release/aoc2020 all
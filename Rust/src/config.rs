@@ -1,16 +1,337 @@
-use clap::Clap;
+use crate::output::Format;
+use crate::solutions::Tag;
+use clap::{AppSettings, ArgSettings, Clap};
 use std::error::Error;
+use std::str::FromStr;
 
 #[derive(Clap)]
-#[clap()]
+#[clap(setting = AppSettings::SubcommandsNegateReqs)]
 pub struct Opts {
     /// Day number (between 1 and 25 inclusive)
+    #[clap(validator(is_day_valid), setting = ArgSettings::Required)]
+    pub day: Option<u8>,
+
+    /// Input file containing input data provided by Advent of Code.
+    /// Accept "-" as a special file that refers to stdin. When omitted,
+    /// "day-NN.input" is looked up in the input directory.
+    pub file: Option<String>,
+
+    /// Directory containing "day-NN.input" files provided by Advent of Code.
+    /// Defaults to $AOC_INPUT_DIR, then to "input_dir" from the closest
+    /// "aoc2020.toml", then to the closest "input" directory up the tree.
+    /// Inputs of years after 2020 are in its "YYYY" subdirectory.
+    #[clap(long, global = true)]
+    pub input_dir: Option<String>,
+
+    /// Year of the puzzles. Defaults to 2020, the first year with solutions
+    #[clap(long, global = true, validator(is_year_valid))]
+    pub year: Option<u16>,
+
+    /// Which part of the puzzle to solve: "1", "2" or "both"
+    #[clap(long, global = true, default_value = "both", possible_values = &["1", "2", "both"])]
+    pub part: Part,
+
+    /// Print how long it took to read the input and to solve each part
+    #[clap(long)]
+    pub time: bool,
+
+    /// Re-run the day whenever the input file changes, comparing the
+    /// answers and timings with the previous run
+    #[clap(long)]
+    pub watch: bool,
+
+    /// Give up on the day after this many seconds with a "timed out" error.
+    /// The time covers reading and parsing the input and solving the parts
+    /// together
+    #[clap(long)]
+    pub timeout: Option<f64>,
+
+    /// Output format: "text", "json" or "csv"
+    #[clap(long, global = true, default_value = "text", possible_values = &["text", "json", "csv"])]
+    pub format: Format,
+
+    #[clap(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Clap)]
+pub enum Command {
+    /// Run solutions for every day and print a summary table
+    All(AllOpts),
+    /// Compare answers with a manifest of known-correct answers
+    Verify(VerifyOpts),
+    /// Solve the examples from the puzzle descriptions and compare the answers
+    Examples(ExamplesOpts),
+    /// Check input files against the input format of their puzzle and
+    /// report every problem with its line and column
+    Lint(LintOpts),
+    /// Print every day with a solution, its puzzle title and tags
+    List(ListOpts),
+    /// Generate random inputs of any size. The same seed and size always
+    /// give the same input
+    Generate(GenerateOpts),
+    /// Solve inputs with both the Rust and the Python solutions and report
+    /// every part where their answers differ
+    CrossCheck(CrossCheckOpts),
+    /// Download puzzle inputs into the input directory. Inputs already
+    /// there are never downloaded again
+    Fetch(FetchOpts),
+    /// Solve a part and submit its answer. Every attempt is recorded, and
+    /// answers known to be wrong are never submitted again
+    Submit(SubmitOpts),
+    /// Add a day with a placeholder solution, an example test, a bench and
+    /// a fuzz target to the crate
+    NewDay(NewDayOpts),
+    /// Start a local HTTP server solving puzzle inputs sent to it
+    Serve(ServeOpts),
+    /// Measure the median time of every part, record it with the git
+    /// revision and the machine, and report the trend with regressions
+    BenchHistory(BenchHistoryOpts),
+}
+
+#[derive(Clap)]
+pub struct AllOpts {
+    /// Number of days solved at the same time. Defaults to the number of
+    /// available cores. Use 1 for precise timings.
+    #[clap(long, short)]
+    pub jobs: Option<usize>,
+
+    /// Run only days with the tag
+    #[clap(long, possible_values = TAGS)]
+    pub tag: Option<Tag>,
+
+    /// Give up on a day after this many seconds with a "timed out" error
+    #[clap(long)]
+    pub timeout: Option<f64>,
+}
+
+#[derive(Clap)]
+pub struct ExamplesOpts {
+    /// Check only days with the tag
+    #[clap(long, possible_values = TAGS)]
+    pub tag: Option<Tag>,
+}
+
+#[derive(Clap)]
+pub struct LintOpts {
+    /// Day number of the input. Every input in the input directory is
+    /// checked when omitted
+    #[clap(validator(is_day_valid))]
+    pub day: Option<u8>,
+
+    /// Input file to check. Accept "-" as a special file that refers to
+    /// stdin. Defaults to "day-NN.input" in the input directory
+    pub file: Option<String>,
+}
+
+#[derive(Clap)]
+pub struct ListOpts {
+    /// Print only days with the tag
+    #[clap(long, possible_values = TAGS)]
+    pub tag: Option<Tag>,
+}
+
+#[derive(Clap)]
+pub struct GenerateOpts {
+    /// Day number of the input. Inputs for every day are generated when
+    /// omitted, which requires --output-dir
+    #[clap(validator(is_day_valid))]
+    pub day: Option<u8>,
+
+    /// Seed of the random number generator
+    #[clap(long, default_value = "2020")]
+    pub seed: u64,
+
+    /// Size of the input. What it means and which sizes are supported
+    /// depends on the day. Defaults to the size of a typical input
+    #[clap(long)]
+    pub size: Option<usize>,
+
+    /// Write "day-NN.input" files into the directory instead of printing
+    /// the input to stdout
+    #[clap(long)]
+    pub output_dir: Option<String>,
+}
+
+#[derive(Clap)]
+pub struct CrossCheckOpts {
+    /// Day number of the input. Every day is checked when omitted
+    #[clap(validator(is_day_valid))]
+    pub day: Option<u8>,
+
+    /// Input file to solve. Accept "-" as a special file that refers to
+    /// stdin. Defaults to "day-NN.input" in the input directory
+    pub file: Option<String>,
+
+    /// Solve generated inputs instead of the input files
+    #[clap(long)]
+    pub generated: bool,
+
+    /// Seed of the generated inputs
+    #[clap(long, default_value = "2020")]
+    pub seed: u64,
+
+    /// Size of the generated inputs. Defaults to the size of a typical input
+    #[clap(long)]
+    pub size: Option<usize>,
+
+    /// Python interpreter running the Python solutions
+    #[clap(long, default_value = "python3")]
+    pub python: String,
+
+    /// Directory with the "aoc2020" Python package. Defaults to the closest
+    /// "Python3" directory up the tree
+    #[clap(long)]
+    pub python_dir: Option<String>,
+
+    /// Give up on a day after this many seconds with a "timed out" error.
+    /// Python solutions running longer are killed
+    #[clap(long)]
+    pub timeout: Option<f64>,
+}
+
+#[derive(Clap)]
+pub struct FetchOpts {
+    /// Day number of the input. Inputs for every day are downloaded when
+    /// omitted
     #[clap(validator(is_day_valid))]
+    pub day: Option<u8>,
+
+    /// Server to download inputs from. Defaults to "base_url" from the
+    /// closest "aoc2020.toml", then to https://adventofcode.com
+    #[clap(long)]
+    pub base_url: Option<String>,
+
+    /// Seconds to wait between two downloads
+    #[clap(long, default_value = "5")]
+    pub interval: f64,
+}
+
+#[derive(Clap)]
+pub struct SubmitOpts {
+    /// Day number of the puzzle
+    #[clap(long, validator(is_day_valid))]
     pub day: u8,
 
-    /// Input file containing input data provided by Advent of Code.
-    /// Accept "-" as a special file that refers to stdin.
-    pub file: String,
+    /// Input file to solve. Accept "-" as a special file that refers to
+    /// stdin. Defaults to "day-NN.input" in the input directory
+    pub file: Option<String>,
+
+    /// Server to submit the answer to. Defaults to "base_url" from the
+    /// closest "aoc2020.toml", then to https://adventofcode.com
+    #[clap(long)]
+    pub base_url: Option<String>,
+
+    /// JSON file with every submitted answer. Defaults to
+    /// "submissions.json" in the input directory
+    #[clap(long)]
+    pub store: Option<String>,
+}
+
+#[derive(Clap)]
+pub struct NewDayOpts {
+    /// Day number of the puzzle. Days are added in order, and day 1 of a
+    /// year without solutions starts the module of the year
+    #[clap(validator(is_day_valid))]
+    pub day: u8,
+
+    /// Title of the puzzle
+    #[clap(long, default_value = "Untitled")]
+    pub title: String,
+
+    /// Directory of the crate to add the day to. Defaults to the crate this
+    /// program was built from
+    #[clap(long)]
+    pub crate_dir: Option<String>,
+}
+
+#[derive(Clap)]
+pub struct ServeOpts {
+    /// Address and port to listen on
+    #[clap(long, default_value = "127.0.0.1:2020")]
+    pub address: String,
+
+    /// Largest accepted puzzle input in bytes
+    #[clap(long, default_value = "1048576")]
+    pub max_body_size: usize,
+
+    /// Seconds a solution may run before it is cancelled and its request
+    /// times out
+    #[clap(long, default_value = "30")]
+    pub timeout: f64,
+
+    /// Number of requests handled at the same time. Defaults to the number
+    /// of available cores
+    #[clap(long, short)]
+    pub jobs: Option<usize>,
+}
+
+#[derive(Clap)]
+pub struct BenchHistoryOpts {
+    /// Number of times every day is solved. The median time of each part is
+    /// recorded
+    #[clap(long, default_value = "10")]
+    pub samples: usize,
+
+    /// Flag parts whose median time grew by more than this many percent
+    /// since the previous run on the same machine
+    #[clap(long, default_value = "10")]
+    pub threshold: f64,
+
+    /// JSON file with every recorded run. Defaults to "bench-history.json"
+    /// in the crate this program was built from
+    #[clap(long)]
+    pub history: Option<String>,
+
+    /// Number of the most recent runs in the report
+    #[clap(long, default_value = "5")]
+    pub last: usize,
+
+    /// Report the recorded runs without measuring a new one
+    #[clap(long)]
+    pub report_only: bool,
+}
+
+const TAGS: &[&str] = &["simulation", "graph", "parsing", "number-theory"];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Part {
+    First,
+    Second,
+    Both,
+}
+
+impl Part {
+    /// Part numbers (1 or 2) to solve.
+    pub fn numbers(self) -> &'static [u8] {
+        match self {
+            Part::First => &[1],
+            Part::Second => &[2],
+            Part::Both => &[1, 2],
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::First),
+            "2" => Ok(Part::Second),
+            "both" => Ok(Part::Both),
+            part => Err(format!("Unsupported part: {}", part)),
+        }
+    }
+}
+
+#[derive(Clap)]
+pub struct VerifyOpts {
+    /// JSON manifest with known-correct answers. Input files in the
+    /// manifest are relative to the directory of the manifest.
+    /// Defaults to "answers.json" in the input directory.
+    #[clap(long)]
+    pub manifest: Option<String>,
 }
 
 fn is_day_valid(val: &str) -> Result<(), String> {
@@ -22,6 +343,13 @@ fn is_day_valid(val: &str) -> Result<(), String> {
     }
 }
 
+fn is_year_valid(val: &str) -> Result<(), String> {
+    match val.parse::<u16>() {
+        Ok(val) if val >= 2015 => Ok(()),
+        _ => Err(String::from("Year must be 2015 or later")),
+    }
+}
+
 impl Opts {
     pub fn new() -> Result<Opts, Box<dyn Error>> {
         Ok(Opts::parse())
@@ -0,0 +1,329 @@
+use crate::manifest::Verification;
+use crate::runner;
+use crate::solutions::{Error, Puzzle};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Directory with the Python package looked up in the current directory and
+/// all its ancestors when no directory is given.
+const PYTHON_DIR_NAME: &str = "Python3";
+
+/// Runs the command line application of the Python package. The package
+/// doesn't have to be installed, it's imported from its directory.
+const PYTHON_ENTRY: &str = "from aoc2020.main import cli; cli()";
+
+/// The only year the Python package has solutions for.
+pub const YEAR: u16 = 2020;
+
+/// How often a running Python solution is checked for being done.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Python implementation of the puzzles from the `aoc2020` package of the
+/// repository.
+pub struct Python {
+    pub program: String,
+    pub package_dir: PathBuf,
+}
+
+impl Python {
+    /// Python solutions from `package_dir`, or from the closest "Python3"
+    /// directory starting from `start_dir` and going up.
+    pub fn find(
+        program: &str,
+        package_dir: Option<&Path>,
+        start_dir: &Path,
+    ) -> Result<Python, Error> {
+        let package_dir = match package_dir {
+            Some(dir) => dir.to_path_buf(),
+            None => start_dir
+                .ancestors()
+                .map(|dir| dir.join(PYTHON_DIR_NAME))
+                .find(|dir| dir.join("aoc2020").is_dir())
+                .ok_or_else(|| {
+                    Error::invalid_argument(format!(
+                        "cannot find the \"{}\" directory with Python solutions",
+                        PYTHON_DIR_NAME
+                    ))
+                })?,
+        };
+
+        if !package_dir.join("aoc2020").is_dir() {
+            return Err(Error::invalid_argument(format!(
+                "there is no \"aoc2020\" package in {}",
+                package_dir.display()
+            )));
+        }
+
+        Ok(Python {
+            program: program.to_string(),
+            package_dir,
+        })
+    }
+
+    /// Answers of the Python solution of the `day` for both parts. A part
+    /// without an answer has the reason why the solution failed. The
+    /// solution is killed once it runs longer than the `timeout`.
+    pub fn solve(
+        &self,
+        day: u8,
+        input: &str,
+        timeout: Option<Duration>,
+    ) -> Vec<(u8, Result<String, Error>)> {
+        let output = self.run(day, input, timeout);
+        let failure = |part: u8| match &output {
+            Ok((_, stderr)) => Error::internal(format!(
+                "Python solution has no answer for part {}: {}",
+                part,
+                stderr.lines().last().unwrap_or("no output")
+            )),
+            Err(err) => err.clone(),
+        };
+        let answers = match &output {
+            Ok((stdout, _)) => parse_answers(day, stdout),
+            Err(_) => vec![],
+        };
+
+        [1, 2]
+            .iter()
+            .map(|&part| {
+                let answer = answers
+                    .iter()
+                    .find(|(number, _)| *number == part)
+                    .map(|(_, answer)| answer.clone())
+                    .ok_or_else(|| failure(part));
+
+                (part, answer)
+            })
+            .collect()
+    }
+
+    /// Standard output and error of the Python application.
+    fn run(
+        &self,
+        day: u8,
+        input: &str,
+        timeout: Option<Duration>,
+    ) -> Result<(String, String), Error> {
+        let cannot_run =
+            |err: io::Error| Error::internal(format!("cannot run {}: {}", self.program, err));
+
+        let mut child = Command::new(&self.program)
+            .arg("-c")
+            .arg(PYTHON_ENTRY)
+            .arg(day.to_string())
+            .arg("-")
+            .current_dir(&self.package_dir)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(cannot_run)?;
+
+        // Pipes are served by their own threads, so neither a full pipe nor
+        // a solution that stopped reading its input keeps the timeout from
+        // being noticed. Killing the solution closes all of them.
+        let stdin = child.stdin.take();
+        let stdout = child.stdout.take();
+        let stderr = child.stderr.take();
+
+        thread::scope(|scope| {
+            scope.spawn(move || {
+                if let Some(mut stdin) = stdin {
+                    // A solution that exits early stops reading, its output
+                    // tells what went wrong.
+                    let _ = stdin.write_all(input.as_bytes());
+                }
+            });
+            let stdout = scope.spawn(move || read_all(stdout));
+            let stderr = scope.spawn(move || read_all(stderr));
+
+            wait(&mut child, day, timeout).map_err(cannot_run)??;
+
+            let joined = |handle: thread::ScopedJoinHandle<'_, io::Result<String>>| {
+                handle
+                    .join()
+                    .unwrap_or_else(|_| Ok(String::new()))
+                    .map_err(cannot_run)
+            };
+
+            Ok((joined(stdout)?, joined(stderr)?))
+        })
+    }
+}
+
+/// Whole output of a pipe of the Python application.
+fn read_all(pipe: Option<impl Read>) -> io::Result<String> {
+    let mut bytes = vec![];
+    if let Some(mut pipe) = pipe {
+        pipe.read_to_end(&mut bytes)?;
+    }
+
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+/// Wait for the `child` to exit, killing it and failing with a "timed out"
+/// error once it runs longer than the `timeout`.
+fn wait(child: &mut Child, day: u8, timeout: Option<Duration>) -> io::Result<Result<(), Error>> {
+    let timeout = match timeout {
+        Some(timeout) => timeout,
+        None => return child.wait().map(|_| Ok(())),
+    };
+
+    let started = Instant::now();
+    while child.try_wait()?.is_none() {
+        if started.elapsed() >= timeout {
+            child.kill()?;
+            child.wait()?;
+            return Ok(Err(Error::timed_out(day, timeout)));
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+
+    Ok(Ok(()))
+}
+
+/// Answers from "Day N-P: answer" lines printed by the Python application.
+fn parse_answers(day: u8, stdout: &str) -> Vec<(u8, String)> {
+    let prefix = format!("Day {}-", day);
+
+    stdout
+        .lines()
+        .filter_map(|line| {
+            let (part, answer) = line.strip_prefix(&prefix)?.split_once(": ")?;
+
+            Some((part.parse().ok()?, answer.to_string()))
+        })
+        .collect()
+}
+
+/// Solve the `input` with both the Rust and the Python solution of the
+/// puzzle and compare the answers for the selected `parts`. Answers of the
+/// Python solution are the expected ones. Each solution gets at most
+/// `timeout` to solve the day.
+pub fn verify(
+    python: &Python,
+    puzzle: &Puzzle,
+    input_name: &str,
+    input: &str,
+    parts: &[u8],
+    timeout: Option<Duration>,
+) -> Vec<Verification> {
+    let expected = python.solve(puzzle.day, input, timeout);
+    let report = runner::within(timeout, || {
+        runner::run_day(puzzle.day, puzzle.solution, input, parts)
+    });
+
+    report
+        .parts
+        .into_iter()
+        .map(|part| {
+            let (expected, actual) = match expected.iter().find(|(number, _)| *number == part.part)
+            {
+                Some((_, Ok(expected))) => (expected.clone(), part.answer),
+                Some((_, Err(err))) => (String::new(), Err(err.clone())),
+                None => (String::new(), part.answer),
+            };
+
+            Verification {
+                day: puzzle.day,
+                part: part.part,
+                input: input_name.to_string(),
+                expected,
+                actual,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::manifest::Status;
+    use crate::solutions::year_2020;
+
+    #[test]
+    fn test_parse_answers() {
+        let stdout = "Day 7-1: 261\nDay 7-2: a,b: c\nDay 8-1: 1\nsomething else\n";
+
+        assert_eq!(
+            parse_answers(7, stdout),
+            vec![(1, String::from("261")), (2, String::from("a,b: c"))]
+        );
+    }
+
+    #[test]
+    fn test_python_solutions_match_on_examples() {
+        let start_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let python = match Python::find("python3", None, start_dir) {
+            Ok(python) => python,
+            Err(_) => return,
+        };
+        if Command::new(&python.program)
+            .arg("--version")
+            .output()
+            .is_err()
+        {
+            return;
+        }
+
+        for &day in &[1, 8, 18] {
+            let puzzle = year_2020::YEAR.find(day).unwrap();
+            let example = &puzzle.examples[0];
+
+            for v in verify(&python, puzzle, "example 1", example.input, &[1, 2], None) {
+                assert_eq!(v.status(), Status::Match, "day {}-{}", v.day, v.part);
+            }
+        }
+    }
+
+    #[test]
+    fn test_missing_python_is_reported_for_every_part() {
+        let python = Python {
+            program: String::from("there-is-no-such-python"),
+            package_dir: PathBuf::from("."),
+        };
+        let puzzle = year_2020::YEAR.find(1).unwrap();
+
+        let verifications = verify(
+            &python,
+            puzzle,
+            "example",
+            puzzle.examples[0].input,
+            &[1, 2],
+            None,
+        );
+
+        assert_eq!(verifications.len(), 2);
+        assert!(verifications.iter().all(|v| v.status() == Status::Failed));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_hanging_python_is_killed() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let program = dir.path().join("python");
+        std::fs::write(&program, "#!/bin/sh\nexec sleep 30\n").unwrap();
+        std::fs::set_permissions(&program, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let python = Python {
+            program: program.display().to_string(),
+            package_dir: dir.path().to_path_buf(),
+        };
+
+        let started = Instant::now();
+        let answers = python.solve(1, "1721\n979\n", Some(Duration::from_millis(100)));
+
+        assert!(started.elapsed() < Duration::from_secs(10));
+        for (_, answer) in answers {
+            assert_eq!(
+                answer.unwrap_err().to_string(),
+                "day 1: timed out after 100.00ms"
+            );
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use crate::manifest::Verification;
+use crate::runner;
+use crate::solutions::Puzzle;
+
+/// Solve the examples of every puzzle through the `Solution` trait and
+/// compare the answers for the selected `parts` with the expected ones.
+pub fn verify(puzzles: &[&Puzzle], parts: &[u8]) -> Vec<Verification> {
+    let mut verifications = vec![];
+
+    for puzzle in puzzles {
+        for (idx, example) in puzzle.examples.iter().enumerate() {
+            let parts: Vec<u8> = parts
+                .iter()
+                .copied()
+                .filter(|&part| example.expected(part).is_some())
+                .collect();
+
+            if parts.is_empty() {
+                continue;
+            }
+
+            let report = runner::run_day(puzzle.day, puzzle.solution, example.input, &parts);
+            for part in report.parts {
+                verifications.push(Verification {
+                    day: puzzle.day,
+                    part: part.part,
+                    input: format!("example {}", idx + 1),
+                    expected: example.expected(part.part).unwrap_or_default().to_string(),
+                    actual: part.answer,
+                });
+            }
+        }
+    }
+
+    verifications
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::manifest::Status;
+    use crate::output;
+    use crate::solutions::YEARS;
+
+    #[test]
+    fn test_every_day_solves_its_examples() {
+        for year in YEARS {
+            let puzzles: Vec<&Puzzle> = year.puzzles.iter().collect();
+
+            for puzzle in &puzzles {
+                assert!(
+                    !puzzle.examples.is_empty(),
+                    "{} day {} has no examples",
+                    year.year,
+                    puzzle.day
+                );
+            }
+
+            let failures: Vec<String> = verify(&puzzles, &[1, 2])
+                .iter()
+                .filter(|v| v.status() != Status::Match)
+                .map(|v| match &v.actual {
+                    Ok(actual) => format!(
+                        "{} day {}-{} ({}): expected {}, got {}",
+                        year.year, v.day, v.part, v.input, v.expected, actual
+                    ),
+                    Err(err) => format!(
+                        "{} day {}-{} ({}): {}",
+                        year.year,
+                        v.day,
+                        v.part,
+                        v.input,
+                        output::describe(err)
+                    ),
+                })
+                .collect();
+
+            assert!(failures.is_empty(), "{}", failures.join("\n"));
+        }
+    }
+}
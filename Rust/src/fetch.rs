@@ -0,0 +1,292 @@
+use crate::input::Settings;
+use crate::runner;
+use crate::solutions::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Environment variable with the session token of an Advent of Code
+/// account, the value of the "session" cookie of the website.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Advent of Code asks automated tools to say who they are.
+const USER_AGENT: &str = "github.com/maddenvvs/advent-of-code-2020 by maddenvvs@gmail.com";
+
+/// Session token from the environment, then from `session` in the config
+/// file.
+pub fn session(env: Option<String>, settings: Option<&Settings>) -> Result<String, Error> {
+    let config = settings.and_then(|s| s.session.clone());
+
+    env.into_iter()
+        .chain(config)
+        .map(|session| session.trim().to_string())
+        .find(|session| !session.is_empty())
+        .ok_or_else(|| {
+            Error::invalid_argument(format!(
+                "session token is required, set ${} or \"session\" in the config file",
+                SESSION_ENV
+            ))
+        })
+}
+
+/// Keeps at least `interval` between two requests.
+struct RateLimiter {
+    interval: Duration,
+    last: Option<Instant>,
+}
+
+impl RateLimiter {
+    fn wait(&mut self) {
+        if let Some(last) = self.last {
+            let next = last + self.interval;
+            let now = Instant::now();
+            if next > now {
+                thread::sleep(next - now);
+            }
+        }
+
+        self.last = Some(Instant::now());
+    }
+}
+
+/// Downloads puzzle inputs from an Advent-of-Code-compatible server and
+/// submits answers to it.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+    limiter: RateLimiter,
+}
+
+impl Client {
+    /// Client of the server at `base_url` sending at most one request per
+    /// `interval`.
+    pub fn new(base_url: &str, session: &str, interval: Duration) -> Client {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+            limiter: RateLimiter {
+                interval,
+                last: None,
+            },
+        }
+    }
+
+    /// Body of the response to a request to the `path` of the day of the
+    /// year. The request is a GET without a `form` and a POST with it.
+    fn request(
+        &mut self,
+        year: u16,
+        day: u8,
+        path: &str,
+        form: Option<&[(&str, &str)]>,
+    ) -> Result<String, Error> {
+        let method = if form.is_some() { "POST" } else { "GET" };
+        let url = format!("{}/{}/day/{}/{}", self.base_url, year, day, path);
+        let failed = |reason: String| {
+            Error::invalid_argument(format!("{} {} failed: {}", method, url, reason))
+        };
+
+        self.limiter.wait();
+        let request = self
+            .agent
+            .request(method, &url)
+            .set("Cookie", &format!("session={}", self.session));
+        let response = match form {
+            Some(form) => request.send_form(form),
+            None => request.call(),
+        };
+        let response = response.map_err(|err| match err {
+            ureq::Error::Status(404, _) => failed(String::from("the puzzle is not available yet")),
+            ureq::Error::Status(status @ 400..=499, _) => failed(format!(
+                "server responded with {}, check the session token",
+                status
+            )),
+            ureq::Error::Status(status, _) => failed(format!("server responded with {}", status)),
+            ureq::Error::Transport(err) => failed(err.to_string()),
+        })?;
+
+        response
+            .into_string()
+            .map_err(|err| failed(err.to_string()))
+    }
+
+    fn download(&mut self, year: u16, day: u8) -> Result<String, Error> {
+        self.request(year, day, "input", None)
+    }
+
+    /// Page the server responds with to the `answer` for the `part` of the
+    /// `day` of the `year`.
+    pub fn submit(&mut self, year: u16, day: u8, part: u8, answer: &str) -> Result<String, Error> {
+        let level = part.to_string();
+
+        self.request(
+            year,
+            day,
+            "answer",
+            Some(&[("level", &level), ("answer", answer)]),
+        )
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Fetched {
+    /// The input was in the cache already.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Make sure the input of the `day` of the `year` is cached as
+/// "day-NN.input" in the directory of the year in `input_dir`, downloading
+/// it only when it isn't there yet.
+pub fn fetch(client: &mut Client, input_dir: &Path, year: u16, day: u8) -> Result<Fetched, Error> {
+    let year_dir = runner::year_dir(input_dir, year);
+    let file = runner::input_path(&year_dir, day);
+    if file.exists() {
+        return Ok(Fetched::Cached(file));
+    }
+
+    let input = client.download(year, day)?;
+
+    // A download interrupted half way must not look like a cached input.
+    let cannot_write =
+        |err: std::io::Error| Error::internal(format!("cannot write {}: {}", file.display(), err));
+    let partial = file.with_extension("input.part");
+    fs::create_dir_all(&year_dir).map_err(cannot_write)?;
+    fs::write(&partial, input).map_err(cannot_write)?;
+    fs::rename(&partial, &file).map_err(cannot_write)?;
+
+    Ok(Fetched::Downloaded(file))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use tiny_http::{Response, Server};
+
+    /// Server answering every request with the path and session cookie it
+    /// got, except for day 25 which isn't available. Paths of the requests
+    /// are recorded.
+    fn mock_server() -> (String, Arc<Mutex<Vec<String>>>) {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let requests = Arc::new(Mutex::new(vec![]));
+
+        let recorded = Arc::clone(&requests);
+        thread::spawn(move || {
+            for request in server.incoming_requests() {
+                let url = request.url().to_string();
+                let cookie = request
+                    .headers()
+                    .iter()
+                    .find(|header| header.field.equiv("Cookie"))
+                    .map(|header| header.value.to_string())
+                    .unwrap_or_default();
+                recorded.lock().unwrap().push(url.clone());
+
+                let response = if url.contains("/day/25/") {
+                    Response::from_string("Not yet").with_status_code(404)
+                } else {
+                    Response::from_string(format!("{}\n{}\n", url, cookie))
+                };
+                let _ = request.respond(response);
+            }
+        });
+
+        (base_url, requests)
+    }
+
+    #[test]
+    fn test_download_into_cache_once() {
+        let (base_url, requests) = mock_server();
+        let dir = tempfile::tempdir().unwrap();
+        let mut client = Client::new(&base_url, "secret", Duration::default());
+
+        let file = dir.path().join("day-07.input");
+        assert_eq!(
+            fetch(&mut client, dir.path(), 2020, 7).unwrap(),
+            Fetched::Downloaded(file.clone())
+        );
+        assert_eq!(
+            fs::read_to_string(&file).unwrap(),
+            "/2020/day/7/input\nsession=secret\n"
+        );
+
+        assert_eq!(
+            fetch(&mut client, dir.path(), 2020, 7).unwrap(),
+            Fetched::Cached(file)
+        );
+        assert_eq!(requests.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_later_years_go_into_their_directory() {
+        let (base_url, _) = mock_server();
+        let dir = tempfile::tempdir().unwrap();
+        let mut client = Client::new(&base_url, "secret", Duration::default());
+
+        let file = dir.path().join("2021").join("day-07.input");
+        assert_eq!(
+            fetch(&mut client, dir.path(), 2021, 7).unwrap(),
+            Fetched::Downloaded(file.clone())
+        );
+        assert!(fs::read_to_string(&file)
+            .unwrap()
+            .starts_with("/2021/day/7/input\n"));
+    }
+
+    #[test]
+    fn test_failed_download_is_not_cached() {
+        let (base_url, _) = mock_server();
+        let dir = tempfile::tempdir().unwrap();
+        let mut client = Client::new(&base_url, "secret", Duration::default());
+
+        let err = fetch(&mut client, dir.path(), 2020, 25).unwrap_err();
+
+        assert!(err.to_string().ends_with("the puzzle is not available yet"));
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_requests_are_rate_limited() {
+        let (base_url, requests) = mock_server();
+        let dir = tempfile::tempdir().unwrap();
+        let interval = Duration::from_millis(200);
+        let mut client = Client::new(&base_url, "secret", interval);
+
+        let started = Instant::now();
+        for day in 1..=3 {
+            fetch(&mut client, dir.path(), 2020, day).unwrap();
+        }
+
+        assert!(started.elapsed() >= 2 * interval);
+        assert_eq!(requests.lock().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_session_from_env_or_config() {
+        let settings = Settings {
+            session: Some(String::from("from-config\n")),
+            ..Settings::default()
+        };
+
+        assert_eq!(
+            session(Some(String::from("from-env")), Some(&settings)).unwrap(),
+            "from-env"
+        );
+        assert_eq!(session(None, Some(&settings)).unwrap(), "from-config");
+        assert_eq!(
+            session(Some(String::from(" ")), Some(&settings)).unwrap(),
+            "from-config"
+        );
+        assert!(session(None, None).is_err());
+    }
+}
@@ -0,0 +1,444 @@
+use crate::runner;
+use crate::solutions::{Context, Error, Puzzle};
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::Duration;
+
+/// History file kept next to `Cargo.toml`, where `cargo clean` doesn't
+/// reach it.
+pub const HISTORY_FILE: &str = "bench-history.json";
+
+/// The shortest parts run in microseconds, where a few of them make a large
+/// percentage. Slowdowns below this many milliseconds are never flagged.
+const NOISE_FLOOR_MS: f64 = 0.05;
+
+/// Median time of a part over every sample of a run.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Timing {
+    pub day: u8,
+    pub part: u8,
+    pub median_ms: f64,
+}
+
+/// Machine the benchmarks ran on. Timings compare only between runs on the
+/// same machine.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Machine {
+    /// Hash of the description.
+    pub id: String,
+    pub description: String,
+}
+
+impl Machine {
+    fn new(description: String) -> Machine {
+        // FNV-1a, unlike the hasher of the standard library, gives the same
+        // hash in every build.
+        let id = description
+            .bytes()
+            .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+                (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+            });
+
+        Machine {
+            id: format!("{:016x}", id),
+            description,
+        }
+    }
+
+    /// Operating system, CPU, number of cores and build profile of the
+    /// current process.
+    pub fn current() -> Machine {
+        let cpu = fs::read_to_string("/proc/cpuinfo")
+            .ok()
+            .and_then(|info| {
+                info.lines()
+                    .find(|line| line.starts_with("model name"))
+                    .and_then(|line| line.split(':').nth(1))
+                    .map(|model| model.trim().to_string())
+            })
+            .unwrap_or_else(|| String::from("unknown CPU"));
+        let cores = thread::available_parallelism().map_or(1, |n| n.get());
+        let profile = if cfg!(debug_assertions) {
+            "debug"
+        } else {
+            "release"
+        };
+
+        Machine::new(format!(
+            "{} {}, {}, {} cores, {} build",
+            env::consts::OS,
+            env::consts::ARCH,
+            cpu,
+            cores,
+            profile
+        ))
+    }
+}
+
+/// Timings of every day of a year measured at the same revision.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Run {
+    pub revision: String,
+    pub machine: Machine,
+    pub year: u16,
+    /// Number of times every day was solved.
+    pub samples: usize,
+    /// Seconds since the Unix epoch.
+    pub recorded_at: u64,
+    pub timings: Vec<Timing>,
+}
+
+impl Run {
+    fn median_ms(&self, day: u8, part: u8) -> Option<f64> {
+        self.timings
+            .iter()
+            .find(|t| t.day == day && t.part == part)
+            .map(|t| t.median_ms)
+    }
+}
+
+/// Every recorded run, kept in a JSON file.
+pub struct History {
+    path: PathBuf,
+    pub runs: Vec<Run>,
+}
+
+impl History {
+    /// Runs from the `path`. A missing file has no runs.
+    pub fn load(path: &Path) -> Result<History, Error> {
+        let runs = match fs::read_to_string(path) {
+            Ok(text) => serde_json::from_str(&text).map_err(|err| {
+                Error::invalid_argument(format!("malformed {}: {}", path.display(), err))
+            })?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => vec![],
+            Err(err) => {
+                return Err(Error::invalid_argument(format!(
+                    "cannot read {}: {}",
+                    path.display(),
+                    err
+                )))
+            }
+        };
+
+        Ok(History {
+            path: path.to_path_buf(),
+            runs,
+        })
+    }
+
+    pub fn save(&self) -> Result<(), Error> {
+        let cannot_write =
+            |err: String| Error::internal(format!("cannot write {}: {}", self.path.display(), err));
+
+        let text = serde_json::to_string_pretty(&self.runs)
+            .map_err(|err| cannot_write(err.to_string()))?;
+        fs::write(&self.path, text + "\n").map_err(|err| cannot_write(err.to_string()))
+    }
+
+    /// Add the `run` and save the history.
+    pub fn record(&mut self, run: Run) -> Result<(), Error> {
+        self.runs.push(run);
+        self.save()
+    }
+
+    /// Runs of the `year` on the `machine`, oldest first.
+    pub fn runs_on(&self, machine: &Machine, year: u16) -> Vec<&Run> {
+        self.runs
+            .iter()
+            .filter(|run| run.machine.id == machine.id && run.year == year)
+            .collect()
+    }
+}
+
+/// Git revision checked out in `dir`, marked "-dirty" when tracked files
+/// have uncommitted changes, or "unknown" outside of a repository.
+pub fn revision(dir: &Path) -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(revision) => {
+            let changes = git(&["status", "--porcelain", "--untracked-files=no"]);
+            if changes.is_some_and(|changes| !changes.is_empty()) {
+                format!("{}-dirty", revision)
+            } else {
+                revision
+            }
+        }
+        None => String::from("unknown"),
+    }
+}
+
+fn median(durations: &mut [Duration]) -> Duration {
+    durations.sort();
+
+    let middle = durations.len() / 2;
+    if durations.len().is_multiple_of(2) {
+        (durations[middle - 1] + durations[middle]) / 2
+    } else {
+        durations[middle]
+    }
+}
+
+/// Median time of the `parts` of every puzzle with its "day-NN.input" file
+/// in `input_dir`, over `samples` solutions of each. Days without an input
+/// are skipped, and a part without an answer fails the whole measurement.
+pub fn measure(
+    puzzles: &[&Puzzle],
+    input_dir: &Path,
+    parts: &[u8],
+    samples: usize,
+) -> Result<Vec<Timing>, Error> {
+    let mut timings = vec![];
+
+    for puzzle in puzzles {
+        let file = runner::input_path(input_dir, puzzle.day);
+        if !file.exists() {
+            continue;
+        }
+        let input = runner::read_input(&file).map_err(|err| {
+            Error::invalid_argument(format!("cannot read {}: {}", file.display(), err))
+        })?;
+
+        let mut durations: Vec<(u8, Vec<Duration>)> =
+            parts.iter().map(|&part| (part, vec![])).collect();
+        for _ in 0..samples {
+            let report = runner::run_day(puzzle.day, puzzle.solution, &input, parts);
+            for (solved, (part, durations)) in report.parts.into_iter().zip(&mut durations) {
+                solved
+                    .answer
+                    .with_context(|| format!("cannot measure day {}-{}", puzzle.day, part))?;
+                durations.push(solved.duration);
+            }
+        }
+
+        for (part, mut durations) in durations {
+            timings.push(Timing {
+                day: puzzle.day,
+                part,
+                median_ms: median(&mut durations).as_secs_f64() * 1000.0,
+            });
+        }
+    }
+
+    Ok(timings)
+}
+
+/// Part that got slower between two runs.
+#[derive(Debug, PartialEq)]
+pub struct Regression {
+    pub day: u8,
+    pub part: u8,
+    pub before_ms: f64,
+    pub after_ms: f64,
+}
+
+impl Regression {
+    pub fn percent(&self) -> f64 {
+        (self.after_ms - self.before_ms) / self.before_ms * 100.0
+    }
+}
+
+/// Parts of the `latest` run whose median time grew by more than
+/// `threshold` percent since the `previous` run. Parts missing from the
+/// previous run have nothing to compare with.
+pub fn regressions(previous: &Run, latest: &Run, threshold: f64) -> Vec<Regression> {
+    latest
+        .timings
+        .iter()
+        .filter_map(|timing| {
+            let before_ms = previous.median_ms(timing.day, timing.part)?;
+
+            Some(Regression {
+                day: timing.day,
+                part: timing.part,
+                before_ms,
+                after_ms: timing.median_ms,
+            })
+        })
+        .filter(|r| r.after_ms - r.before_ms > NOISE_FLOOR_MS && r.percent() > threshold)
+        .collect()
+}
+
+/// Table with the median time of every part in each of the `runs`, oldest
+/// first, and its change in the last run. Parts that regressed by more
+/// than `threshold` percent are flagged.
+pub fn write_trend(out: &mut dyn Write, runs: &[&Run], threshold: f64) -> io::Result<()> {
+    let (latest, previous) = match runs {
+        [] => return writeln!(out, "No runs recorded yet"),
+        [latest] => (*latest, None),
+        [.., previous, latest] => (*latest, Some(*previous)),
+    };
+    let regressions = previous.map_or_else(Vec::new, |p| regressions(p, latest, threshold));
+
+    let width = runs
+        .iter()
+        .map(|run| run.revision.len())
+        .chain(Some("00000.000".len()))
+        .max()
+        .unwrap_or_default();
+
+    write!(out, "Day  Part")?;
+    for run in runs {
+        write!(out, "  {:>width$}", run.revision, width = width)?;
+    }
+    writeln!(out, "   Change")?;
+
+    for timing in &latest.timings {
+        write!(out, "{:>3}  {:>4}", timing.day, timing.part)?;
+        for run in runs {
+            let median = run
+                .median_ms(timing.day, timing.part)
+                .map_or(String::from("-"), |ms| format!("{:.3}", ms));
+            write!(out, "  {:>width$}", median, width = width)?;
+        }
+
+        let before = previous.and_then(|p| p.median_ms(timing.day, timing.part));
+        match before {
+            Some(before) => {
+                let change = (timing.median_ms - before) / before * 100.0;
+                write!(out, "  {:>+6.1}%", change)?;
+            }
+            None => write!(out, "  {:>7}", "-")?,
+        }
+        if regressions
+            .iter()
+            .any(|r| r.day == timing.day && r.part == timing.part)
+        {
+            write!(out, "  REGRESSED")?;
+        }
+        writeln!(out)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::year_2020;
+
+    fn run(revision: &str, timings: &[(u8, u8, f64)]) -> Run {
+        Run {
+            revision: revision.to_string(),
+            machine: Machine::new(String::from("test machine")),
+            year: 2020,
+            samples: 3,
+            recorded_at: 0,
+            timings: timings
+                .iter()
+                .map(|&(day, part, median_ms)| Timing {
+                    day,
+                    part,
+                    median_ms,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_median() {
+        let ms = Duration::from_millis;
+
+        assert_eq!(median(&mut [ms(5), ms(1), ms(3)]), ms(3));
+        assert_eq!(median(&mut [ms(4), ms(1), ms(2), ms(9)]), ms(3));
+    }
+
+    #[test]
+    fn test_machine_id_is_stable() {
+        let machine = Machine::new(String::from("linux x86_64"));
+
+        assert_eq!(machine, Machine::new(String::from("linux x86_64")));
+        assert_eq!(machine.id, "cee42a9862cb502e");
+        assert_ne!(machine.id, Machine::new(String::from("linux aarch64")).id);
+    }
+
+    #[test]
+    fn test_record_runs() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(HISTORY_FILE);
+
+        let mut history = History::load(&path).unwrap();
+        assert!(history.runs.is_empty());
+        history.record(run("abc1234", &[(1, 1, 0.5)])).unwrap();
+
+        let mut other = run("abc1234", &[]);
+        other.machine = Machine::new(String::from("other machine"));
+        history.record(other).unwrap();
+
+        let history = History::load(&path).unwrap();
+        let machine = &history.runs[0].machine;
+        assert_eq!(history.runs.len(), 2);
+        assert_eq!(history.runs_on(machine, 2020), vec![&history.runs[0]]);
+        assert!(history.runs_on(machine, 2021).is_empty());
+    }
+
+    #[test]
+    fn test_regressions() {
+        let previous = run("before", &[(1, 1, 10.0), (1, 2, 10.0), (2, 1, 0.01)]);
+        let latest = run(
+            "after",
+            &[(1, 1, 10.5), (1, 2, 12.0), (2, 1, 0.04), (3, 1, 100.0)],
+        );
+
+        let regressions = regressions(&previous, &latest, 10.0);
+
+        assert_eq!(
+            regressions,
+            vec![Regression {
+                day: 1,
+                part: 2,
+                before_ms: 10.0,
+                after_ms: 12.0
+            }]
+        );
+        assert!((regressions[0].percent() - 20.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_trend_report() {
+        let first = run("1111111", &[(1, 1, 10.0), (1, 2, 20.0)]);
+        let second = run("2222222-dirty", &[(1, 1, 10.0), (1, 2, 30.0), (2, 1, 1.0)]);
+        let mut out = vec![];
+
+        write_trend(&mut out, &[&first, &second], 10.0).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "Day  Part        1111111  2222222-dirty   Change
+  1     1         10.000         10.000    +0.0%
+  1     2         20.000         30.000   +50.0%  REGRESSED
+  2     1              -          1.000        -
+"
+        );
+    }
+
+    #[test]
+    fn test_measure_skips_missing_inputs() {
+        let dir = tempfile::tempdir().unwrap();
+        let day_01 = year_2020::YEAR.find(1).unwrap();
+        let day_02 = year_2020::YEAR.find(2).unwrap();
+        fs::write(runner::input_path(dir.path(), 1), day_01.examples[0].input).unwrap();
+
+        let timings = measure(&[day_01, day_02], dir.path(), &[1, 2], 3).unwrap();
+
+        assert_eq!(timings.len(), 2);
+        assert!(timings.iter().all(|t| t.day == 1 && t.median_ms >= 0.0));
+
+        fs::write(runner::input_path(dir.path(), 2), "1-3 a\n").unwrap();
+        let err = measure(&[day_02], dir.path(), &[1], 1).unwrap_err();
+        assert!(err.to_string().starts_with("cannot measure day 2-1"));
+    }
+}
@@ -0,0 +1,219 @@
+use crate::runner::{input_path, year_dir};
+use crate::solutions::Error;
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Environment variable pointing at the directory with input files.
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+
+/// Config file looked up in the current directory and all its ancestors.
+pub const CONFIG_FILE: &str = "aoc2020.toml";
+
+/// Directory name looked up in the current directory and all its ancestors
+/// when nothing else points at the input directory.
+const INPUT_DIR_NAME: &str = "input";
+
+#[derive(Debug, Default, Deserialize)]
+pub struct Settings {
+    /// Relative paths are resolved against the directory of the config file.
+    pub input_dir: Option<PathBuf>,
+    /// Session token of an Advent of Code account used by `fetch`.
+    pub session: Option<String>,
+    /// Server `fetch` downloads inputs from.
+    pub base_url: Option<String>,
+}
+
+impl Settings {
+    /// Find the closest config file starting from `start_dir` and going up.
+    pub fn find(start_dir: &Path) -> Result<Option<(PathBuf, Settings)>, Error> {
+        let path = match start_dir
+            .ancestors()
+            .map(|dir| dir.join(CONFIG_FILE))
+            .find(|path| path.is_file())
+        {
+            Some(path) => path,
+            None => return Ok(None),
+        };
+
+        let text = fs::read_to_string(&path).map_err(|err| {
+            Error::invalid_argument(format!("cannot read {}: {}", path.display(), err))
+        })?;
+        let settings = toml::from_str(&text).map_err(|err| {
+            Error::invalid_argument(format!("malformed config {}: {}", path.display(), err))
+        })?;
+
+        Ok(Some((path, settings)))
+    }
+}
+
+/// Everything that may point at the input directory, most important first.
+pub struct Sources {
+    pub flag: Option<PathBuf>,
+    pub env: Option<PathBuf>,
+    pub start_dir: PathBuf,
+}
+
+impl Sources {
+    /// Sources of the current process: `flag` from the command line,
+    /// the environment and the current directory.
+    pub fn from_env(flag: Option<&str>) -> Sources {
+        Sources {
+            flag: flag.map(PathBuf::from),
+            env: std::env::var_os(INPUT_DIR_ENV).map(PathBuf::from),
+            start_dir: std::env::current_dir().unwrap_or_default(),
+        }
+    }
+
+    /// Candidate input directories in the order they should be tried.
+    pub fn candidate_dirs(&self) -> Result<Vec<PathBuf>, Error> {
+        let mut candidates: Vec<PathBuf> = vec![];
+        candidates.extend(self.flag.clone());
+        candidates.extend(self.env.clone());
+
+        if let Some((path, settings)) = Settings::find(&self.start_dir)? {
+            let config_dir = path.parent().unwrap_or_else(|| Path::new(""));
+            candidates.extend(settings.input_dir.map(|dir| config_dir.join(dir)));
+        }
+
+        candidates.extend(
+            self.start_dir
+                .ancestors()
+                .map(|dir| dir.join(INPUT_DIR_NAME)),
+        );
+
+        Ok(candidates)
+    }
+
+    /// First candidate input directory that exists.
+    pub fn input_dir(&self) -> Result<PathBuf, Error> {
+        let candidates = self.candidate_dirs()?;
+
+        candidates
+            .iter()
+            .find(|dir| dir.is_dir())
+            .cloned()
+            .ok_or_else(|| not_found("input directory", &candidates))
+    }
+
+    /// Input file of the `day` of the `year` from the first candidate
+    /// directory that has it.
+    pub fn input_file(&self, year: u16, day: u8) -> Result<PathBuf, Error> {
+        let candidates: Vec<PathBuf> = self
+            .candidate_dirs()?
+            .iter()
+            .map(|dir| input_path(&year_dir(dir, year), day))
+            .collect();
+
+        candidates
+            .iter()
+            .find(|file| file.is_file())
+            .cloned()
+            .ok_or_else(|| {
+                not_found(
+                    &format!("input file for day {} of {}", day, year),
+                    &candidates,
+                )
+            })
+    }
+}
+
+fn not_found(what: &str, tried: &[PathBuf]) -> Error {
+    let tried: Vec<String> = tried
+        .iter()
+        .map(|path| format!("  {}", path.display()))
+        .collect();
+
+    Error::invalid_argument(format!(
+        "cannot find {}, tried:\n{}",
+        what,
+        tried.join("\n")
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sources(start_dir: &Path) -> Sources {
+        Sources {
+            flag: None,
+            env: None,
+            start_dir: start_dir.to_path_buf(),
+        }
+    }
+
+    #[test]
+    fn test_finds_input_dir_in_ancestors() {
+        let root = tempfile::tempdir().unwrap();
+        let nested = root.path().join("Rust").join("src");
+        fs::create_dir_all(&nested).unwrap();
+        fs::create_dir(root.path().join("input")).unwrap();
+        fs::write(root.path().join("input").join("day-07.input"), "").unwrap();
+
+        assert_eq!(
+            sources(&nested).input_file(2020, 7).unwrap(),
+            root.path().join("input").join("day-07.input")
+        );
+    }
+
+    #[test]
+    fn test_later_years_have_their_own_directory() {
+        let root = tempfile::tempdir().unwrap();
+        let input_dir = root.path().join("input");
+        fs::create_dir_all(input_dir.join("2021")).unwrap();
+        fs::write(input_dir.join("day-07.input"), "").unwrap();
+        fs::write(input_dir.join("2021").join("day-07.input"), "").unwrap();
+
+        let sources = sources(root.path());
+        assert_eq!(
+            sources.input_file(2020, 7).unwrap(),
+            input_dir.join("day-07.input")
+        );
+        assert_eq!(
+            sources.input_file(2021, 7).unwrap(),
+            input_dir.join("2021").join("day-07.input")
+        );
+        assert!(sources.input_file(2022, 7).is_err());
+    }
+
+    #[test]
+    fn test_flag_takes_precedence_over_config() {
+        let root = tempfile::tempdir().unwrap();
+        fs::write(root.path().join(CONFIG_FILE), "input_dir = \"puzzles\"").unwrap();
+
+        let mut sources = sources(root.path());
+        sources.flag = Some(PathBuf::from("/from/flag"));
+        sources.env = Some(PathBuf::from("/from/env"));
+
+        let candidates = sources.candidate_dirs().unwrap();
+        assert_eq!(
+            &candidates[..3],
+            &[
+                PathBuf::from("/from/flag"),
+                PathBuf::from("/from/env"),
+                root.path().join("puzzles")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_error_lists_tried_paths() {
+        let root = tempfile::tempdir().unwrap();
+        let message = sources(root.path())
+            .input_file(2020, 3)
+            .unwrap_err()
+            .to_string();
+
+        assert!(message
+            .starts_with("invalid argument: cannot find input file for day 3 of 2020, tried:"));
+        assert!(message.contains(
+            &root
+                .path()
+                .join("input")
+                .join("day-03.input")
+                .display()
+                .to_string()
+        ));
+    }
+}
@@ -1,72 +1,683 @@
 #![warn(clippy::all)]
 
 pub mod config;
-use config::Opts;
+use config::{
+    AllOpts, BenchHistoryOpts, Command, CrossCheckOpts, ExamplesOpts, FetchOpts, GenerateOpts,
+    LintOpts, ListOpts, NewDayOpts, Opts, Part, ServeOpts, SubmitOpts, VerifyOpts,
+};
+
+pub mod crosscheck;
+use crosscheck::Python;
+
+pub mod examples;
+
+pub mod fetch;
+use fetch::{Client, Fetched};
+
+pub mod history;
+use history::{History, Machine, Run};
+
+pub mod input;
+use input::{Settings, Sources};
+
+pub mod manifest;
+use manifest::{Manifest, Status, Verification};
+
+pub mod output;
+use output::Format;
+
+pub mod runner;
+use runner::DayReport;
+
+pub mod serve;
+use serve::Limits;
+
+pub mod scaffold;
 
 mod solutions;
 pub use solutions::*;
 
-use std::error::Error;
+pub mod submit;
+use submit::Store;
+
+pub mod watch;
+use watch::Watcher;
+
+use std::error;
 use std::fs;
-use std::io::{self, Read};
-
-fn available_solutions() -> Vec<Box<dyn Solution>> {
-    vec![
-        Box::new(Day01 {}),
-        Box::new(Day02 {}),
-        Box::new(Day03 {}),
-        Box::new(Day04 {}),
-        Box::new(Day05 {}),
-        Box::new(Day06 {}),
-        Box::new(Day07 {}),
-        Box::new(Day08 {}),
-        Box::new(Day09 {}),
-        Box::new(Day10 {}),
-        Box::new(Day11 {}),
-        Box::new(Day12 {}),
-        Box::new(Day13 {}),
-        Box::new(Day14 {}),
-        Box::new(Day15 {}),
-        Box::new(Day16 {}),
-        Box::new(Day17 {}),
-        Box::new(Day18 {}),
-        Box::new(Day19 {}),
-        Box::new(Day20 {}),
-        Box::new(Day21 {}),
-        Box::new(Day22 {}),
-        Box::new(Day23 {}),
-        Box::new(Day24 {}),
-        Box::new(Day25 {}),
-    ]
-}
-
-fn read_problem_input(filename: &str) -> io::Result<String> {
-    match filename {
-        "-" => {
-            let mut buffer = String::new();
-            io::stdin().read_to_string(&mut buffer)?;
-            Ok(buffer)
-        }
-        filename => fs::read_to_string(filename),
-    }
-}
-
-pub fn run(opts: Opts) -> Result<(), Box<dyn Error>> {
-    let problem_input = read_problem_input(&opts.file)?;
-
-    let solutions = available_solutions();
-    let solution = &solutions[(opts.day - 1) as usize];
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// Solutions of the year from `--year`, or of the first year when it's
+/// not given.
+fn selected_year(year: Option<u16>) -> Result<&'static Year, Error> {
+    match year {
+        Some(year) => solutions::year(year)
+            .ok_or_else(|| Error::invalid_argument(format!("there are no solutions for {}", year))),
+        None => Ok(solutions::first_year()),
+    }
+}
+
+fn find_puzzle(year: &Year, day: u8) -> Result<&'static Puzzle, Error> {
+    year.find(day).ok_or_else(|| {
+        Error::invalid_argument(format!(
+            "there is no solution for day {} of {}",
+            day, year.year
+        ))
+    })
+}
+
+/// Registered puzzles of the `year`, optionally only the ones with the `tag`.
+fn puzzles_with(year: &Year, tag: Option<Tag>) -> Vec<&'static Puzzle> {
+    year.puzzles
+        .iter()
+        .filter(|puzzle| tag.is_none_or(|tag| puzzle.has_tag(tag)))
+        .collect()
+}
+
+fn part_name(part: u8) -> &'static str {
+    match part {
+        1 => "first",
+        _ => "second",
+    }
+}
+
+/// Timeout given in seconds on the command line.
+fn seconds(seconds: f64) -> Result<Duration, Error> {
+    Duration::try_from_secs_f64(seconds)
+        .map_err(|_| Error::invalid_argument(format!("invalid timeout: {} seconds", seconds)))
+}
+
+fn run_single_day(year: &Year, day: u8, opts: &Opts) -> Result<(), Box<dyn error::Error>> {
+    let puzzle = find_puzzle(year, day)?;
+
+    let file = match &opts.file {
+        Some(file) => PathBuf::from(file),
+        None => Sources::from_env(opts.input_dir.as_deref()).input_file(year.year, day)?,
+    };
+    let timeout = opts.timeout.map(seconds).transpose()?;
+    if opts.watch {
+        return watch_single_day(puzzle, &file, opts.part, timeout);
+    }
+
+    let report = runner::within(timeout, || {
+        runner::run_file(day, puzzle.solution, &file, opts.part.numbers())
+    });
+
+    if opts.format != Format::Text {
+        let reports = [report];
+        output::write(&mut io::stdout(), opts.format, &reports)?;
+
+        return ensure_all_solved(&reports);
+    }
+
+    if opts.time {
+        println!("Day {} input read in {:.2?}", day, report.read);
+        println!("Day {} input parsed in {:.2?}", day, report.parse);
+    }
+
+    for part in report.parts {
+        let number = part.part;
+        let answer = part.answer.with_context(|| {
+            format!("cannot solve the {} task of day {}", part_name(number), day)
+        })?;
+
+        if opts.time {
+            println!(
+                "Day {}-{}: {} (solved in {:.2?})",
+                day, number, answer, part.duration
+            );
+        } else {
+            println!("Day {}-{}: {}", day, number, answer);
+        }
+    }
+
+    Ok(())
+}
+
+fn watch_single_day(
+    puzzle: &Puzzle,
+    file: &Path,
+    parts: Part,
+    timeout: Option<Duration>,
+) -> Result<(), Box<dyn error::Error>> {
+    if file == Path::new("-") {
+        return Err(Error::invalid_argument("--watch needs an input file instead of stdin").into());
+    }
+
+    let mut watcher = Watcher::new(file, watch::DEBOUNCE);
+    let mut previous = None;
+    loop {
+        let report = runner::within(timeout, || {
+            runner::run_file(puzzle.day, puzzle.solution, file, parts.numbers())
+        });
+        for line in watch::describe_changes(previous.as_ref(), &report) {
+            println!("{}", line);
+        }
+        previous = Some(report);
+
+        println!("Watching {} for changes...", file.display());
+        watcher.wait_for_change();
+        println!();
+    }
+}
+
+fn ensure_all_solved(reports: &[DayReport]) -> Result<(), Box<dyn error::Error>> {
+    let failed = reports
+        .iter()
+        .flat_map(|r| &r.parts)
+        .filter(|p| p.answer.is_err())
+        .count();
+    let total = reports.iter().map(|r| r.parts.len()).sum::<usize>();
+
+    if failed > 0 {
+        return Err(format!("{} of {} parts failed", failed, total).into());
+    }
+
+    Ok(())
+}
+
+fn run_all_days(year: &Year, all_opts: &AllOpts, opts: &Opts) -> Result<(), Box<dyn error::Error>> {
+    let input_dir = Sources::from_env(opts.input_dir.as_deref()).input_dir()?;
+    let workers = all_opts
+        .jobs
+        .or_else(|| thread::available_parallelism().ok().map(|n| n.get()))
+        .unwrap_or(1);
+    let reports = runner::run_all_parallel(
+        &puzzles_with(year, all_opts.tag),
+        &runner::year_dir(&input_dir, year.year),
+        opts.part.numbers(),
+        all_opts.timeout.map(seconds).transpose()?,
+        workers,
+    );
+    output::write(&mut io::stdout(), opts.format, &reports)?;
+
+    ensure_all_solved(&reports)
+}
+
+fn verify_answers(
+    year: &Year,
+    opts: &VerifyOpts,
+    input_dir: Option<&str>,
+    parts: Part,
+) -> Result<(), Box<dyn error::Error>> {
+    let manifest_path = match &opts.manifest {
+        Some(manifest) => PathBuf::from(manifest),
+        None => runner::year_dir(&Sources::from_env(input_dir).input_dir()?, year.year)
+            .join("answers.json"),
+    };
+    let manifest = Manifest::load(&manifest_path)?;
+    let base_dir = manifest_path.parent().unwrap_or_else(|| Path::new(""));
+
+    let verifications = manifest::verify(&manifest, base_dir, year.puzzles, parts.numbers());
+
+    print_verifications(&verifications)
+}
+
+/// Print one line per verification and a summary, failing when any answer
+/// doesn't match.
+fn print_verifications(verifications: &[Verification]) -> Result<(), Box<dyn error::Error>> {
+    let mut mismatched = 0;
+    let mut failed = 0;
+    for v in verifications {
+        let prefix = format!("Day {}-{} ({})", v.day, v.part, v.input);
+
+        match (v.status(), &v.actual) {
+            (Status::Match, _) => println!("{}: ok", prefix),
+            (Status::Mismatch, Ok(actual)) => {
+                mismatched += 1;
+                println!(
+                    "{}: MISMATCH expected \"{}\", got \"{}\"",
+                    prefix, v.expected, actual
+                );
+            }
+            (_, actual) => {
+                failed += 1;
+                let reason = actual.as_ref().err().map(|err| output::describe(err));
+                println!("{}: FAILED {}", prefix, reason.unwrap_or_default());
+            }
+        }
+    }
 
     println!(
-        "Day {}-1: {}",
+        "Verified {} answers: {} ok, {} mismatched, {} failed",
+        verifications.len(),
+        verifications.len() - mismatched - failed,
+        mismatched,
+        failed
+    );
+
+    if mismatched + failed > 0 {
+        return Err(format!("{} answers don't match", mismatched + failed).into());
+    }
+
+    Ok(())
+}
+
+fn check_examples(
+    year: &Year,
+    opts: &ExamplesOpts,
+    parts: Part,
+) -> Result<(), Box<dyn error::Error>> {
+    let verifications = examples::verify(&puzzles_with(year, opts.tag), parts.numbers());
+
+    print_verifications(&verifications)
+}
+
+fn lint_inputs(
+    year: &Year,
+    opts: &LintOpts,
+    input_dir: Option<&str>,
+) -> Result<(), Box<dyn error::Error>> {
+    let sources = Sources::from_env(input_dir);
+    let files = match opts.day {
+        Some(day) => {
+            let puzzle = find_puzzle(year, day)?;
+            let file = match &opts.file {
+                Some(file) => PathBuf::from(file),
+                None => sources.input_file(year.year, day)?,
+            };
+            vec![(puzzle, file)]
+        }
+        None => {
+            let input_dir = runner::year_dir(&sources.input_dir()?, year.year);
+            year.puzzles
+                .iter()
+                .map(|puzzle| (puzzle, runner::input_path(&input_dir, puzzle.day)))
+                .filter(|(_, file)| file.exists())
+                .collect()
+        }
+    };
+
+    let mut problems = 0;
+    for (puzzle, file) in &files {
+        let input = runner::read_input(file)
+            .map_err(|err| format!("cannot read {}: {}", file.display(), err))?;
+        let issues = puzzle.lint(&input);
+
+        for issue in &issues {
+            println!("{}:{}", file.display(), issue);
+        }
+        problems += issues.len();
+    }
+
+    println!("Checked {} inputs: {} problems", files.len(), problems);
+
+    if problems > 0 {
+        return Err(format!("{} problems found", problems).into());
+    }
+
+    Ok(())
+}
+
+fn generate_inputs(year: &Year, opts: &GenerateOpts) -> Result<(), Box<dyn error::Error>> {
+    let puzzles = match opts.day {
+        Some(day) => vec![find_puzzle(year, day)?],
+        None if opts.output_dir.is_some() => year.puzzles.iter().collect(),
+        None => {
+            return Err(Error::invalid_argument(
+                "--output-dir is required to generate inputs for every day",
+            )
+            .into())
+        }
+    };
+
+    for puzzle in puzzles {
+        let generator = puzzle.generator;
+        let size = opts.size.unwrap_or(generator.default_size);
+        let input = generator
+            .input(opts.seed, size)
+            .with_context(|| format!("day {}", puzzle.day))?;
+
+        match &opts.output_dir {
+            Some(output_dir) => {
+                let output_dir = runner::year_dir(Path::new(output_dir), year.year);
+                fs::create_dir_all(&output_dir)?;
+
+                let file = runner::input_path(&output_dir, puzzle.day);
+                fs::write(&file, input)
+                    .map_err(|err| format!("cannot write {}: {}", file.display(), err))?;
+                println!("{}", file.display());
+            }
+            None => io::stdout().write_all(input.as_bytes())?,
+        }
+    }
+
+    Ok(())
+}
+
+fn cross_check(
+    year: &Year,
+    opts: &CrossCheckOpts,
+    input_dir: Option<&str>,
+    parts: Part,
+) -> Result<(), Box<dyn error::Error>> {
+    if year.year != crosscheck::YEAR {
+        return Err(Error::invalid_argument(format!(
+            "there are Python solutions for {} only",
+            crosscheck::YEAR
+        ))
+        .into());
+    }
+
+    let start_dir = std::env::current_dir().unwrap_or_default();
+    let python = Python::find(
+        &opts.python,
+        opts.python_dir.as_deref().map(Path::new),
+        &start_dir,
+    )?;
+
+    let puzzles = match opts.day {
+        Some(day) => vec![find_puzzle(year, day)?],
+        None => year.puzzles.iter().collect(),
+    };
+
+    let timeout = opts.timeout.map(seconds).transpose()?;
+
+    let mut verifications = vec![];
+    for puzzle in puzzles {
+        let (name, input) = if opts.generated {
+            let generator = puzzle.generator;
+            let size = opts.size.unwrap_or(generator.default_size);
+            let input = generator
+                .input(opts.seed, size)
+                .with_context(|| format!("day {}", puzzle.day))?;
+
+            (format!("generated, seed {}", opts.seed), input)
+        } else {
+            let sources = Sources::from_env(input_dir);
+            let file = match (&opts.file, opts.day) {
+                (Some(file), _) => PathBuf::from(file),
+                (None, Some(day)) => sources.input_file(year.year, day)?,
+                (None, None) => {
+                    let year_dir = runner::year_dir(&sources.input_dir()?, year.year);
+                    runner::input_path(&year_dir, puzzle.day)
+                }
+            };
+            if opts.day.is_none() && !file.exists() {
+                continue;
+            }
+            let input = runner::read_input(&file)
+                .map_err(|err| format!("cannot read {}: {}", file.display(), err))?;
+
+            (file.display().to_string(), input)
+        };
+
+        verifications.extend(crosscheck::verify(
+            &python,
+            puzzle,
+            &name,
+            &input,
+            parts.numbers(),
+            timeout,
+        ));
+    }
+
+    print_verifications(&verifications)
+}
+
+/// Client of the server at `base_url`, then at "base_url" from the config
+/// file, with the session token from the environment or the config file.
+fn client(
+    sources: &Sources,
+    base_url: Option<&str>,
+    interval: Duration,
+) -> Result<Client, Box<dyn error::Error>> {
+    let settings = Settings::find(&sources.start_dir)?.map(|(_, settings)| settings);
+    let session = fetch::session(std::env::var(fetch::SESSION_ENV).ok(), settings.as_ref())?;
+    let base_url = base_url
+        .map(String::from)
+        .or_else(|| settings.and_then(|s| s.base_url))
+        .unwrap_or_else(|| String::from(fetch::DEFAULT_BASE_URL));
+
+    Ok(Client::new(&base_url, &session, interval))
+}
+
+fn fetch_inputs(
+    year: &Year,
+    opts: &FetchOpts,
+    input_dir: Option<&str>,
+) -> Result<(), Box<dyn error::Error>> {
+    let sources = Sources::from_env(input_dir);
+    let mut client = client(&sources, opts.base_url.as_deref(), seconds(opts.interval)?)?;
+
+    let input_dir = match &sources.flag {
+        Some(dir) => {
+            fs::create_dir_all(dir)?;
+            dir.clone()
+        }
+        None => sources.input_dir()?,
+    };
+    let days: Vec<u8> = match opts.day {
+        Some(day) => vec![day],
+        None => year.puzzles.iter().map(|puzzle| puzzle.day).collect(),
+    };
+
+    for day in days {
+        match fetch::fetch(&mut client, &input_dir, year.year, day)? {
+            Fetched::Cached(file) => println!("{} (cached)", file.display()),
+            Fetched::Downloaded(file) => println!("{}", file.display()),
+        }
+    }
+
+    Ok(())
+}
+
+fn submit_answer(
+    year: &Year,
+    opts: &SubmitOpts,
+    input_dir: Option<&str>,
+    part: Part,
+) -> Result<(), Box<dyn error::Error>> {
+    let part = match part {
+        Part::First => 1,
+        Part::Second => 2,
+        Part::Both => {
+            return Err(Error::invalid_argument("choose the part to submit with --part").into())
+        }
+    };
+    let puzzle = find_puzzle(year, opts.day)?;
+
+    let sources = Sources::from_env(input_dir);
+    let file = match &opts.file {
+        Some(file) => PathBuf::from(file),
+        None => sources.input_file(year.year, opts.day)?,
+    };
+    let store_path = match &opts.store {
+        Some(store) => PathBuf::from(store),
+        None => runner::year_dir(&sources.input_dir()?, year.year).join(submit::STORE_FILE),
+    };
+
+    let report = runner::run_file(opts.day, puzzle.solution, &file, &[part]);
+    let answer = match report.parts.into_iter().next() {
+        Some(solved) => solved.answer.with_context(|| {
+            format!(
+                "cannot solve the {} task of day {}",
+                part_name(part),
+                opts.day
+            )
+        })?,
+        None => return Err(Error::internal("the part wasn't solved").into()),
+    };
+    let answer = answer.to_string();
+
+    let mut store = Store::load(&store_path)?;
+    let mut client = client(&sources, opts.base_url.as_deref(), Duration::default())?;
+    println!("Day {}-{}: {}", opts.day, part, answer);
+    let outcome = submit::submit(
+        &mut client,
+        &mut store,
+        year.year,
         opts.day,
-        solution.first_task(&problem_input)?
+        part,
+        &answer,
+        |warning| eprintln!("Warning: {}", warning),
+    )?;
+
+    println!("{}: {}", submit::describe(outcome.verdict), outcome.message);
+    if outcome.verdict != submit::Verdict::Right {
+        return Err(format!("answer {} was not accepted", answer).into());
+    }
+
+    Ok(())
+}
+
+fn new_day(opts: &NewDayOpts, year: Option<u16>) -> Result<(), Box<dyn error::Error>> {
+    let crate_dir = opts
+        .crate_dir
+        .as_deref()
+        .unwrap_or(env!("CARGO_MANIFEST_DIR"));
+    let year = year.unwrap_or(solutions::FIRST_YEAR);
+    let scaffold = scaffold::new_day(Path::new(crate_dir), year, opts.day, &opts.title)?;
+
+    for file in &scaffold.created {
+        println!("created {}", file.display());
+    }
+    for file in &scaffold.updated {
+        println!("updated {}", file.display());
+    }
+
+    Ok(())
+}
+
+fn serve_solutions(year: &Year, opts: &ServeOpts) -> Result<(), Box<dyn error::Error>> {
+    let limits = Limits {
+        max_body_size: opts.max_body_size,
+        timeout: seconds(opts.timeout)?,
+    };
+    let workers = opts
+        .jobs
+        .or_else(|| thread::available_parallelism().ok().map(|n| n.get()))
+        .unwrap_or(1);
+
+    let server = serve::bind(&opts.address)?;
+    println!(
+        "Listening on http://{} with solutions of {}",
+        opts.address, year.year
     );
+    serve::run(server, year, limits, workers);
+
+    Ok(())
+}
+
+fn bench_history(
+    year: &Year,
+    opts: &BenchHistoryOpts,
+    input_dir: Option<&str>,
+    parts: Part,
+) -> Result<(), Box<dyn error::Error>> {
+    let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let history_path = match &opts.history {
+        Some(history) => PathBuf::from(history),
+        None => crate_dir.join(history::HISTORY_FILE),
+    };
+    let mut history = History::load(&history_path)?;
+    let machine = Machine::current();
+
+    if !opts.report_only {
+        if opts.samples == 0 {
+            return Err(Error::invalid_argument("--samples must be at least 1").into());
+        }
+
+        let input_dir = runner::year_dir(&Sources::from_env(input_dir).input_dir()?, year.year);
+        let puzzles: Vec<&Puzzle> = year.puzzles.iter().collect();
+        let timings = history::measure(&puzzles, &input_dir, parts.numbers(), opts.samples)?;
+        let recorded_at = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_or(0, |since| since.as_secs());
+
+        history.record(Run {
+            revision: history::revision(crate_dir),
+            machine: machine.clone(),
+            year: year.year,
+            samples: opts.samples,
+            recorded_at,
+            timings,
+        })?;
+    }
+
+    let runs = history.runs_on(&machine, year.year);
     println!(
-        "Day {}-2: {}",
-        opts.day,
-        solution.second_task(&problem_input)?
+        "{} runs of {} on {}",
+        runs.len(),
+        year.year,
+        machine.description
     );
+    let recent = &runs[runs.len().saturating_sub(opts.last.max(1))..];
+    history::write_trend(&mut io::stdout(), recent, opts.threshold)?;
+
+    let regressions = match runs.as_slice() {
+        [.., previous, latest] => history::regressions(previous, latest, opts.threshold),
+        _ => vec![],
+    };
+    if !regressions.is_empty() {
+        return Err(format!(
+            "{} parts regressed by more than {}%",
+            regressions.len(),
+            opts.threshold
+        )
+        .into());
+    }
 
     Ok(())
 }
+
+fn list_puzzles(year: &Year, opts: &ListOpts) {
+    for puzzle in puzzles_with(year, opts.tag) {
+        let tags: Vec<&str> = puzzle.tags.iter().map(|tag| tag.name()).collect();
+        let tags = if tags.is_empty() {
+            String::from("-")
+        } else {
+            tags.join(", ")
+        };
+
+        println!("Day {:02}  {:<25}  {}", puzzle.day, puzzle.title, tags);
+    }
+}
+
+pub fn run(opts: Opts) -> Result<(), Box<dyn error::Error>> {
+    // Only a new day may be added to a year without solutions yet.
+    let year = || selected_year(opts.year);
+
+    match (&opts.command, opts.day) {
+        (Some(Command::All(all_opts)), _) => run_all_days(year()?, all_opts, &opts),
+        (Some(Command::Verify(verify_opts)), _) => {
+            verify_answers(year()?, verify_opts, opts.input_dir.as_deref(), opts.part)
+        }
+        (Some(Command::Examples(examples_opts)), _) => {
+            check_examples(year()?, examples_opts, opts.part)
+        }
+        (Some(Command::Lint(lint_opts)), _) => {
+            lint_inputs(year()?, lint_opts, opts.input_dir.as_deref())
+        }
+        (Some(Command::Generate(generate_opts)), _) => generate_inputs(year()?, generate_opts),
+        (Some(Command::CrossCheck(cross_check_opts)), _) => cross_check(
+            year()?,
+            cross_check_opts,
+            opts.input_dir.as_deref(),
+            opts.part,
+        ),
+        (Some(Command::Fetch(fetch_opts)), _) => {
+            fetch_inputs(year()?, fetch_opts, opts.input_dir.as_deref())
+        }
+        (Some(Command::Submit(submit_opts)), _) => {
+            submit_answer(year()?, submit_opts, opts.input_dir.as_deref(), opts.part)
+        }
+        (Some(Command::NewDay(new_day_opts)), _) => new_day(new_day_opts, opts.year),
+        (Some(Command::Serve(serve_opts)), _) => serve_solutions(year()?, serve_opts),
+        (Some(Command::BenchHistory(bench_history_opts)), _) => bench_history(
+            year()?,
+            bench_history_opts,
+            opts.input_dir.as_deref(),
+            opts.part,
+        ),
+        (Some(Command::List(list_opts)), _) => {
+            list_puzzles(year()?, list_opts);
+            Ok(())
+        }
+        (None, Some(day)) => run_single_day(year()?, day, &opts),
+        _ => Err(Error::invalid_argument("day is required").into()),
+    }
+}
@@ -13,6 +13,12 @@ fn main() {
     if let Err(err) = aoc2020::run(opts) {
         eprintln!("Application error: {}", err);
 
+        let mut source = err.source();
+        while let Some(cause) = source {
+            eprintln!("  caused by: {}", cause);
+            source = cause.source();
+        }
+
         process::exit(1);
     }
 }
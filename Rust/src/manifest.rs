@@ -0,0 +1,180 @@
+use crate::runner;
+use crate::solutions::{Answer, Error, Puzzle};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// Known-correct answers for one input file. `input` is relative to the
+/// directory of the manifest.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Entry {
+    pub day: u8,
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Entry {
+    fn expected(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            _ => self.part2.as_deref(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Manifest {
+    pub entries: Vec<Entry>,
+}
+
+impl Manifest {
+    pub fn load(path: &Path) -> Result<Manifest, Error> {
+        let text = fs::read_to_string(path).map_err(|err| {
+            Error::invalid_argument(format!("cannot read {}: {}", path.display(), err))
+        })?;
+
+        serde_json::from_str(&text).map_err(|err| {
+            Error::invalid_argument(format!("malformed manifest {}: {}", path.display(), err))
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Status {
+    Match,
+    Mismatch,
+    Failed,
+}
+
+pub struct Verification {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub expected: String,
+    pub actual: Result<Answer, Error>,
+}
+
+impl Verification {
+    pub fn status(&self) -> Status {
+        match &self.actual {
+            Ok(actual) if actual.to_string() == self.expected => Status::Match,
+            Ok(_) => Status::Mismatch,
+            Err(_) => Status::Failed,
+        }
+    }
+}
+
+/// Run every manifest entry with a solution for its day from `puzzles` and
+/// compare the answers for the selected `parts`.
+pub fn verify(
+    manifest: &Manifest,
+    base_dir: &Path,
+    puzzles: &[Puzzle],
+    parts: &[u8],
+) -> Vec<Verification> {
+    let mut verifications = vec![];
+
+    for entry in &manifest.entries {
+        let parts: Vec<u8> = parts
+            .iter()
+            .copied()
+            .filter(|&part| entry.expected(part).is_some())
+            .collect();
+
+        let answers: Vec<(u8, Result<Answer, Error>)> =
+            match puzzles.iter().find(|puzzle| puzzle.day == entry.day) {
+                Some(puzzle) => {
+                    let input = base_dir.join(&entry.input);
+                    runner::run_file(entry.day, puzzle.solution, &input, &parts)
+                        .parts
+                        .into_iter()
+                        .map(|p| (p.part, p.answer))
+                        .collect()
+                }
+                None => parts
+                    .iter()
+                    .map(|&part| {
+                        let message = format!("there is no solution for day {}", entry.day);
+                        (part, Err(Error::invalid_argument(message)))
+                    })
+                    .collect(),
+            };
+
+        for (part, actual) in answers {
+            verifications.push(Verification {
+                day: entry.day,
+                part,
+                input: entry.input.clone(),
+                expected: entry.expected(part).unwrap_or_default().to_string(),
+                actual,
+            });
+        }
+    }
+
+    verifications
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::{year_2020, Challenge, InputFormat, Pattern};
+
+    struct Constant {}
+
+    impl Challenge for Constant {
+        type Input<'a> = &'a str;
+
+        fn parse<'a>(&self, input: &'a str) -> Result<&'a str, Error> {
+            Ok(input.lines().next().unwrap_or_default())
+        }
+
+        fn first_part(&self, line: &&str) -> Result<Answer, Error> {
+            Ok((*line).into())
+        }
+
+        fn second_part(&self, _: &&str) -> Result<Answer, Error> {
+            Err(Error::no_solution(1, "never"))
+        }
+    }
+
+    #[test]
+    fn test_verify_reports_every_status() {
+        let manifest: Manifest = serde_json::from_str(
+            r#"{ "entries": [
+                { "day": 1, "input": "Cargo.toml", "part1": "[package]", "part2": "x" },
+                { "day": 1, "input": "Cargo.toml", "part1": "wrong" },
+                { "day": 2, "input": "Cargo.toml", "part1": "no solution for day 2" }
+            ] }"#,
+        )
+        .unwrap();
+        let puzzles = [Puzzle {
+            day: 1,
+            title: "Constant",
+            tags: &[],
+            solution: &Constant {},
+            examples: &[],
+            format: &InputFormat::Lines(Pattern::Word),
+            generator: year_2020::PUZZLES[0].generator,
+        }];
+
+        let verifications = verify(
+            &manifest,
+            Path::new(env!("CARGO_MANIFEST_DIR")),
+            &puzzles,
+            &[1, 2],
+        );
+
+        let statuses: Vec<(u8, Status)> =
+            verifications.iter().map(|v| (v.part, v.status())).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                (1, Status::Match),
+                (2, Status::Failed),
+                (1, Status::Mismatch),
+                (1, Status::Failed)
+            ]
+        );
+    }
+}
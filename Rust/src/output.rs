@@ -0,0 +1,211 @@
+use crate::runner::{DayReport, PartReport};
+use serde::Serialize;
+use std::error;
+use std::io::{self, Write};
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            format => Err(format!("Unsupported output format: {}", format)),
+        }
+    }
+}
+
+/// One solved (or failed) part in a machine-readable form.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub duration_ms: f64,
+    pub status: &'static str,
+    pub error: Option<String>,
+}
+
+/// Error message followed by all its causes.
+pub fn describe(err: &dyn error::Error) -> String {
+    let mut description = err.to_string();
+    let mut source = err.source();
+
+    while let Some(cause) = source {
+        description.push_str(": ");
+        description.push_str(&cause.to_string());
+        source = cause.source();
+    }
+
+    description
+}
+
+pub fn records(reports: &[DayReport]) -> Vec<Record> {
+    reports
+        .iter()
+        .flat_map(|r| r.parts.iter().map(move |p| (r.day, p)))
+        .map(|(day, p)| Record {
+            day,
+            part: p.part,
+            answer: p.answer.as_ref().ok().map(|a| a.to_string()),
+            duration_ms: p.duration.as_micros() as f64 / 1000.0,
+            status: p.status(),
+            error: p.answer.as_ref().err().map(|err| describe(err)),
+        })
+        .collect()
+}
+
+pub fn write_json(out: &mut dyn Write, reports: &[DayReport]) -> io::Result<()> {
+    serde_json::to_writer_pretty(&mut *out, &records(reports))?;
+    writeln!(out)
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+pub fn write_csv(out: &mut dyn Write, reports: &[DayReport]) -> io::Result<()> {
+    writeln!(out, "day,part,answer,duration_ms,status,error")?;
+
+    for record in records(reports) {
+        writeln!(
+            out,
+            "{},{},{},{:.3},{},{}",
+            record.day,
+            record.part,
+            csv_field(record.answer.as_deref().unwrap_or_default()),
+            record.duration_ms,
+            record.status,
+            csv_field(record.error.as_deref().unwrap_or_default()),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Summary table for humans. Errors are listed below the table.
+pub fn write_table(out: &mut dyn Write, reports: &[DayReport]) -> io::Result<()> {
+    let parts: Vec<(u8, &PartReport)> = reports
+        .iter()
+        .flat_map(|r| r.parts.iter().map(move |p| (r.day, p)))
+        .collect();
+
+    let answers: Vec<String> = parts
+        .iter()
+        .map(|(_, p)| {
+            p.answer
+                .as_ref()
+                .map_or(String::from("-"), |a| a.to_string())
+        })
+        .collect();
+    let answer_width = answers
+        .iter()
+        .map(|a| a.len())
+        .chain(Some("Answer".len()))
+        .max()
+        .unwrap_or_default();
+
+    writeln!(
+        out,
+        "Day  Part  {:<width$}  Status  Time",
+        "Answer",
+        width = answer_width
+    )?;
+    for ((day, p), answer) in parts.iter().zip(&answers) {
+        writeln!(
+            out,
+            "{:>3}  {:>4}  {:<width$}  {:<6}  {:>10}",
+            day,
+            p.part,
+            answer,
+            p.status(),
+            format!("{:.2?}", p.duration),
+            width = answer_width
+        )?;
+    }
+
+    for (day, p) in &parts {
+        if let Err(err) = &p.answer {
+            writeln!(out, "Day {}-{}: {}", day, p.part, describe(err))?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn write(out: &mut dyn Write, format: Format, reports: &[DayReport]) -> io::Result<()> {
+    match format {
+        Format::Text => write_table(out, reports),
+        Format::Json => write_json(out, reports),
+        Format::Csv => write_csv(out, reports),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::{Answer, Error};
+    use std::time::Duration;
+
+    fn test_reports() -> Vec<DayReport> {
+        vec![DayReport {
+            day: 21,
+            read: Duration::default(),
+            parse: Duration::default(),
+            parts: vec![
+                PartReport {
+                    part: 1,
+                    answer: Ok(Answer::list(vec!["mxmxvkd", "sqjhc", "fvjkl"], ",")),
+                    duration: Duration::from_micros(1500),
+                },
+                PartReport {
+                    part: 2,
+                    answer: Err(Error::no_solution(21, "say \"hi\"")),
+                    duration: Duration::from_micros(20),
+                },
+            ],
+        }]
+    }
+
+    #[test]
+    fn test_write_csv() {
+        let mut out = vec![];
+        write_csv(&mut out, &test_reports()).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "day,part,answer,duration_ms,status,error
+21,1,\"mxmxvkd,sqjhc,fvjkl\",1.500,ok,
+21,2,,0.020,failed,\"day 21: no solution found: say \"\"hi\"\"\"
+"
+        );
+    }
+
+    #[test]
+    fn test_write_json() {
+        let mut out = vec![];
+        write_json(&mut out, &test_reports()).unwrap();
+
+        let json: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(json[0]["answer"], "mxmxvkd,sqjhc,fvjkl");
+        assert_eq!(json[0]["status"], "ok");
+        assert_eq!(json[0]["error"], serde_json::Value::Null);
+        assert_eq!(json[1]["day"], 21);
+        assert_eq!(json[1]["part"], 2);
+        assert_eq!(json[1]["status"], "failed");
+        assert_eq!(json[1]["error"], "day 21: no solution found: say \"hi\"");
+    }
+}
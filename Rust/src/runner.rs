@@ -0,0 +1,469 @@
+use crate::solutions::{
+    check_cancelled, Answer, Cancellation, Error, Parsed, Puzzle, Solution, FIRST_YEAR,
+};
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::fs;
+use std::io::{self, Read};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Once};
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub struct PartReport {
+    pub part: u8,
+    pub answer: Result<Answer, Error>,
+    pub duration: Duration,
+}
+
+impl PartReport {
+    pub fn status(&self) -> &'static str {
+        match self.answer {
+            Ok(_) => "ok",
+            Err(_) => "failed",
+        }
+    }
+}
+
+pub struct DayReport {
+    pub day: u8,
+    /// Time spent reading the input.
+    pub read: Duration,
+    /// Time spent parsing the input.
+    pub parse: Duration,
+    pub parts: Vec<PartReport>,
+}
+
+impl DayReport {
+    pub fn is_ok(&self) -> bool {
+        self.parts.iter().all(|p| p.answer.is_ok())
+    }
+}
+
+/// Directory with the inputs of the `year`. Inputs of the first year are
+/// right in `input_dir`, inputs of any other year are in its "YYYY"
+/// subdirectory.
+pub fn year_dir(input_dir: &Path, year: u16) -> PathBuf {
+    if year == FIRST_YEAR {
+        input_dir.to_path_buf()
+    } else {
+        input_dir.join(year.to_string())
+    }
+}
+
+pub fn input_path(input_dir: &Path, day: u8) -> PathBuf {
+    input_dir.join(format!("day-{:02}.input", day))
+}
+
+/// Read the whole input file. "-" refers to stdin.
+pub fn read_input(file: &Path) -> io::Result<String> {
+    if file == Path::new("-") {
+        let mut buffer = String::new();
+        io::stdin().read_to_string(&mut buffer)?;
+        Ok(buffer)
+    } else {
+        fs::read_to_string(file)
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown panic payload"
+    }
+}
+
+thread_local! {
+    /// Whether panics on the thread are caught by `catch_panic`.
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    /// Location of the last caught panic on the thread.
+    static LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+static PANIC_HOOK: Once = Once::new();
+
+/// Remember where caught panics happen instead of printing them. Other
+/// panics are reported by the previous hook as usual.
+fn install_panic_hook() {
+    PANIC_HOOK.call_once(|| {
+        let previous = panic::take_hook();
+
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.with(Cell::get) {
+                let location = info.location().map(|location| location.to_string());
+                LOCATION.with(|last| *last.borrow_mut() = location);
+            } else {
+                previous(info);
+            }
+        }));
+    });
+}
+
+/// Run `f`, turning a panic into an error with the message and location of
+/// the panic.
+fn catch_panic<T>(day: u8, f: impl FnOnce() -> Result<T, Error>) -> Result<T, Error> {
+    install_panic_hook();
+
+    let was_catching = CATCHING.with(|catching| catching.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.with(|catching| catching.set(was_catching));
+
+    result.unwrap_or_else(|payload| {
+        let location = LOCATION.with(|last| last.borrow_mut().take());
+        Err(Error::panicked(
+            day,
+            panic_message(payload.as_ref()),
+            location,
+        ))
+    })
+}
+
+/// Solve the `part`. A part started or answered after the current
+/// cancellation token of the thread is cancelled fails with a "timed out"
+/// error.
+fn run_part(day: u8, parsed: &dyn Parsed, part: u8) -> PartReport {
+    let started = Instant::now();
+    let answer = check_cancelled(day)
+        .and_then(|()| {
+            catch_panic(day, || match part {
+                1 => parsed.first_part(),
+                _ => parsed.second_part(),
+            })
+        })
+        .and_then(|answer| check_cancelled(day).map(|()| answer));
+
+    PartReport {
+        part,
+        answer,
+        duration: started.elapsed(),
+    }
+}
+
+/// Parse the input once and solve the given `parts` of a day with it.
+/// A panic inside the solution is reported as a failed part instead of
+/// unwinding further. When parsing fails, every part fails with its error.
+pub fn run_day(day: u8, solution: &dyn Solution, input: &str, parts: &[u8]) -> DayReport {
+    let started = Instant::now();
+    let parsed = catch_panic(day, || solution.prepare(input))
+        .and_then(|parsed| check_cancelled(day).map(|()| parsed));
+    let parse = started.elapsed();
+
+    let parts = match parsed {
+        Ok(parsed) => parts
+            .iter()
+            .map(|&part| run_part(day, parsed.as_ref(), part))
+            .collect(),
+        Err(err) => parts
+            .iter()
+            .map(|&part| PartReport {
+                part,
+                answer: Err(err.clone()),
+                duration: Duration::default(),
+            })
+            .collect(),
+    };
+
+    DayReport {
+        day,
+        read: Duration::default(),
+        parse,
+        parts,
+    }
+}
+
+/// Read the input `file` and solve the given `parts` of a day with it.
+pub fn run_file(day: u8, solution: &dyn Solution, file: &Path, parts: &[u8]) -> DayReport {
+    let started = Instant::now();
+
+    match read_input(file) {
+        Ok(input) => {
+            let read = started.elapsed();
+
+            DayReport {
+                read,
+                ..run_day(day, solution, &input, parts)
+            }
+        }
+        Err(err) => DayReport {
+            day,
+            read: started.elapsed(),
+            parse: Duration::default(),
+            parts: parts
+                .iter()
+                .map(|&part| PartReport {
+                    part,
+                    answer: Err(Error::invalid_argument(format!(
+                        "cannot read {}: {}",
+                        file.display(),
+                        err
+                    ))),
+                    duration: Duration::default(),
+                })
+                .collect(),
+        },
+    }
+}
+
+/// Run `f`, cancelling solutions that take longer than the `timeout`. The
+/// `timeout` covers everything `f` does, e.g. reading and parsing the input
+/// and solving both parts of a day together. Cancelled solutions fail with
+/// a "timed out" error, and so do parts parsed, started or answered after
+/// the `timeout`, so a late answer is never reported as a success.
+pub fn within<T>(timeout: Option<Duration>, f: impl FnOnce() -> T) -> T {
+    match timeout {
+        Some(timeout) => Cancellation::after(timeout).run(f),
+        None => f(),
+    }
+}
+
+fn run_puzzle(
+    puzzle: &Puzzle,
+    input_dir: &Path,
+    parts: &[u8],
+    timeout: Option<Duration>,
+) -> DayReport {
+    let file = input_path(input_dir, puzzle.day);
+    within(timeout, || {
+        run_file(puzzle.day, puzzle.solution, &file, parts)
+    })
+}
+
+/// Solve every puzzle with its "day-NN.input" file from `input_dir`. Every
+/// day gets at most `timeout` to solve its parts.
+pub fn run_all(
+    puzzles: &[&Puzzle],
+    input_dir: &Path,
+    parts: &[u8],
+    timeout: Option<Duration>,
+) -> Vec<DayReport> {
+    puzzles
+        .iter()
+        .map(|puzzle| run_puzzle(puzzle, input_dir, parts, timeout))
+        .collect()
+}
+
+/// Same as `run_all`, but days are solved by a pool of `workers` threads.
+/// Reports are still returned in the order of `puzzles`. Days compete for cores, so
+/// timings are less precise than in a sequential run.
+pub fn run_all_parallel(
+    puzzles: &[&Puzzle],
+    input_dir: &Path,
+    parts: &[u8],
+    timeout: Option<Duration>,
+    workers: usize,
+) -> Vec<DayReport> {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..workers.clamp(1, puzzles.len().max(1)) {
+            let sender = sender.clone();
+            let next = &next;
+
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let puzzle = match puzzles.get(index) {
+                    Some(puzzle) => puzzle,
+                    None => break,
+                };
+
+                let report = run_puzzle(puzzle, input_dir, parts, timeout);
+
+                if sender.send((index, report)).is_err() {
+                    break;
+                }
+            });
+        }
+    });
+    drop(sender);
+
+    let mut reports: Vec<(usize, DayReport)> = receiver.into_iter().collect();
+    reports.sort_by_key(|(index, _)| *index);
+    reports.into_iter().map(|(_, report)| report).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::{year_2020, Challenge, InputFormat, Pattern, Steps};
+
+    struct Panicking {}
+
+    impl Challenge for Panicking {
+        type Input<'a> = ();
+
+        fn parse(&self, _: &str) -> Result<(), Error> {
+            Ok(())
+        }
+
+        fn first_part(&self, _: &()) -> Result<Answer, Error> {
+            Ok(Answer::from(42))
+        }
+
+        fn second_part(&self, _: &()) -> Result<Answer, Error> {
+            panic!("Unrecognized cell value: {}", '?')
+        }
+    }
+
+    #[test]
+    fn test_panic_fails_only_its_part() {
+        let report = run_day(11, &Panicking {}, "", &[1, 2]);
+
+        assert_eq!(
+            report.parts[0].answer.as_ref().unwrap(),
+            &Answer::Integer(42)
+        );
+        let err = report.parts[1].answer.as_ref().unwrap_err().to_string();
+        assert!(
+            err.starts_with("day 11: solution panicked at src/runner.rs:"),
+            "{}",
+            err
+        );
+        assert!(err.ends_with(": Unrecognized cell value: ?"), "{}", err);
+        assert!(!report.is_ok());
+    }
+
+    #[test]
+    fn test_panic_fails_only_its_day() {
+        let puzzles = [puzzle(1, &Panicking {}), puzzle(2, &Sleepy { millis: 0 })];
+        let puzzles: Vec<&Puzzle> = puzzles.iter().collect();
+        let input_dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join("input");
+
+        let reports = run_all_parallel(&puzzles, &input_dir, &[1, 2], None, 2);
+
+        let statuses: Vec<Vec<&str>> = reports
+            .iter()
+            .map(|r| r.parts.iter().map(|p| p.status()).collect())
+            .collect();
+        assert_eq!(statuses, vec![vec!["ok", "failed"], vec!["ok", "ok"]]);
+    }
+
+    fn puzzle(day: u8, solution: &'static dyn Solution) -> Puzzle {
+        Puzzle {
+            day,
+            title: "Test",
+            tags: &[],
+            solution,
+            examples: &[],
+            format: &InputFormat::Lines(Pattern::Word),
+            generator: year_2020::PUZZLES[0].generator,
+        }
+    }
+
+    #[test]
+    fn test_missing_input_fails_day() {
+        let puzzle = puzzle(11, &Panicking {});
+        let reports = run_all(&[&puzzle], Path::new("/nonexistent"), &[1, 2], None);
+
+        assert_eq!(reports.len(), 1);
+        assert!(reports[0].parts.iter().all(|p| p.status() == "failed"));
+    }
+
+    struct Sleepy {
+        millis: u64,
+    }
+
+    impl Challenge for Sleepy {
+        type Input<'a> = ();
+
+        fn parse(&self, _: &str) -> Result<(), Error> {
+            Ok(())
+        }
+
+        fn first_part(&self, _: &()) -> Result<Answer, Error> {
+            thread::sleep(Duration::from_millis(self.millis));
+            Ok(self.millis.into())
+        }
+
+        fn second_part(&self, _: &()) -> Result<Answer, Error> {
+            Ok(Answer::from(""))
+        }
+    }
+
+    #[test]
+    fn test_parallel_reports_in_day_order() {
+        let puzzles = [
+            puzzle(1, &Sleepy { millis: 30 }),
+            puzzle(2, &Sleepy { millis: 0 }),
+            puzzle(3, &Sleepy { millis: 20 }),
+            puzzle(4, &Sleepy { millis: 10 }),
+        ];
+        let puzzles: Vec<&Puzzle> = puzzles.iter().collect();
+        let input_dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join("input");
+
+        let reports = run_all_parallel(&puzzles, &input_dir, &[1], None, 3);
+
+        let days: Vec<u8> = reports.iter().map(|r| r.day).collect();
+        assert_eq!(days, vec![1, 2, 3, 4]);
+        let answers: Vec<i128> = reports
+            .iter()
+            .map(|r| r.parts[0].answer.as_ref().unwrap().as_integer().unwrap())
+            .collect();
+        assert_eq!(answers, vec![30, 0, 20, 10]);
+    }
+
+    struct Endless {}
+
+    impl Challenge for Endless {
+        type Input<'a> = ();
+
+        fn parse(&self, _: &str) -> Result<(), Error> {
+            Ok(())
+        }
+
+        fn first_part(&self, _: &()) -> Result<Answer, Error> {
+            Ok(Answer::from(1))
+        }
+
+        fn second_part(&self, _: &()) -> Result<Answer, Error> {
+            let mut steps = Steps::new(23);
+            loop {
+                steps.step()?;
+            }
+        }
+    }
+
+    #[test]
+    fn test_timeout_stops_long_solution() {
+        let report = within(Some(Duration::from_millis(10)), || {
+            run_day(23, &Endless {}, "", &[2])
+        });
+
+        assert_eq!(
+            report.parts[0].answer.as_ref().unwrap_err().to_string(),
+            "day 23: timed out after 10.00ms"
+        );
+    }
+
+    #[test]
+    fn test_timeout_fails_late_answer() {
+        let report = within(Some(Duration::from_millis(10)), || {
+            run_day(23, &Sleepy { millis: 20 }, "", &[1, 2])
+        });
+
+        // The first part answers too late, and the second one never starts.
+        for part in &report.parts {
+            assert_eq!(
+                part.answer.as_ref().unwrap_err().to_string(),
+                "day 23: timed out after 10.00ms"
+            );
+        }
+    }
+
+    #[test]
+    fn test_run_selected_part_only() {
+        let report = run_day(11, &Panicking {}, "", &[1]);
+
+        assert_eq!(report.parts.len(), 1);
+        assert!(report.is_ok());
+    }
+}
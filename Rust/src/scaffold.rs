@@ -0,0 +1,650 @@
+use crate::solutions::{Error, FIRST_YEAR};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Day file with a placeholder solution. It already builds, passes every
+/// check of the registry and has an example test, so the solution can be
+/// written in small steps.
+const DAY_TEMPLATE: &str = r#"use super::format::{InputFormat, Pattern};
+use super::generator::{Generator, Rng};
+use super::registry::Example;
+use super::solution::{Answer, Challenge, Error};
+
+const DAY: u8 = __DAY__;
+
+fn parse_numbers(numbers_text: &str) -> Result<Vec<u64>, Error> {
+    numbers_text
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            line.parse()
+                .map_err(|_| Error::parse(DAY, idx + 1, 1, format!("number {} is too large", line)))
+        })
+        .collect()
+}
+
+fn solve_first_part(numbers: &[u64]) -> usize {
+    numbers.len()
+}
+
+fn solve_second_part(numbers: &[u64]) -> usize {
+    numbers.len()
+}
+
+fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| rng.between(1, 100).to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub struct Day__NN__ {}
+
+const EXAMPLES: &[Example] = &[Example {
+    input: "1
+2
+3",
+    first: Some("3"),
+    second: Some("3"),
+}];
+
+const FORMAT: InputFormat = InputFormat::Lines(Pattern::Number);
+
+const GENERATOR: Generator = Generator {
+    size: "number of lines",
+    sizes: 1..=100_000,
+    default_size: 1_000,
+    generate,
+};
+
+register! {
+    day: DAY,
+    title: __TITLE__,
+    tags: [],
+    solution: Day__NN__,
+    examples: EXAMPLES,
+    format: FORMAT,
+    generator: GENERATOR,
+}
+
+impl Challenge for Day__NN__ {
+    type Input<'a> = Vec<u64>;
+
+    fn parse<'a>(&self, numbers_text: &'a str) -> Result<Self::Input<'a>, Error> {
+        FORMAT.validate(DAY, numbers_text)?;
+
+        parse_numbers(numbers_text)
+    }
+
+    fn first_part(&self, numbers: &Self::Input<'_>) -> Result<Answer, Error> {
+        Ok(solve_first_part(numbers).into())
+    }
+
+    fn second_part(&self, numbers: &Self::Input<'_>) -> Result<Answer, Error> {
+        Ok(solve_second_part(numbers).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_task() {
+        let numbers = parse_numbers(EXAMPLES[0].input).unwrap();
+
+        assert_eq!(solve_first_part(&numbers), 3);
+    }
+
+    #[test]
+    fn test_second_task() {
+        let numbers = parse_numbers(EXAMPLES[0].input).unwrap();
+
+        assert_eq!(solve_second_part(&numbers), 3);
+    }
+}
+"#;
+
+/// Module of a year without any days yet.
+const YEAR_TEMPLATE: &str = r#"//! Solutions of [Advent of Code __YEAR__](https://adventofcode.com/__YEAR__).
+
+// Days of every year use the shared building blocks of `solutions`
+// through `super`, the same way in every year module.
+use super::{format, generator, registry, solution};
+
+days! {
+    year: __YEAR__,
+}
+"#;
+
+const BENCH_TEMPLATE: &str = r#"pub fn __BENCH__(c: &mut Criterion) {
+    let input = read_input(__YEAR__, __DAY__);
+    let day = year___YEAR__::Day__NN__ {};
+    c.bench_function("__LABEL__ first task", |b| {
+        b.iter(|| day.first_task(black_box(&input)))
+    });
+    c.bench_function("__LABEL__ second task", |b| {
+        b.iter(|| day.second_task(black_box(&input)))
+    });
+}
+
+"#;
+
+const FUZZ_TEMPLATE: &str = r#"#![no_main]
+use aoc2020::year___YEAR__::Day__NN__;
+use aoc2020::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let input = String::from_utf8_lossy(data);
+
+    let _ = Day__NN__ {}.first_task(&input);
+    let _ = Day__NN__ {}.second_task(&input);
+});
+"#;
+
+const FUZZ_BIN_TEMPLATE: &str = r#"
+[[bin]]
+name = "__TARGET__"
+path = "fuzz_targets/__TARGET__.rs"
+test = false
+doc = false
+bench = false
+"#;
+
+/// Bench functions of the days go before the benches of every day.
+const BENCH_ANCHOR: &str = "/// Parse step of every day";
+
+fn fill(template: &str, year: u16, day: u8, title: &str) -> String {
+    // Benches and fuzz targets of the first year keep the names they had
+    // before there were other years.
+    let (bench, label, target) = if year == FIRST_YEAR {
+        (
+            format!("day{:02}", day),
+            format!("Day {:02}", day),
+            format!("day_{:02}", day),
+        )
+    } else {
+        (
+            format!("year_{}_day{:02}", year, day),
+            format!("{} Day {:02}", year, day),
+            format!("year_{}_day_{:02}", year, day),
+        )
+    };
+
+    template
+        .replace("__BENCH__", &bench)
+        .replace("__LABEL__", &label)
+        .replace("__TARGET__", &target)
+        .replace("__YEAR__", &year.to_string())
+        .replace("__NN__", &format!("{:02}", day))
+        .replace("__DAY__", &day.to_string())
+        .replace("__TITLE__", &format!("{:?}", title))
+}
+
+fn malformed(what: &str) -> Error {
+    Error::invalid_argument(format!("cannot find where to add the day in {}", what))
+}
+
+/// Source of the day file for the `day` of the `year`.
+pub fn day_file(year: u16, day: u8, title: &str) -> String {
+    fill(DAY_TEMPLATE, year, day, title)
+}
+
+/// Index of the first line of the `macro_name!` block among the `lines`
+/// and of its closing brace.
+fn macro_block(lines: &[&str], macro_name: &str, file: &str) -> Result<(usize, usize), Error> {
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with(&format!("{}! {{", macro_name)))
+        .ok_or_else(|| malformed(file))?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| *line == "}")
+            .ok_or_else(|| malformed(file))?;
+
+    Ok((start, end))
+}
+
+/// The `lines` with the `declaration` of a module among the declarations
+/// starting with `prefix`, which are kept in order. The first declaration
+/// goes right before the line at `before`, followed by a blank line.
+fn declare_module<'a>(
+    lines: &[&'a str],
+    prefix: &str,
+    declaration: &'a str,
+    before: usize,
+) -> Vec<&'a str> {
+    let declared: Vec<usize> = (0..lines.len())
+        .filter(|&idx| lines[idx].starts_with(prefix))
+        .collect();
+    let mut result = lines.to_vec();
+
+    match (
+        declared.iter().find(|&&idx| lines[idx] > declaration),
+        declared.last(),
+    ) {
+        (Some(&idx), _) => result.insert(idx, declaration),
+        (None, Some(&idx)) => result.insert(idx + 1, declaration),
+        (None, None) => {
+            result.insert(before, "");
+            result.insert(before, declaration);
+        }
+    }
+
+    result
+}
+
+/// `mod.rs` of a year with the module of the `day` declared, in the `days!`
+/// list and its solution exported. Days are registered in order, so the day must
+/// come right after the last registered one.
+pub fn register_module(mod_rs: &str, day: u8) -> Result<String, Error> {
+    let module = format!("day_{:02}", day);
+    let lines: Vec<&str> = mod_rs.lines().collect();
+
+    let (start, end) = macro_block(&lines, "days", "the year module")?;
+    let days = &lines[start + 1..end];
+    let registered = days
+        .iter()
+        .filter(|line| line.trim().starts_with("day_"))
+        .count();
+
+    if days
+        .iter()
+        .any(|line| line.trim() == format!("{},", module))
+    {
+        return Err(Error::invalid_argument(format!(
+            "day {} is registered already",
+            day
+        )));
+    }
+    if day as usize != registered + 1 {
+        return Err(Error::invalid_argument(format!(
+            "days are added in order, the next one is day {}",
+            registered + 1
+        )));
+    }
+
+    // The first day is exported after the `days!` block.
+    let last_export = lines
+        .iter()
+        .rposition(|line| line.starts_with("pub use day_"));
+    let export = format!("pub use {}::Day{:02};", module, day);
+    let entry = format!("    {},", module);
+
+    let mut result: Vec<&str> = vec![];
+    for (idx, line) in lines.iter().enumerate() {
+        if idx == end {
+            result.push(&entry);
+        }
+        result.push(line);
+        if idx == last_export.unwrap_or(end) {
+            if last_export.is_none() {
+                result.push("");
+            }
+            result.push(&export);
+        }
+    }
+
+    let declaration = format!("mod {};", module);
+    let result = declare_module(&result, "mod day_", &declaration, start);
+
+    Ok(result.join("\n") + "\n")
+}
+
+/// `solutions/mod.rs` with the module of the `year` declared and in the
+/// `years!` list, both kept in order.
+pub fn register_year(mod_rs: &str, year: u16) -> Result<String, Error> {
+    let module = format!("year_{}", year);
+    let lines: Vec<&str> = mod_rs.lines().collect();
+
+    let (start, end) = macro_block(&lines, "years", "solutions/mod.rs")?;
+    let years = &lines[start + 1..end];
+    if years
+        .iter()
+        .any(|line| line.trim() == format!("{},", module))
+    {
+        return Err(Error::invalid_argument(format!(
+            "year {} is registered already",
+            year
+        )));
+    }
+
+    // Module names differ only in the year, so they sort like the years.
+    let position = start
+        + 1
+        + years
+            .iter()
+            .take_while(|line| line.trim() < module.as_str())
+            .count();
+    let entry = format!("    {},", module);
+
+    let mut result: Vec<&str> = lines.clone();
+    result.insert(position, &entry);
+
+    let declaration = format!("pub mod {};", module);
+    let result = declare_module(&result, "pub mod year_", &declaration, start);
+
+    Ok(result.join("\n") + "\n")
+}
+
+/// `benches/criterion.rs` with a bench of both parts of the `day` of the
+/// `year`. Like the benches of other days, it's listed in `criterion_group!`
+/// but commented out, as the benches of every day run on all registered
+/// days anyway.
+pub fn register_bench(bench_rs: &str, year: u16, day: u8) -> Result<String, Error> {
+    let anchor = bench_rs
+        .find(BENCH_ANCHOR)
+        .ok_or_else(|| malformed("benches/criterion.rs"))?;
+    let group_end = bench_rs[anchor..]
+        .find("\n);")
+        .map(|idx| anchor + idx)
+        .ok_or_else(|| malformed("benches/criterion.rs"))?;
+
+    let group = bench_rs[anchor..group_end].trim_end();
+    let separator = if group.ends_with(',') || group.ends_with('(') {
+        ""
+    } else {
+        ","
+    };
+
+    Ok(format!(
+        "{}{}{}{}\n    // {}{}",
+        &bench_rs[..anchor],
+        fill(BENCH_TEMPLATE, year, day, ""),
+        group,
+        separator,
+        fill("__BENCH__", year, day, ""),
+        &bench_rs[group_end..],
+    ))
+}
+
+/// Files written for a new day.
+#[derive(Debug, PartialEq)]
+pub struct Scaffold {
+    pub created: Vec<PathBuf>,
+    pub updated: Vec<PathBuf>,
+}
+
+/// Add a day with a placeholder solution to the crate in `crate_dir`: the
+/// day file, its module in the registry of the year, a bench and a fuzz
+/// target. Day 1 of a year without solutions starts the module of the year.
+pub fn new_day(crate_dir: &Path, year: u16, day: u8, title: &str) -> Result<Scaffold, Error> {
+    let path = |relative: &str| crate_dir.join(relative);
+    let read = |path: &Path| {
+        fs::read_to_string(path).map_err(|err| {
+            Error::invalid_argument(format!("cannot read {}: {}", path.display(), err))
+        })
+    };
+    let write = |path: &Path, text: &str| {
+        fs::write(path, text)
+            .map_err(|err| Error::internal(format!("cannot write {}: {}", path.display(), err)))
+    };
+
+    let year_dir = path(&format!("src/solutions/year_{}", year));
+    let day_path = year_dir.join(format!("day_{:02}.rs", day));
+    if day_path.exists() {
+        return Err(Error::invalid_argument(format!(
+            "{} exists already",
+            day_path.display()
+        )));
+    }
+
+    // Everything is prepared first, so a problem leaves the crate untouched.
+    let year_path = year_dir.join("mod.rs");
+    let solutions_path = path("src/solutions/mod.rs");
+    let (year_rs, solutions_rs) = if year_path.is_file() {
+        (register_module(&read(&year_path)?, day)?, None)
+    } else {
+        let year_rs = fill(YEAR_TEMPLATE, year, day, title);
+        let solutions_rs = register_year(&read(&solutions_path)?, year)?;
+        (register_module(&year_rs, day)?, Some(solutions_rs))
+    };
+    let bench_path = path("benches/criterion.rs");
+    let bench_rs = register_bench(&read(&bench_path)?, year, day)?;
+
+    let mut scaffold = Scaffold {
+        created: vec![day_path.clone()],
+        updated: vec![bench_path.clone()],
+    };
+    match &solutions_rs {
+        Some(solutions_rs) => {
+            fs::create_dir_all(&year_dir).map_err(|err| {
+                Error::internal(format!("cannot create {}: {}", year_dir.display(), err))
+            })?;
+            write(&solutions_path, solutions_rs)?;
+            scaffold.created.push(year_path.clone());
+            scaffold.updated.push(solutions_path);
+        }
+        None => scaffold.updated.push(year_path.clone()),
+    }
+    write(&day_path, &day_file(year, day, title))?;
+    write(&year_path, &year_rs)?;
+    write(&bench_path, &bench_rs)?;
+
+    let fuzz_manifest = path("fuzz/Cargo.toml");
+    let fuzz_target = path(&fill("fuzz/fuzz_targets/__TARGET__.rs", year, day, title));
+    if fuzz_manifest.is_file() && !fuzz_target.exists() {
+        write(&fuzz_target, &fill(FUZZ_TEMPLATE, year, day, title))?;
+        let manifest = read(&fuzz_manifest)? + &fill(FUZZ_BIN_TEMPLATE, year, day, title);
+        write(&fuzz_manifest, &manifest)?;
+
+        scaffold.created.push(fuzz_target);
+        scaffold.updated.push(fuzz_manifest);
+    }
+
+    Ok(scaffold)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOLUTIONS_RS: &str = "mod answer;
+
+pub mod year_2020;
+pub mod year_2022;
+
+years! {
+    year_2020,
+    year_2022,
+}
+";
+
+    const YEAR_RS: &str = "use super::{format, registry};
+
+mod day_01;
+mod day_02;
+
+days! {
+    year: 2020,
+    day_01,
+    day_02,
+}
+
+pub use day_01::Day01;
+pub use day_02::Day02;
+";
+
+    const BENCH_RS: &str = "pub fn day02(c: &mut Criterion) {
+}
+
+/// Parse step of every day on its own, without solving any part.
+pub fn parse(c: &mut Criterion) {
+}
+
+criterion_group!(
+    benches, parse, generated,
+    day01,
+    // day02
+);
+criterion_main!(benches);
+";
+
+    /// Crate with the registry and the 2020 module from above.
+    fn crate_dir() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("src/solutions/year_2020")).unwrap();
+        fs::create_dir_all(dir.path().join("benches")).unwrap();
+        fs::write(dir.path().join("src/solutions/mod.rs"), SOLUTIONS_RS).unwrap();
+        fs::write(dir.path().join("src/solutions/year_2020/mod.rs"), YEAR_RS).unwrap();
+        fs::write(dir.path().join("benches/criterion.rs"), BENCH_RS).unwrap();
+
+        dir
+    }
+
+    #[test]
+    fn test_register_module() {
+        assert_eq!(
+            register_module(YEAR_RS, 3).unwrap(),
+            "use super::{format, registry};
+
+mod day_01;
+mod day_02;
+mod day_03;
+
+days! {
+    year: 2020,
+    day_01,
+    day_02,
+    day_03,
+}
+
+pub use day_01::Day01;
+pub use day_02::Day02;
+pub use day_03::Day03;
+"
+        );
+        assert!(register_module(YEAR_RS, 2).is_err());
+        assert!(register_module(YEAR_RS, 4).is_err());
+        assert!(register_module("mod answer;\n", 1).is_err());
+
+        assert!(register_module(&fill(YEAR_TEMPLATE, 2021, 1, ""), 1)
+            .unwrap()
+            .ends_with(
+                "mod day_01;\n\ndays! {\n    year: 2021,\n    day_01,\n}\n\npub use day_01::Day01;\n"
+            ));
+    }
+
+    #[test]
+    fn test_register_year() {
+        assert_eq!(
+            register_year(SOLUTIONS_RS, 2021).unwrap(),
+            "mod answer;
+
+pub mod year_2020;
+pub mod year_2021;
+pub mod year_2022;
+
+years! {
+    year_2020,
+    year_2021,
+    year_2022,
+}
+"
+        );
+        assert!(register_year(SOLUTIONS_RS, 2023)
+            .unwrap()
+            .contains("    year_2022,\n    year_2023,\n}"));
+        assert!(register_year("years! {\n}\n", 2020)
+            .unwrap()
+            .starts_with("pub mod year_2020;\n\nyears! {\n    year_2020,\n}"));
+        assert!(register_year(SOLUTIONS_RS, 2022).is_err());
+    }
+
+    #[test]
+    fn test_register_bench() {
+        let bench_rs = register_bench(BENCH_RS, 2020, 3).unwrap();
+
+        assert!(bench_rs.contains(
+            "pub fn day03(c: &mut Criterion) {
+    let input = read_input(2020, 3);
+    let day = year_2020::Day03 {};
+    c.bench_function(\"Day 03 first task\", |b| {"
+        ));
+        assert!(bench_rs.contains("}\n\n/// Parse step of every day"));
+        assert!(bench_rs.ends_with(
+            "    day01,
+    // day02,
+    // day03
+);
+criterion_main!(benches);
+"
+        ));
+
+        let bench_rs = register_bench(BENCH_RS, 2021, 1).unwrap();
+        assert!(bench_rs.contains("pub fn year_2021_day01(c: &mut Criterion) {"));
+        assert!(bench_rs.contains("c.bench_function(\"2021 Day 01 first task\""));
+        assert!(bench_rs.contains("    // day02,\n    // year_2021_day01\n);"));
+    }
+
+    #[test]
+    fn test_new_day() {
+        let dir = crate_dir();
+
+        let scaffold = new_day(dir.path(), 2020, 3, "Toboggan \"Trajectory\"").unwrap();
+
+        assert_eq!(
+            scaffold.created,
+            vec![dir.path().join("src/solutions/year_2020/day_03.rs")]
+        );
+        let day_rs = fs::read_to_string(&scaffold.created[0]).unwrap();
+        assert!(day_rs.contains("const DAY: u8 = 3;"));
+        assert!(day_rs.contains("pub struct Day03 {}"));
+        assert!(day_rs.contains("    title: \"Toboggan \\\"Trajectory\\\"\",\n"));
+
+        assert!(new_day(dir.path(), 2020, 3, "Again").is_err());
+    }
+
+    #[test]
+    fn test_first_day_starts_its_year() {
+        let dir = crate_dir();
+        fs::create_dir_all(dir.path().join("fuzz")).unwrap();
+        fs::write(dir.path().join("fuzz/Cargo.toml"), "[package]\n").unwrap();
+        fs::create_dir_all(dir.path().join("fuzz/fuzz_targets")).unwrap();
+
+        assert!(new_day(dir.path(), 2021, 2, "Too soon").is_err());
+        let scaffold = new_day(dir.path(), 2021, 1, "Sonar Sweep").unwrap();
+
+        let year_dir = dir.path().join("src/solutions/year_2021");
+        assert_eq!(
+            scaffold.created,
+            vec![
+                year_dir.join("day_01.rs"),
+                year_dir.join("mod.rs"),
+                dir.path().join("fuzz/fuzz_targets/year_2021_day_01.rs")
+            ]
+        );
+        let year_rs = fs::read_to_string(year_dir.join("mod.rs")).unwrap();
+        assert!(year_rs.contains("    year: 2021,\n    day_01,\n"));
+        let solutions_rs = fs::read_to_string(dir.path().join("src/solutions/mod.rs")).unwrap();
+        assert!(solutions_rs.contains("    year_2020,\n    year_2021,\n    year_2022,\n"));
+        let fuzz_rs = fs::read_to_string(&scaffold.created[2]).unwrap();
+        assert!(fuzz_rs.contains("use aoc2020::year_2021::Day01;"));
+
+        assert!(new_day(dir.path(), 2021, 2, "Dive!").is_ok());
+    }
+
+    #[test]
+    fn test_failed_scaffold_leaves_crate_untouched() {
+        let dir = crate_dir();
+        fs::remove_file(dir.path().join("benches/criterion.rs")).unwrap();
+
+        assert!(new_day(dir.path(), 2020, 3, "No benches").is_err());
+        assert!(new_day(dir.path(), 2021, 1, "No benches").is_err());
+        assert!(!dir
+            .path()
+            .join("src/solutions/year_2020/day_03.rs")
+            .exists());
+        assert!(!dir.path().join("src/solutions/year_2021").exists());
+        assert_eq!(
+            fs::read_to_string(dir.path().join("src/solutions/year_2020/mod.rs")).unwrap(),
+            YEAR_RS
+        );
+        assert_eq!(
+            fs::read_to_string(dir.path().join("src/solutions/mod.rs")).unwrap(),
+            SOLUTIONS_RS
+        );
+    }
+}
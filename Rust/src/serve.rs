@@ -0,0 +1,371 @@
+use crate::output;
+use crate::runner;
+use crate::solutions::{Error, Solution, Year};
+use serde::Serialize;
+use std::io::Read;
+use std::thread;
+use std::time::Duration;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+/// What a single request is allowed to use.
+#[derive(Clone, Copy, Debug)]
+pub struct Limits {
+    /// Largest accepted puzzle input in bytes.
+    pub max_body_size: usize,
+    /// How long a solution may run before it's cancelled.
+    pub timeout: Duration,
+}
+
+/// Status code and JSON body of a response.
+#[derive(Debug, PartialEq)]
+pub struct Reply {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Reply {
+    fn json(status: u16, body: &impl Serialize) -> Reply {
+        Reply {
+            status,
+            body: serde_json::to_string(body).unwrap_or_default(),
+        }
+    }
+
+    fn error(status: u16, message: impl Into<String>) -> Reply {
+        Reply::json(
+            status,
+            &Failure {
+                error: message.into(),
+            },
+        )
+    }
+}
+
+#[derive(Serialize)]
+struct Failure {
+    error: String,
+}
+
+#[derive(Serialize)]
+struct Day {
+    day: u8,
+    title: &'static str,
+    tags: Vec<&'static str>,
+}
+
+#[derive(Serialize)]
+struct Solved {
+    day: u8,
+    part: u8,
+    answer: String,
+    parse_ms: f64,
+    duration_ms: f64,
+}
+
+fn milliseconds(duration: Duration) -> f64 {
+    duration.as_micros() as f64 / 1000.0
+}
+
+fn list_days(year: &Year) -> Reply {
+    let days: Vec<Day> = year
+        .puzzles
+        .iter()
+        .map(|puzzle| Day {
+            day: puzzle.day,
+            title: puzzle.title,
+            tags: puzzle.tags.iter().map(|tag| tag.name()).collect(),
+        })
+        .collect();
+
+    Reply::json(200, &days)
+}
+
+/// Status code of a failed part. Problems with the input are the client's
+/// fault, everything else is the server's.
+fn error_status(err: &Error) -> u16 {
+    match err.root_cause() {
+        Error::Parse { .. } | Error::NoSolution { .. } => 422,
+        Error::InvalidArgument(_) => 400,
+        Error::TimedOut { .. } => 504,
+        _ => 500,
+    }
+}
+
+/// Solve one part of a day on the calling thread, cancelling the solution
+/// once it runs longer than `timeout`. Solutions notice the cancellation
+/// between their steps, so the reply comes a bit after the `timeout`, and
+/// an answer found too late is a timeout as well.
+pub fn solve(
+    day: u8,
+    solution: &'static dyn Solution,
+    part: u8,
+    input: String,
+    timeout: Duration,
+) -> Reply {
+    let report = runner::within(Some(timeout), || {
+        runner::run_day(day, solution, &input, &[part])
+    });
+
+    match report.parts.into_iter().next() {
+        Some(solved) => match solved.answer {
+            Ok(answer) => Reply::json(
+                200,
+                &Solved {
+                    day,
+                    part,
+                    answer: answer.to_string(),
+                    parse_ms: milliseconds(report.parse),
+                    duration_ms: milliseconds(solved.duration),
+                },
+            ),
+            Err(err) => Reply::error(error_status(&err), output::describe(&err)),
+        },
+        None => Reply::error(500, format!("day {}: part {} wasn't solved", day, part)),
+    }
+}
+
+/// Whole `body` as text, or an error reply when it's larger than
+/// `max_body_size` bytes or isn't UTF-8.
+fn read_body(body: &mut dyn Read, max_body_size: usize) -> Result<String, Reply> {
+    let mut bytes = vec![];
+    body.take(max_body_size as u64 + 1)
+        .read_to_end(&mut bytes)
+        .map_err(|err| Reply::error(400, format!("cannot read the request body: {}", err)))?;
+
+    if bytes.len() > max_body_size {
+        return Err(Reply::error(
+            413,
+            format!("puzzle input is larger than {} bytes", max_body_size),
+        ));
+    }
+
+    String::from_utf8(bytes).map_err(|_| Reply::error(400, "puzzle input is not valid UTF-8"))
+}
+
+fn solve_request(
+    year: &Year,
+    day: &str,
+    part: &str,
+    body: &mut dyn Read,
+    limits: &Limits,
+) -> Reply {
+    let puzzle = match day.parse().ok().and_then(|day| year.find(day)) {
+        Some(puzzle) => puzzle,
+        None => return Reply::error(404, format!("there is no solution for day {}", day)),
+    };
+    let part = match part.parse() {
+        Ok(part @ 1..=2) => part,
+        _ => return Reply::error(404, format!("there is no part {}", part)),
+    };
+
+    match read_body(body, limits.max_body_size) {
+        Ok(input) => solve(puzzle.day, puzzle.solution, part, input, limits.timeout),
+        Err(reply) => reply,
+    }
+}
+
+/// Route a request for the puzzles of the `year` to its handler:
+///
+/// - `GET /days` lists every day with a solution,
+/// - `POST /days/{day}/parts/{part}` solves the part with the body as the
+///   puzzle input.
+pub fn handle(year: &Year, method: &str, url: &str, body: &mut dyn Read, limits: &Limits) -> Reply {
+    let path = url.split('?').next().unwrap_or_default();
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+
+    match (method, segments.as_slice()) {
+        ("GET", ["days"]) => list_days(year),
+        ("POST", ["days", day, "parts", part]) => solve_request(year, day, part, body, limits),
+        (_, ["days"]) | (_, ["days", _, "parts", _]) => {
+            Reply::error(405, format!("{} is not allowed for {}", method, path))
+        }
+        _ => Reply::error(404, format!("there is nothing at {}", path)),
+    }
+}
+
+fn respond(mut request: Request, year: &Year, limits: &Limits) {
+    let method = request.method().as_str().to_string();
+    let url = request.url().to_string();
+
+    let reply = match request.body_length() {
+        // Refuse large inputs before reading them.
+        Some(length) if length > limits.max_body_size && method == Method::Post.as_str() => {
+            Reply::error(
+                413,
+                format!("puzzle input is larger than {} bytes", limits.max_body_size),
+            )
+        }
+        _ => handle(year, &method, &url, request.as_reader(), limits),
+    };
+
+    let content_type = Header::from_bytes("Content-Type", "application/json")
+        .expect("Content-Type header is valid");
+    let response = Response::from_string(reply.body)
+        .with_status_code(reply.status)
+        .with_header(content_type);
+
+    // The client may have disconnected, there is no one to report it to.
+    let _ = request.respond(response);
+}
+
+/// Serve requests for the puzzles of the `year` from the `server` with a
+/// pool of `workers` threads. Every worker solves one request at a time, so
+/// at most `workers` solutions run at once. Never returns unless the server
+/// is shut down.
+pub fn run(server: Server, year: &Year, limits: Limits, workers: usize) {
+    thread::scope(|scope| {
+        for _ in 0..workers.max(1) {
+            let server = &server;
+            let limits = &limits;
+
+            scope.spawn(move || {
+                for request in server.incoming_requests() {
+                    respond(request, year, limits);
+                }
+            });
+        }
+    });
+}
+
+/// Start a server listening on `address`, e.g. "127.0.0.1:2020".
+pub fn bind(address: &str) -> Result<Server, Error> {
+    Server::http(address)
+        .map_err(|err| Error::invalid_argument(format!("cannot listen on {}: {}", address, err)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::{year_2020, Answer, Challenge};
+    use std::io::{self, Write};
+    use std::net::TcpStream;
+
+    const LIMITS: Limits = Limits {
+        max_body_size: 1024,
+        timeout: Duration::from_secs(10),
+    };
+
+    fn get(url: &str) -> Reply {
+        handle(&year_2020::YEAR, "GET", url, &mut io::empty(), &LIMITS)
+    }
+
+    fn post(url: &str, body: &str, limits: &Limits) -> Reply {
+        handle(&year_2020::YEAR, "POST", url, &mut body.as_bytes(), limits)
+    }
+
+    fn json(reply: &Reply) -> serde_json::Value {
+        serde_json::from_str(&reply.body).unwrap()
+    }
+
+    #[test]
+    fn test_list_days() {
+        let reply = get("/days");
+        let days = json(&reply);
+
+        assert_eq!(reply.status, 200);
+        assert_eq!(days.as_array().unwrap().len(), year_2020::PUZZLES.len());
+        assert_eq!(days[0]["day"], 1);
+        assert_eq!(days[0]["title"], year_2020::PUZZLES[0].title);
+    }
+
+    #[test]
+    fn test_solve_part() {
+        let puzzle = year_2020::YEAR.find(1).unwrap();
+        let reply = post("/days/1/parts/2", puzzle.examples[0].input, &LIMITS);
+        let solved = json(&reply);
+
+        assert_eq!(reply.status, 200);
+        assert_eq!(solved["day"], 1);
+        assert_eq!(solved["part"], 2);
+        assert_eq!(solved["answer"], "241861950");
+        assert!(solved["duration_ms"].is_number());
+        assert!(solved["parse_ms"].is_number());
+    }
+
+    #[test]
+    fn test_rejected_requests() {
+        let status = |reply: Reply| reply.status;
+
+        assert_eq!(status(post("/days/1/parts/1", "1721\nabc", &LIMITS)), 422);
+        assert_eq!(status(post("/days/26/parts/1", "", &LIMITS)), 404);
+        assert_eq!(status(post("/days/1/parts/3", "", &LIMITS)), 404);
+        assert_eq!(status(post("/days", "", &LIMITS)), 405);
+        assert_eq!(status(post("/nothing", "", &LIMITS)), 404);
+        assert_eq!(status(get("/days/1/parts/1")), 405);
+
+        let small = Limits {
+            max_body_size: 4,
+            ..LIMITS
+        };
+        assert_eq!(status(post("/days/1/parts/1", "1721\n", &small)), 413);
+        assert_eq!(
+            status(handle(
+                &year_2020::YEAR,
+                "POST",
+                "/days/1/parts/1",
+                &mut &[0xff, 0xfe][..],
+                &LIMITS
+            )),
+            400
+        );
+    }
+
+    struct Sleeping {}
+
+    impl Challenge for Sleeping {
+        type Input<'a> = ();
+
+        fn parse(&self, _: &str) -> Result<(), Error> {
+            Ok(())
+        }
+
+        fn first_part(&self, _: &()) -> Result<Answer, Error> {
+            thread::sleep(Duration::from_millis(100));
+            Ok(Answer::from(42))
+        }
+
+        fn second_part(&self, _: &()) -> Result<Answer, Error> {
+            Ok(Answer::from(42))
+        }
+    }
+
+    #[test]
+    fn test_slow_solution_times_out() {
+        let reply = solve(1, &Sleeping {}, 1, String::new(), Duration::from_millis(50));
+
+        assert_eq!(reply.status, 504);
+        assert_eq!(json(&reply)["error"], "day 1: timed out after 50.00ms");
+        assert_eq!(
+            solve(1, &Sleeping {}, 2, String::new(), Duration::from_secs(10)).status,
+            200
+        );
+    }
+
+    #[test]
+    fn test_serve_over_http() {
+        let server = bind("127.0.0.1:0").unwrap();
+        let address = server.server_addr().to_ip().unwrap();
+        thread::spawn(move || run(server, &year_2020::YEAR, LIMITS, 1));
+
+        let mut stream = TcpStream::connect(address).unwrap();
+        let input = "1721\n979\n366\n299\n675\n1456\n";
+        write!(
+            stream,
+            "POST /days/1/parts/1 HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            input.len(),
+            input
+        )
+        .unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+
+        assert!(head.starts_with("HTTP/1.1 200"), "{}", head);
+        assert!(head.contains("application/json"), "{}", head);
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(body).unwrap()["answer"],
+            "514579"
+        );
+    }
+}
@@ -0,0 +1,137 @@
+use serde::Serialize;
+use std::convert::TryFrom;
+use std::fmt::{Display, Error as fmtError, Formatter};
+
+/// Answer to one part of a puzzle. `Display` gives exactly what Advent of
+/// Code expects to be submitted.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", content = "value", rename_all = "kebab-case")]
+pub enum Answer {
+    Integer(i64),
+    /// Integer that doesn't fit into `i64`.
+    BigInteger(i128),
+    Text(String),
+    /// Items that are submitted joined with the `separator`.
+    List {
+        items: Vec<Answer>,
+        separator: &'static str,
+    },
+}
+
+impl Answer {
+    pub fn list<T: Into<Answer>>(
+        items: impl IntoIterator<Item = T>,
+        separator: &'static str,
+    ) -> Answer {
+        Answer::List {
+            items: items.into_iter().map(Into::into).collect(),
+            separator,
+        }
+    }
+
+    /// Numeric value of integer answers.
+    pub fn as_integer(&self) -> Option<i128> {
+        match self {
+            Answer::Integer(value) => Some(*value as i128),
+            Answer::BigInteger(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmtError> {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::BigInteger(value) => write!(f, "{}", value),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::List { items, separator } => {
+                for (idx, item) in items.iter().enumerate() {
+                    if idx > 0 {
+                        write!(f, "{}", separator)?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(value: i128) -> Self {
+        i64::try_from(value).map_or(Answer::BigInteger(value), Answer::Integer)
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        match i128::try_from(value) {
+            Ok(value) => value.into(),
+            Err(_) => Answer::Text(value.to_string()),
+        }
+    }
+}
+
+macro_rules! from_integer {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(value: $int) -> Self {
+                    (value as i128).into()
+                }
+            }
+        )*
+    };
+}
+
+from_integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(-42).to_string(), "-42");
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(
+            Answer::list(vec!["mxmxvkd", "sqjhc", "fvjkl"], ",").to_string(),
+            "mxmxvkd,sqjhc,fvjkl"
+        );
+        assert_eq!(Answer::list(vec![6, 7, 3], "").to_string(), "673");
+    }
+
+    #[test]
+    fn test_integer_variants() {
+        assert_eq!(Answer::from(7usize), Answer::Integer(7));
+        assert_eq!(Answer::from(u64::MAX), Answer::BigInteger(u64::MAX as i128));
+        assert_eq!(Answer::from(u64::MAX).as_integer(), Some(u64::MAX as i128));
+        assert_eq!(Answer::from("I did it!!!").as_integer(), None);
+    }
+
+    #[test]
+    fn test_serialize() {
+        assert_eq!(
+            serde_json::to_string(&Answer::from(5)).unwrap(),
+            r#"{"type":"integer","value":5}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&Answer::list(vec![1, 2], "")).unwrap(),
+            r#"{"type":"list","value":{"items":[{"type":"integer","value":1},{"type":"integer","value":2}],"separator":""}}"#
+        );
+    }
+}
@@ -0,0 +1,171 @@
+use super::solution::Error;
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// How many steps a solution makes between two checks of its cancellation,
+/// so checking stays cheap even in the tightest loops.
+const CHECK_EVERY: u32 = 1 << 12;
+
+thread_local! {
+    static CURRENT: RefCell<Option<Cancellation>> = const { RefCell::new(None) };
+}
+
+/// Tells long-running solutions to give up, either when its time is out or
+/// when someone cancels it. Clones share the same state, so a token can be
+/// cancelled from another thread.
+#[derive(Clone, Debug)]
+pub struct Cancellation {
+    cancelled: Arc<AtomicBool>,
+    started: Instant,
+    timeout: Option<Duration>,
+}
+
+impl Cancellation {
+    /// Token cancelled only by `cancel`.
+    pub fn new() -> Cancellation {
+        Cancellation {
+            cancelled: Arc::new(AtomicBool::new(false)),
+            started: Instant::now(),
+            timeout: None,
+        }
+    }
+
+    /// Token cancelled by `cancel` or once `timeout` passes.
+    pub fn after(timeout: Duration) -> Cancellation {
+        Cancellation {
+            timeout: Some(timeout),
+            ..Cancellation::new()
+        }
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+            || self
+                .timeout
+                .is_some_and(|timeout| self.started.elapsed() >= timeout)
+    }
+
+    /// Run `f` with the token as the current one of the thread. Solutions
+    /// called from `f` stop with a "timed out" error once it's cancelled.
+    pub fn run<T>(&self, f: impl FnOnce() -> T) -> T {
+        struct Restore(Option<Cancellation>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                let previous = self.0.take();
+                CURRENT.with(|current| *current.borrow_mut() = previous);
+            }
+        }
+
+        let previous = CURRENT.with(|current| current.borrow_mut().replace(self.clone()));
+        let _restore = Restore(previous);
+
+        f()
+    }
+
+    fn error(&self, day: u8) -> Error {
+        Error::timed_out(day, self.timeout.unwrap_or_else(|| self.started.elapsed()))
+    }
+}
+
+impl Default for Cancellation {
+    fn default() -> Self {
+        Cancellation::new()
+    }
+}
+
+/// Steps of a long-running loop in a solution. Every step may fail with a
+/// "timed out" error when the current cancellation token of the thread is
+/// cancelled. Without a token, solutions run until they finish.
+pub struct Steps {
+    day: u8,
+    token: Option<Cancellation>,
+    count: u32,
+}
+
+impl Steps {
+    pub fn new(day: u8) -> Steps {
+        Steps {
+            day,
+            token: CURRENT.with(|current| current.borrow().clone()),
+            count: 0,
+        }
+    }
+
+    #[inline]
+    pub fn step(&mut self) -> Result<(), Error> {
+        self.count += 1;
+        if self.count < CHECK_EVERY {
+            return Ok(());
+        }
+        self.count = 0;
+
+        match &self.token {
+            Some(token) if token.is_cancelled() => Err(token.error(self.day)),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Fail with a "timed out" error when the current cancellation token of the
+/// thread is already cancelled, e.g. to reject an answer found too late.
+pub fn check_cancelled(day: u8) -> Result<(), Error> {
+    CURRENT.with(|current| match &*current.borrow() {
+        Some(token) if token.is_cancelled() => Err(token.error(day)),
+        _ => Ok(()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spin(steps: &mut Steps, count: u32) -> Result<(), Error> {
+        (0..count).try_for_each(|_| steps.step())
+    }
+
+    #[test]
+    fn test_steps_stop_once_cancelled() {
+        let token = Cancellation::new();
+
+        token.run(|| {
+            let mut steps = Steps::new(15);
+            assert!(spin(&mut steps, 10 * CHECK_EVERY).is_ok());
+
+            token.cancel();
+            assert!(spin(&mut steps, CHECK_EVERY).is_err());
+        });
+
+        assert!(spin(&mut Steps::new(15), 10 * CHECK_EVERY).is_ok());
+    }
+
+    #[test]
+    fn test_steps_time_out() {
+        let token = Cancellation::after(Duration::from_millis(0));
+        let err = token
+            .run(|| spin(&mut Steps::new(22), CHECK_EVERY))
+            .unwrap_err();
+
+        assert_eq!(err.to_string(), "day 22: timed out after 0.00ns");
+    }
+
+    #[test]
+    fn test_check_fails_once_cancelled() {
+        let token = Cancellation::new();
+
+        token.run(|| {
+            assert!(check_cancelled(17).is_ok());
+
+            token.cancel();
+            assert!(check_cancelled(17).is_err());
+        });
+
+        assert!(check_cancelled(17).is_ok());
+    }
+}
@@ -0,0 +1,619 @@
+use super::cancel::Steps;
+use super::solution::Error;
+use std::collections::BTreeSet;
+use std::convert::Infallible;
+use std::fmt::{Display, Error as fmtError, Formatter};
+
+pub const LOWERCASE: &str = "abcdefghijklmnopqrstuvwxyz";
+pub const DIGITS: &str = "0123456789";
+
+/// Shape of a single line, matched from left to right. Numbers, words and
+/// runs of characters are always matched as long as possible.
+#[derive(Debug)]
+pub enum Pattern {
+    /// Exactly the text.
+    Literal(&'static str),
+    /// Decimal number without a sign.
+    Number,
+    /// Decimal number with an optional "+" or "-" sign.
+    SignedNumber,
+    /// One or more lowercase letters.
+    Word,
+    /// A single character from the set.
+    Char(&'static str),
+    /// One or more characters from the set.
+    Chars(&'static str),
+    /// Every pattern, one after another.
+    Seq(&'static [Pattern]),
+    /// Any of the patterns.
+    Alt(&'static [Pattern]),
+    /// One or more items, separated by the text.
+    List(&'static Pattern, &'static str),
+}
+
+/// Shape of the whole input of a day.
+#[derive(Debug)]
+pub enum InputFormat {
+    /// Exactly one line.
+    Line(Pattern),
+    /// One or more lines with the same pattern.
+    Lines(Pattern),
+    /// A line followed by the rest of the input in its own format.
+    Header(Pattern, &'static InputFormat),
+    /// Rectangular grid of characters from the set.
+    Grid(&'static str),
+    /// Sections separated by a blank line, each one in its own format.
+    Sections(&'static [InputFormat]),
+    /// One or more sections separated by a blank line, all in the same format.
+    Groups(&'static InputFormat),
+}
+
+/// Place where the input doesn't follow the format. Both `line` and
+/// `column` are 1-based.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Issue {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl Display for Issue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmtError> {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+/// The furthest position in a line where matching failed, with everything
+/// that was expected there.
+#[derive(Default)]
+struct Failure {
+    position: usize,
+    expected: Vec<String>,
+}
+
+impl Failure {
+    fn record(&mut self, position: usize, expected: impl FnOnce() -> String) {
+        if position > self.position || self.expected.is_empty() {
+            self.position = position;
+            self.expected.clear();
+        }
+        if position == self.position {
+            let expected = expected();
+            if !self.expected.contains(&expected) {
+                self.expected.push(expected);
+            }
+        }
+    }
+}
+
+fn describe_set(set: &str) -> String {
+    match set {
+        DIGITS => String::from("digit"),
+        LOWERCASE => String::from("lowercase letter"),
+        set => format!("one of \"{}\"", set),
+    }
+}
+
+fn column(line: &str, position: usize) -> usize {
+    line[..position].chars().count() + 1
+}
+
+impl Pattern {
+    fn describe(&self) -> String {
+        match self {
+            Pattern::Literal(text) => format!("\"{}\"", text),
+            Pattern::Number => String::from("number"),
+            Pattern::SignedNumber => String::from("signed number"),
+            Pattern::Word => String::from("word"),
+            Pattern::Char(set) | Pattern::Chars(set) => describe_set(set),
+            Pattern::Seq(patterns) => patterns
+                .first()
+                .map_or_else(String::new, |pattern| pattern.describe()),
+            Pattern::Alt(patterns) => patterns
+                .iter()
+                .map(|pattern| pattern.describe())
+                .collect::<Vec<_>>()
+                .join(" or "),
+            Pattern::List(item, _) => item.describe(),
+        }
+    }
+
+    /// Byte length of the longest run of characters from the set at `start`.
+    fn run(line: &str, start: usize, set: &str, limit: usize) -> usize {
+        line[start..]
+            .chars()
+            .take(limit)
+            .take_while(|&ch| set.contains(ch))
+            .map(char::len_utf8)
+            .sum()
+    }
+
+    /// Every position in the `line` where a match starting at `start` can end.
+    fn ends(&self, line: &str, start: usize, failure: &mut Failure) -> Vec<usize> {
+        // A run of characters could also go on where it stopped.
+        let run = |len: usize, set: &str, failure: &mut Failure| {
+            if len == 0 {
+                failure.record(start, || self.describe());
+                return vec![];
+            }
+            failure.record(start + len, || describe_set(set));
+            vec![start + len]
+        };
+
+        match self {
+            Pattern::Literal(text) => {
+                if line[start..].starts_with(text) {
+                    vec![start + text.len()]
+                } else {
+                    failure.record(start, || self.describe());
+                    vec![]
+                }
+            }
+            Pattern::Number => run(Self::run(line, start, DIGITS, usize::MAX), DIGITS, failure),
+            Pattern::SignedNumber => {
+                let sign = Self::run(line, start, "+-", 1);
+                match Self::run(line, start + sign, DIGITS, usize::MAX) {
+                    0 => run(0, DIGITS, failure),
+                    len => run(sign + len, DIGITS, failure),
+                }
+            }
+            Pattern::Word => run(
+                Self::run(line, start, LOWERCASE, usize::MAX),
+                LOWERCASE,
+                failure,
+            ),
+            Pattern::Char(set) => match Self::run(line, start, set, 1) {
+                0 => run(0, set, failure),
+                len => vec![start + len],
+            },
+            Pattern::Chars(set) => run(Self::run(line, start, set, usize::MAX), set, failure),
+            Pattern::Seq(patterns) => patterns.iter().fold(vec![start], |starts, pattern| {
+                let mut ends: Vec<usize> = starts
+                    .into_iter()
+                    .flat_map(|start| pattern.ends(line, start, failure))
+                    .collect();
+                ends.sort_unstable();
+                ends.dedup();
+                ends
+            }),
+            Pattern::Alt(patterns) => {
+                let mut ends: Vec<usize> = patterns
+                    .iter()
+                    .flat_map(|pattern| pattern.ends(line, start, failure))
+                    .collect();
+                ends.sort_unstable();
+                ends.dedup();
+                ends
+            }
+            Pattern::List(item, separator) => {
+                let mut ends: BTreeSet<usize> =
+                    item.ends(line, start, failure).into_iter().collect();
+                let mut frontier: Vec<usize> = ends.iter().copied().collect();
+
+                while !frontier.is_empty() {
+                    frontier = frontier
+                        .into_iter()
+                        .filter(|&end| line[end..].starts_with(separator))
+                        .flat_map(|end| item.ends(line, end + separator.len(), failure))
+                        .filter(|&end| ends.insert(end))
+                        .collect();
+                }
+
+                ends.into_iter().collect()
+            }
+        }
+    }
+
+    /// Check that the whole `line` matches, reporting the furthest position
+    /// the match could reach otherwise.
+    fn check(&self, number: usize, line: &str) -> Option<Issue> {
+        let mut failure = Failure::default();
+        let ends = self.ends(line, 0, &mut failure);
+        if ends.contains(&line.len()) {
+            return None;
+        }
+
+        for end in ends {
+            failure.record(end, || String::from("end of line"));
+        }
+
+        let found = match line[failure.position..].chars().next() {
+            Some(ch) => format!("'{}'", ch.escape_debug()),
+            None => String::from("end of line"),
+        };
+
+        Some(Issue {
+            line: number,
+            column: column(line, failure.position),
+            message: format!(
+                "expected {}, found {}",
+                failure.expected.join(" or "),
+                found
+            ),
+        })
+    }
+}
+
+/// Lines of the input with their 1-based numbers.
+type Lines<'a> = [(usize, &'a str)];
+
+impl InputFormat {
+    /// Every place where the `input` doesn't follow the format. Lines are
+    /// checked independently, so one bad line doesn't hide the others.
+    /// Lines end with "\n" only, so "\r" is reported like any other
+    /// unexpected character. The last line may end with "\n" as well, and
+    /// the parser of every day accepts it.
+    pub fn check(&self, input: &str) -> Vec<Issue> {
+        match self.issues(input, &mut || Ok::<(), Infallible>(())) {
+            Ok(issues) => issues,
+            Err(never) => match never {},
+        }
+    }
+
+    /// Fail with the first place where the `input` doesn't follow the format,
+    /// so solutions only ever see well-formed input. Every line is a step,
+    /// so checking a huge input stops once the day is cancelled.
+    pub fn validate(&self, day: u8, input: &str) -> Result<(), Error> {
+        let mut steps = Steps::new(day);
+
+        match self.issues(input, &mut || steps.step())?.into_iter().next() {
+            Some(issue) => Err(Error::parse(day, issue.line, issue.column, issue.message)),
+            None => Ok(()),
+        }
+    }
+
+    /// Issues of the `input`, calling `step` before checking every line.
+    fn issues<E>(
+        &self,
+        input: &str,
+        step: &mut impl FnMut() -> Result<(), E>,
+    ) -> Result<Vec<Issue>, E> {
+        let text = input.strip_suffix('\n').unwrap_or(input);
+        let lines: Vec<(usize, &str)> = match input {
+            "" => vec![],
+            _ => text
+                .split('\n')
+                .enumerate()
+                .map(|(idx, line)| (idx + 1, line))
+                .collect(),
+        };
+        let mut issues = vec![];
+
+        self.check_lines(&lines, 1, &mut issues, step)?;
+
+        Ok(issues)
+    }
+
+    /// What the input in this format starts with.
+    fn describe(&self) -> String {
+        match self {
+            InputFormat::Line(pattern)
+            | InputFormat::Lines(pattern)
+            | InputFormat::Header(pattern, _) => pattern.describe(),
+            InputFormat::Grid(set) => describe_set(set),
+            InputFormat::Sections(formats) => formats
+                .first()
+                .map_or_else(String::new, |format| format.describe()),
+            InputFormat::Groups(format) => format.describe(),
+        }
+    }
+
+    /// Check the `lines`, which start at line number `start` of the input.
+    fn check_lines<E>(
+        &self,
+        lines: &Lines,
+        start: usize,
+        issues: &mut Vec<Issue>,
+        step: &mut impl FnMut() -> Result<(), E>,
+    ) -> Result<(), E> {
+        if lines.is_empty() {
+            issues.push(Issue {
+                line: start,
+                column: 1,
+                message: format!("expected {}, found end of input", self.describe()),
+            });
+            return Ok(());
+        }
+
+        let end = start + lines.len();
+        match self {
+            InputFormat::Line(pattern) => {
+                let (number, line) = lines[0];
+                step()?;
+                issues.extend(pattern.check(number, line));
+                if lines.len() > 1 {
+                    issues.push(Issue {
+                        line: start + 1,
+                        column: 1,
+                        message: String::from("expected end of input, found another line"),
+                    });
+                }
+            }
+            InputFormat::Lines(pattern) => {
+                for &(number, line) in lines {
+                    step()?;
+                    issues.extend(pattern.check(number, line));
+                }
+            }
+            InputFormat::Header(pattern, rest) => {
+                let (number, line) = lines[0];
+                step()?;
+                issues.extend(pattern.check(number, line));
+                rest.check_lines(&lines[1..], start + 1, issues, step)?;
+            }
+            InputFormat::Grid(set) => Self::check_grid(set, lines, issues, step)?,
+            InputFormat::Sections(formats) => {
+                let sections = Self::split_sections(lines, start);
+
+                for (format, &(start, section)) in formats.iter().zip(&sections) {
+                    format.check_lines(section, start, issues, step)?;
+                }
+                for &(start, _) in sections.iter().skip(formats.len()) {
+                    issues.push(Issue {
+                        line: start,
+                        column: 1,
+                        message: format!(
+                            "expected end of input after {} sections, found another section",
+                            formats.len()
+                        ),
+                    });
+                }
+                if let Some(format) = formats.get(sections.len()) {
+                    issues.push(Issue {
+                        line: end,
+                        column: 1,
+                        message: format!(
+                            "expected {} more section(s) starting with {}, found end of input",
+                            formats.len() - sections.len(),
+                            format.describe()
+                        ),
+                    });
+                }
+            }
+            InputFormat::Groups(format) => {
+                for (start, section) in Self::split_sections(lines, start) {
+                    format.check_lines(section, start, issues, step)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Split the lines on blank lines, keeping the number of the first line
+    /// of every section. Every blank line ends a section, so two blank lines
+    /// in a row give an empty section.
+    fn split_sections<'a, 'b>(lines: &'b Lines<'a>, start: usize) -> Vec<(usize, &'b Lines<'a>)> {
+        let mut next = start;
+
+        lines
+            .split(|(_, line)| line.is_empty())
+            .map(|section| {
+                let start = next;
+                next += section.len() + 1;
+                (start, section)
+            })
+            .collect()
+    }
+
+    fn check_grid<E>(
+        set: &str,
+        lines: &Lines,
+        issues: &mut Vec<Issue>,
+        step: &mut impl FnMut() -> Result<(), E>,
+    ) -> Result<(), E> {
+        let width = lines[0].1.chars().count();
+        if width == 0 {
+            issues.push(Issue {
+                line: lines[0].0,
+                column: 1,
+                message: format!("expected {}, found end of line", describe_set(set)),
+            });
+            return Ok(());
+        }
+
+        for &(number, line) in lines {
+            step()?;
+            for (idx, ch) in line.chars().enumerate() {
+                if !set.contains(ch) {
+                    issues.push(Issue {
+                        line: number,
+                        column: idx + 1,
+                        message: format!(
+                            "expected {}, found '{}'",
+                            describe_set(set),
+                            ch.escape_debug()
+                        ),
+                    });
+                }
+            }
+
+            let len = line.chars().count();
+            if len != width {
+                issues.push(Issue {
+                    line: number,
+                    column: len.min(width) + 1,
+                    message: format!(
+                        "expected a row of {} characters like the first one, found {}",
+                        width, len
+                    ),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::cancel::Cancellation;
+    use super::*;
+    use crate::solutions::YEARS;
+    use std::time::Duration;
+
+    const INSTRUCTION: Pattern = Pattern::Seq(&[
+        Pattern::Alt(&[
+            Pattern::Literal("nop"),
+            Pattern::Literal("acc"),
+            Pattern::Literal("jmp"),
+        ]),
+        Pattern::Literal(" "),
+        Pattern::SignedNumber,
+    ]);
+
+    fn issue(line: usize, column: usize, message: &str) -> Issue {
+        Issue {
+            line,
+            column,
+            message: message.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_report_every_bad_line() {
+        let format = InputFormat::Lines(INSTRUCTION);
+
+        assert_eq!(
+            format.check("nop +0\nxyz +1\nacc +1\njmp 4x\njmp"),
+            vec![
+                issue(2, 1, "expected \"nop\" or \"acc\" or \"jmp\", found 'x'"),
+                issue(4, 6, "expected digit or end of line, found 'x'"),
+                issue(5, 4, "expected \" \", found end of line"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_stops_once_cancelled() {
+        let format = InputFormat::Lines(INSTRUCTION);
+        let input = vec!["nop +0"; 10_000].join("\n");
+
+        assert!(format.validate(8, &input).is_ok());
+        assert_eq!(
+            Cancellation::after(Duration::ZERO)
+                .run(|| format.validate(8, &input))
+                .unwrap_err()
+                .to_string(),
+            "day 8: timed out after 0.00ns"
+        );
+    }
+
+    #[test]
+    fn test_list_with_alternatives() {
+        const BUSES: Pattern = Pattern::List(
+            &Pattern::Alt(&[Pattern::Number, Pattern::Literal("x")]),
+            ",",
+        );
+
+        assert!(BUSES.check(1, "7,13,x,x,59,x,31,19").is_none());
+        assert_eq!(
+            BUSES.check(1, "7,13,,x"),
+            Some(issue(1, 6, "expected number or \"x\", found ','"))
+        );
+    }
+
+    #[test]
+    fn test_sections() {
+        const DECKS: InputFormat = InputFormat::Sections(&[
+            InputFormat::Header(
+                Pattern::Literal("Player 1:"),
+                &InputFormat::Lines(Pattern::Number),
+            ),
+            InputFormat::Header(
+                Pattern::Literal("Player 2:"),
+                &InputFormat::Lines(Pattern::Number),
+            ),
+        ]);
+
+        assert!(DECKS.check("Player 1:\n9\n2\n\nPlayer 2:\n5\n8").is_empty());
+        assert_eq!(
+            DECKS.check("Player 1:\n9\n2a\n"),
+            vec![
+                issue(3, 2, "expected digit or end of line, found 'a'"),
+                issue(
+                    4,
+                    1,
+                    "expected 1 more section(s) starting with \"Player 2:\", found end of input"
+                ),
+            ]
+        );
+        assert_eq!(
+            DECKS.check("Player 1:\n\nPlayer 2:\n5"),
+            vec![issue(2, 1, "expected number, found end of input")]
+        );
+    }
+
+    #[test]
+    fn test_grid() {
+        let format = InputFormat::Grid(".#");
+
+        assert_eq!(
+            format.check("..#\n.?#\n##"),
+            vec![
+                issue(2, 2, "expected one of \".#\", found '?'"),
+                issue(
+                    3,
+                    3,
+                    "expected a row of 3 characters like the first one, found 2"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_empty_grid() {
+        let format = InputFormat::Grid(".#");
+
+        assert_eq!(
+            format.check("\n"),
+            vec![issue(1, 1, "expected one of \".#\", found end of line")]
+        );
+    }
+
+    #[test]
+    fn test_carriage_return_is_not_a_line_ending() {
+        let format = InputFormat::Lines(INSTRUCTION);
+
+        assert!(format.check("nop +0\nacc +1\n").is_empty());
+        assert_eq!(
+            format.check("nop +0\r\nacc +1"),
+            vec![issue(1, 7, "expected digit or end of line, found '\\r'")]
+        );
+    }
+
+    #[test]
+    fn test_validate_fails_with_first_issue() {
+        let format = InputFormat::Lines(INSTRUCTION);
+
+        assert!(format.validate(8, "nop +0\njmp -4").is_ok());
+        assert_eq!(
+            format
+                .validate(8, "nop +0\nxyz +1\njmp")
+                .unwrap_err()
+                .to_string(),
+            "day 8: cannot parse input at line 2, column 1: \
+             expected \"nop\" or \"acc\" or \"jmp\", found 'x'"
+        );
+    }
+
+    #[test]
+    fn test_examples_follow_the_format() {
+        for year in YEARS {
+            for puzzle in year.puzzles {
+                for (idx, example) in puzzle.examples.iter().enumerate() {
+                    let issues = puzzle.format.check(example.input);
+
+                    assert!(
+                        issues.is_empty(),
+                        "{} day {} example {}: {:?}",
+                        year.year,
+                        puzzle.day,
+                        idx + 1,
+                        issues
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use super::solution::Error;
+use std::ops::RangeInclusive;
+
+/// Small random number generator (SplitMix64). It's implemented here, so the
+/// same seed gives the same input on every platform and with every version
+/// of dependencies.
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Number between `from` and `to` inclusive.
+    pub fn between(&mut self, from: u64, to: u64) -> u64 {
+        match (to - from).checked_add(1) {
+            Some(count) => from + self.next_u64() % count,
+            None => self.next_u64(),
+        }
+    }
+
+    /// Index into a collection with `len` elements.
+    pub fn index(&mut self, len: usize) -> usize {
+        self.between(0, len as u64 - 1) as usize
+    }
+
+    /// True with the probability of `percent` / 100.
+    pub fn chance(&mut self, percent: u64) -> bool {
+        self.between(1, 100) <= percent
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for idx in (1..items.len()).rev() {
+            items.swap(idx, self.index(idx + 1));
+        }
+    }
+
+    /// Word of lowercase letters with the length between `from` and `to`.
+    pub fn word(&mut self, from: usize, to: usize) -> String {
+        let len = self.between(from as u64, to as u64);
+
+        (0..len)
+            .map(|_| (b'a' + self.between(0, 25) as u8) as char)
+            .collect()
+    }
+}
+
+/// Generator of synthetic inputs for a day. Generated inputs are always
+/// valid and have answers for both parts.
+pub struct Generator {
+    /// What the size of an input means for the day.
+    pub size: &'static str,
+    pub sizes: RangeInclusive<usize>,
+    pub default_size: usize,
+    pub generate: fn(&mut Rng, usize) -> String,
+}
+
+impl Generator {
+    /// The input for the `seed`. The same seed and size always give the
+    /// same input.
+    pub fn input(&self, seed: u64, size: usize) -> Result<String, Error> {
+        if !self.sizes.contains(&size) {
+            return Err(Error::invalid_argument(format!(
+                "size ({}) must be between {} and {}, got {}",
+                self.size,
+                self.sizes.start(),
+                self.sizes.end(),
+                size
+            )));
+        }
+
+        Ok((self.generate)(&mut Rng::new(seed), size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner;
+    use crate::solutions::{year_2020, YEARS};
+
+    #[test]
+    fn test_rng_is_deterministic() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..10).map(|_| rng.between(1, 6)).collect::<Vec<_>>()
+        };
+
+        assert_eq!(numbers(2020), numbers(2020));
+        assert_ne!(numbers(2020), numbers(2021));
+        assert!(numbers(2020).iter().all(|n| (1..=6).contains(n)));
+    }
+
+    #[test]
+    fn test_reject_unsupported_size() {
+        let generator = year_2020::PUZZLES[0].generator;
+
+        assert!(generator.input(1, generator.sizes.end() + 1).is_err());
+    }
+
+    #[test]
+    fn test_generated_inputs_are_valid_and_solvable() {
+        for year in YEARS {
+            for puzzle in year.puzzles {
+                let generator = puzzle.generator;
+                let size = *generator.sizes.start();
+
+                for seed in 0..3 {
+                    let input = generator.input(seed, size).unwrap();
+                    assert_eq!(input, generator.input(seed, size).unwrap());
+
+                    let issues = puzzle.format.check(&input);
+                    assert!(
+                        issues.is_empty(),
+                        "{} day {} seed {}: {:?}",
+                        year.year,
+                        puzzle.day,
+                        seed,
+                        issues
+                    );
+
+                    // Solving is slow for a few days in debug builds, so only
+                    // one input of each day is solved.
+                    if seed > 0 {
+                        continue;
+                    }
+
+                    let report = runner::run_day(puzzle.day, puzzle.solution, &input, &[1, 2]);
+                    for part in report.parts {
+                        assert!(
+                            part.answer.is_ok(),
+                            "{} day {}-{} seed {}: {:?}\n{}",
+                            year.year,
+                            puzzle.day,
+                            part.part,
+                            seed,
+                            part.answer,
+                            input
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,55 +1,46 @@
+mod answer;
+mod cancel;
+mod format;
+mod generator;
 mod solution;
 
-mod day_01;
-mod day_02;
-mod day_03;
-mod day_04;
-mod day_05;
-mod day_06;
-mod day_07;
-mod day_08;
-mod day_09;
-mod day_10;
-mod day_11;
-mod day_12;
-mod day_13;
-mod day_14;
-mod day_15;
-mod day_16;
-mod day_17;
-mod day_18;
-mod day_19;
-mod day_20;
-mod day_21;
-mod day_22;
-mod day_23;
-mod day_24;
-mod day_25;
+#[macro_use]
+mod registry;
 
+pub use cancel::{check_cancelled, Cancellation, Steps};
+pub use format::{InputFormat, Issue, Pattern};
+pub use generator::{Generator, Rng};
+pub use registry::{Example, Puzzle, Tag, Year};
 pub use solution::*;
 
-pub use day_01::Day01;
-pub use day_02::Day02;
-pub use day_03::Day03;
-pub use day_04::Day04;
-pub use day_05::Day05;
-pub use day_06::Day06;
-pub use day_07::Day07;
-pub use day_08::Day08;
-pub use day_09::Day09;
-pub use day_10::Day10;
-pub use day_11::Day11;
-pub use day_12::Day12;
-pub use day_13::Day13;
-pub use day_14::Day14;
-pub use day_15::Day15;
-pub use day_16::Day16;
-pub use day_17::Day17;
-pub use day_18::Day18;
-pub use day_19::Day19;
-pub use day_20::Day20;
-pub use day_21::Day21;
-pub use day_22::Day22;
-pub use day_23::Day23;
-pub use day_24::Day24;
-pub use day_25::Day25;
+/// The year the crate started with. Its inputs, benches and fuzz targets
+/// keep the names they had before there were other years.
+pub const FIRST_YEAR: u16 = 2020;
+
+pub mod year_2020;
+
+years! {
+    year_2020,
+}
+
+// Solutions of the first year keep the paths they had before solutions were
+// grouped by year, e.g. `aoc2020::Day01` and `aoc2020::PUZZLES`.
+pub use year_2020::*;
+
+/// Puzzle of the given `day` of the first year, if there is a solution for it.
+#[deprecated(note = "use `year(2020)` or `first_year()` and `Year::find` instead")]
+pub fn find(day: u8) -> Option<&'static Puzzle> {
+    year_2020::YEAR.find(day)
+}
+
+/// Solutions of the given `year`, if there are any.
+pub fn year(year: u16) -> Option<&'static Year> {
+    YEARS.iter().find(|y| y.year == year)
+}
+
+/// Solutions of the year the crate started with, used when no year is
+/// given. Inputs of this year are right in the input directory, so a day
+/// without a year never runs the solution of another year on them.
+pub fn first_year() -> &'static Year {
+    year(FIRST_YEAR).expect("the first year is registered")
+}
@@ -0,0 +1,257 @@
+use super::format::{InputFormat, Issue};
+use super::generator::Generator;
+use super::solution::{Error, Solution};
+use std::fmt::{Display, Error as fmtError, Formatter};
+use std::str::FromStr;
+
+/// Rough kind of the puzzle, used to pick a group of days to run.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Tag {
+    Simulation,
+    Graph,
+    Parsing,
+    NumberTheory,
+}
+
+impl Tag {
+    pub const ALL: [Tag; 4] = [Tag::Simulation, Tag::Graph, Tag::Parsing, Tag::NumberTheory];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Tag::Simulation => "simulation",
+            Tag::Graph => "graph",
+            Tag::Parsing => "parsing",
+            Tag::NumberTheory => "number-theory",
+        }
+    }
+}
+
+impl Display for Tag {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmtError> {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Tag {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Tag::ALL
+            .iter()
+            .copied()
+            .find(|tag| tag.name() == s)
+            .ok_or_else(|| format!("Unsupported tag: {}", s))
+    }
+}
+
+/// Example input from the puzzle description with the answers given there.
+/// Not every example has an answer for both parts.
+pub struct Example {
+    pub input: &'static str,
+    pub first: Option<&'static str>,
+    pub second: Option<&'static str>,
+}
+
+impl Example {
+    pub fn expected(&self, part: u8) -> Option<&'static str> {
+        match part {
+            1 => self.first,
+            _ => self.second,
+        }
+    }
+}
+
+/// Solution of a day together with the description of its puzzle.
+pub struct Puzzle {
+    pub day: u8,
+    pub title: &'static str,
+    pub tags: &'static [Tag],
+    pub solution: &'static dyn Solution,
+    pub examples: &'static [Example],
+    /// Shape of the puzzle input, checked by the `lint` command.
+    pub format: &'static InputFormat,
+    /// Source of synthetic inputs for the `generate` command and benches.
+    pub generator: &'static Generator,
+}
+
+impl Puzzle {
+    pub fn has_tag(&self, tag: Tag) -> bool {
+        self.tags.contains(&tag)
+    }
+
+    /// Every place where the `input` doesn't follow the format. Formats
+    /// describe lines one at a time, so an input that follows the format is
+    /// also parsed by the solution, which finds e.g. unbalanced parentheses.
+    pub fn lint(&self, input: &str) -> Vec<Issue> {
+        let issues = self.format.check(input);
+        if !issues.is_empty() {
+            return issues;
+        }
+
+        match self.solution.prepare(input).map(|_| ()) {
+            Ok(()) => vec![],
+            Err(err) => match err.root_cause() {
+                Error::Parse {
+                    line,
+                    column,
+                    message,
+                    ..
+                } => vec![Issue {
+                    line: *line,
+                    column: *column,
+                    message: message.clone(),
+                }],
+                err => vec![Issue {
+                    line: 1,
+                    column: 1,
+                    message: err.to_string(),
+                }],
+            },
+        }
+    }
+}
+
+/// Declare the `PUZZLE` of a day file. The registry in `solutions/mod.rs`
+/// picks it up from there.
+macro_rules! register {
+    (
+        day: $day:expr,
+        title: $title:expr,
+        tags: [$($tag:ident),* $(,)?],
+        solution: $solution:ident,
+        examples: $examples:expr,
+        format: $format:expr,
+        generator: $generator:expr $(,)?
+    ) => {
+        pub const PUZZLE: $crate::solutions::Puzzle = $crate::solutions::Puzzle {
+            day: $day,
+            title: $title,
+            tags: &[$($crate::solutions::Tag::$tag),*],
+            solution: &$solution {},
+            examples: $examples,
+            format: &$format,
+            generator: &$generator,
+        };
+    };
+}
+
+/// Puzzles of one Advent of Code event.
+pub struct Year {
+    pub year: u16,
+    /// Every registered puzzle of the year, ordered by day.
+    pub puzzles: &'static [Puzzle],
+}
+
+impl Year {
+    /// Puzzle of the given `day`, if there is a solution for it.
+    pub fn find(&self, day: u8) -> Option<&'static Puzzle> {
+        self.puzzles.iter().find(|puzzle| puzzle.day == day)
+    }
+}
+
+/// Collect the puzzles of the day modules of a year into `PUZZLES` and
+/// `YEAR`. The modules are declared next to the macro as plain `mod` items,
+/// so tools like rustfmt find them. Days have to be listed in order,
+/// starting from 1 and without gaps, otherwise the crate doesn't compile.
+macro_rules! days {
+    (year: $year:expr, $($module:ident),* $(,)?) => {
+        /// Every registered puzzle of the year, ordered by day.
+        pub const PUZZLES: &[$crate::solutions::Puzzle] = &[$($module::PUZZLE),*];
+
+        pub const YEAR: $crate::solutions::Year = $crate::solutions::Year {
+            year: $year,
+            puzzles: PUZZLES,
+        };
+
+        const _: () = {
+            let mut index = 0;
+            while index < PUZZLES.len() {
+                assert!(
+                    PUZZLES[index].day as usize == index + 1,
+                    "days must be registered in order, without gaps or duplicates"
+                );
+                index += 1;
+            }
+        };
+    };
+}
+
+/// Collect the years of the year modules, declared next to the macro as
+/// plain `mod` items, into `YEARS`. Years have to be listed in order,
+/// otherwise the crate doesn't compile.
+macro_rules! years {
+    ($($module:ident),* $(,)?) => {
+        /// Every year with solutions, oldest first.
+        pub const YEARS: &[Year] = &[$($module::YEAR),*];
+
+        const _: () = {
+            let mut index = 1;
+            while index < YEARS.len() {
+                assert!(
+                    YEARS[index - 1].year < YEARS[index].year,
+                    "years must be registered in order, without duplicates"
+                );
+                index += 1;
+            }
+        };
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tag() {
+        for tag in Tag::ALL.iter() {
+            assert_eq!(tag.name().parse::<Tag>().unwrap(), *tag);
+        }
+        assert!("dynamic-programming".parse::<Tag>().is_err());
+    }
+
+    #[test]
+    fn test_lint_runs_parser_after_format() {
+        let puzzle = crate::solutions::year_2020::YEAR.find(18).unwrap();
+        let lint = |input: &str| {
+            puzzle
+                .lint(input)
+                .iter()
+                .map(Issue::to_string)
+                .collect::<Vec<_>>()
+        };
+
+        assert!(lint("1 + (2 * 3)").is_empty());
+        assert_eq!(
+            lint("1 + 2\n(1 + 2"),
+            vec!["2:7: expected ')', found end of line"]
+        );
+        assert_eq!(
+            lint("1 +"),
+            vec!["1:4: expected number or '(', found end of line"]
+        );
+        assert_eq!(
+            lint("1 + a"),
+            vec!["1:5: expected one of \"0123456789+*() \" or end of line, found 'a'"]
+        );
+    }
+
+    #[test]
+    fn test_lint_accepts_trailing_newline() {
+        for year in crate::solutions::YEARS {
+            for puzzle in year.puzzles {
+                for example in puzzle.examples {
+                    let input = format!("{}\n", example.input.trim_end_matches('\n'));
+
+                    assert_eq!(
+                        puzzle.lint(&input),
+                        vec![],
+                        "{} day {}: {:?}",
+                        year.year,
+                        puzzle.day,
+                        input
+                    );
+                }
+            }
+        }
+    }
+}
@@ -1,19 +1,248 @@
+pub use super::answer::Answer;
 use std::error::Error as ErrorTrait;
 use std::fmt::{Display, Error as fmtError, Formatter};
+use std::time::Duration;
 
-pub trait Solution {
-    fn first_task(&self, input: &str) -> Result<String, Error>;
+/// Solution of a day. The input is parsed once by `parse` and both parts are
+/// solved from the parsed `Input`.
+pub trait Challenge: Send + Sync {
+    type Input<'a>;
 
-    fn second_task(&self, input: &str) -> Result<String, Error>;
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, Error>;
+
+    fn first_part(&self, input: &Self::Input<'_>) -> Result<Answer, Error>;
+
+    fn second_part(&self, input: &Self::Input<'_>) -> Result<Answer, Error>;
+}
+
+/// Parsed input of a day, ready to solve both parts.
+pub trait Parsed {
+    fn first_part(&self) -> Result<Answer, Error>;
+
+    fn second_part(&self) -> Result<Answer, Error>;
 }
 
-#[derive(Debug)]
-pub struct Error {}
+/// Object-safe view of a `Challenge`, so days with different `Input` types
+/// can be kept together. Solutions are shared between worker threads when
+/// all days run in parallel.
+pub trait Solution: Send + Sync {
+    fn prepare<'a>(&'a self, input: &'a str) -> Result<Box<dyn Parsed + 'a>, Error>;
+
+    fn first_task(&self, input: &str) -> Result<Answer, Error> {
+        self.prepare(input)?.first_part()
+    }
+
+    fn second_task(&self, input: &str) -> Result<Answer, Error> {
+        self.prepare(input)?.second_part()
+    }
+}
+
+struct ParsedInput<'a, C: Challenge> {
+    challenge: &'a C,
+    input: C::Input<'a>,
+}
+
+impl<C: Challenge> Parsed for ParsedInput<'_, C> {
+    fn first_part(&self) -> Result<Answer, Error> {
+        self.challenge.first_part(&self.input)
+    }
+
+    fn second_part(&self) -> Result<Answer, Error> {
+        self.challenge.second_part(&self.input)
+    }
+}
+
+impl<C: Challenge> Solution for C {
+    fn prepare<'a>(&'a self, input: &'a str) -> Result<Box<dyn Parsed + 'a>, Error> {
+        Ok(Box::new(ParsedInput {
+            challenge: self,
+            input: self.parse(input)?,
+        }))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Error {
+    /// Puzzle input doesn't follow the format described in the puzzle.
+    /// Both `line` and `column` are 1-based.
+    Parse {
+        day: u8,
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// Puzzle input is well-formed, but it has no answer.
+    NoSolution { day: u8, message: String },
+    /// Solution panicked. The location is "file:line:column" of the panic,
+    /// when it's known.
+    Panicked {
+        day: u8,
+        message: String,
+        location: Option<String>,
+    },
+    /// Solution was cancelled before it found the answer.
+    TimedOut { day: u8, after: Duration },
+    /// Caller passed a value the solution can't work with.
+    InvalidArgument(String),
+    /// Something that should never happen, happened.
+    Internal(String),
+    /// Another error with a human-readable explanation of what was going on.
+    Context { context: String, source: Box<Error> },
+}
+
+impl Error {
+    pub fn parse(day: u8, line: usize, column: usize, message: impl Into<String>) -> Error {
+        Error::Parse {
+            day,
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    pub fn no_solution(day: u8, message: impl Into<String>) -> Error {
+        Error::NoSolution {
+            day,
+            message: message.into(),
+        }
+    }
+
+    pub fn panicked(day: u8, message: impl Into<String>, location: Option<String>) -> Error {
+        Error::Panicked {
+            day,
+            message: message.into(),
+            location,
+        }
+    }
+
+    pub fn timed_out(day: u8, after: Duration) -> Error {
+        Error::TimedOut { day, after }
+    }
+
+    pub fn invalid_argument(message: impl Into<String>) -> Error {
+        Error::InvalidArgument(message.into())
+    }
+
+    pub fn internal(message: impl Into<String>) -> Error {
+        Error::Internal(message.into())
+    }
+
+    pub fn context(self, context: impl Into<String>) -> Error {
+        Error::Context {
+            context: context.into(),
+            source: Box::new(self),
+        }
+    }
+
+    /// Error at the bottom of the context chain.
+    pub fn root_cause(&self) -> &Error {
+        match self {
+            Error::Context { source, .. } => source.root_cause(),
+            err => err,
+        }
+    }
+}
 
 impl Display for Error {
-    fn fmt(&self, _: &mut Formatter<'_>) -> Result<(), fmtError> {
-        Ok(())
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmtError> {
+        match self {
+            Error::Parse {
+                day,
+                line,
+                column,
+                message,
+            } => write!(
+                f,
+                "day {}: cannot parse input at line {}, column {}: {}",
+                day, line, column, message
+            ),
+            Error::NoSolution { day, message } => {
+                write!(f, "day {}: no solution found: {}", day, message)
+            }
+            Error::Panicked {
+                day,
+                message,
+                location: Some(location),
+            } => write!(
+                f,
+                "day {}: solution panicked at {}: {}",
+                day, location, message
+            ),
+            Error::Panicked {
+                day,
+                message,
+                location: None,
+            } => write!(f, "day {}: solution panicked: {}", day, message),
+            Error::TimedOut { day, after } => {
+                write!(f, "day {}: timed out after {:.2?}", day, after)
+            }
+            Error::InvalidArgument(message) => write!(f, "invalid argument: {}", message),
+            Error::Internal(message) => write!(f, "internal error: {}", message),
+            Error::Context { context, .. } => write!(f, "{}", context),
+        }
+    }
+}
+
+impl ErrorTrait for Error {
+    fn source(&self) -> Option<&(dyn ErrorTrait + 'static)> {
+        match self {
+            Error::Context { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+/// Attach context to the error of a `Result`.
+pub trait Context<T> {
+    fn context(self, context: impl Into<String>) -> Result<T, Error>;
+
+    fn with_context<C, F>(self, context: F) -> Result<T, Error>
+    where
+        C: Into<String>,
+        F: FnOnce() -> C;
+}
+
+impl<T> Context<T> for Result<T, Error> {
+    fn context(self, context: impl Into<String>) -> Result<T, Error> {
+        self.map_err(|err| err.context(context))
+    }
+
+    fn with_context<C, F>(self, context: F) -> Result<T, Error>
+    where
+        C: Into<String>,
+        F: FnOnce() -> C,
+    {
+        self.map_err(|err| err.context(context()))
     }
 }
 
-impl ErrorTrait for Error {}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_parse_error() {
+        let err = Error::parse(8, 3, 1, "unknown opcode \"xyz\"");
+
+        assert_eq!(
+            err.to_string(),
+            "day 8: cannot parse input at line 3, column 1: unknown opcode \"xyz\""
+        );
+    }
+
+    #[test]
+    fn test_context_chain() {
+        let result: Result<(), Error> = Err(Error::no_solution(8, "program never loops"));
+        let err = result
+            .context("solving first task")
+            .with_context(|| format!("running day {}", 8))
+            .unwrap_err();
+
+        assert_eq!(err.to_string(), "running day 8");
+        assert_eq!(err.source().unwrap().to_string(), "solving first task");
+        assert_eq!(
+            err.root_cause().to_string(),
+            "day 8: no solution found: program never loops"
+        );
+    }
+}
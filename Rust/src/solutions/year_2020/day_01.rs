@@ -0,0 +1,263 @@
+use super::cancel::Steps;
+use super::format::{InputFormat, Pattern};
+use super::generator::{Generator, Rng};
+use super::registry::Example;
+use super::solution::{Answer, Challenge, Error as ChallengeErr};
+use itertools::Itertools;
+use std::cmp::{Ordering, PartialOrd};
+use std::collections::HashSet;
+use std::ops::Add;
+
+const DAY: u8 = 1;
+const NEW_YEAR: i64 = 2020;
+
+/// Every pair of entries compared is a step.
+fn find_two_indexes_with_given_sum_helper<T>(
+    sorted: &[T],
+    mut start: usize,
+    mut end: usize,
+    target: T,
+    steps: &mut Steps,
+) -> Result<Option<(usize, usize)>, ChallengeErr>
+where
+    T: Add<Output = T> + PartialOrd + Copy,
+{
+    use Ordering::*;
+
+    while start < end {
+        steps.step()?;
+
+        match (sorted[start] + sorted[end]).partial_cmp(&target) {
+            Some(Equal) => return Ok(Some((start, end))),
+            Some(Less) => start += 1,
+            Some(Greater) => end -= 1,
+            None => return Ok(None),
+        };
+    }
+
+    Ok(None)
+}
+
+fn find_two_indexes_with_given_sum<T>(
+    sorted: &[T],
+    target: T,
+    steps: &mut Steps,
+) -> Result<Option<(usize, usize)>, ChallengeErr>
+where
+    T: Add<Output = T> + PartialOrd + Copy,
+{
+    find_two_indexes_with_given_sum_helper(sorted, 0, sorted.len() - 1, target, steps)
+}
+
+/// Number of pairs and triples of the `entries` summing up to 2020.
+fn count_sums_to_new_year(entries: &[u64]) -> (usize, usize) {
+    let new_year = NEW_YEAR as u64;
+    let count = |k| {
+        entries
+            .iter()
+            .combinations(k)
+            .filter(|combination| combination.iter().copied().sum::<u64>() == new_year)
+            .count()
+    };
+
+    (count(2), count(3))
+}
+
+/// Entries with exactly one pair and one triple summing up to 2020, so the
+/// answers don't depend on the order of the search. The other entries are
+/// larger than half of 2020, so no two of them are in a pair or a triple,
+/// and none of them completes one with the planted entries.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let new_year = NEW_YEAR as u64;
+    let planted = loop {
+        let pair = rng.between(1, new_year - 1);
+        let first = rng.between(1, new_year - 2);
+        let second = rng.between(1, new_year - 1 - first);
+        let planted = vec![
+            pair,
+            new_year - pair,
+            first,
+            second,
+            new_year - first - second,
+        ];
+
+        if count_sums_to_new_year(&planted) == (1, 1) {
+            break planted;
+        }
+    };
+
+    let completing: HashSet<u64> = planted
+        .iter()
+        .map(|&entry| vec![entry])
+        .chain(planted.iter().copied().combinations(2))
+        .filter_map(|entries| new_year.checked_sub(entries.iter().sum()))
+        .collect();
+
+    let mut entries = planted;
+    while entries.len() < size {
+        let entry = rng.between(new_year / 2 + 1, new_year - 1);
+        if !completing.contains(&entry) {
+            entries.push(entry);
+        }
+    }
+    rng.shuffle(&mut entries);
+
+    entries
+        .iter()
+        .map(|entry| entry.to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub struct Day01 {}
+
+const EXAMPLES: &[Example] = &[Example {
+    input: "1721
+979
+366
+299
+675
+1456",
+    first: Some("514579"),
+    second: Some("241861950"),
+}];
+
+const FORMAT: InputFormat = InputFormat::Lines(Pattern::Number);
+
+const GENERATOR: Generator = Generator {
+    size: "number of entries",
+    sizes: 5..=100_000,
+    default_size: 2_000,
+    generate,
+};
+
+register! {
+    day: DAY,
+    title: "Report Repair",
+    tags: [],
+    solution: Day01,
+    examples: EXAMPLES,
+    format: FORMAT,
+    generator: GENERATOR,
+}
+
+impl Day01 {
+    /// Entries fit into `i32`, so sums of them never overflow `i64`.
+    fn parse_input(input: &str) -> Result<Vec<i64>, ChallengeErr> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| {
+                line.parse::<i32>().map(i64::from).map_err(|_| {
+                    ChallengeErr::parse(DAY, idx + 1, 1, format!("invalid entry \"{}\"", line))
+                })
+            })
+            .collect()
+    }
+
+    fn find_product_of_two_numbers_equal_to_2020(
+        entities: &[i64],
+    ) -> Result<Option<i64>, ChallengeErr> {
+        let mut entities_copy = entities.to_vec();
+        entities_copy.sort_unstable();
+
+        let indexes =
+            find_two_indexes_with_given_sum(&entities_copy, NEW_YEAR, &mut Steps::new(DAY))?;
+
+        Ok(indexes.map(|(f, s)| entities_copy[f] * entities_copy[s]))
+    }
+
+    fn find_product_of_three_numbers_equal_to_2020(
+        entities: &[i64],
+    ) -> Result<Option<i64>, ChallengeErr> {
+        let mut entities_copy = entities.to_vec();
+        entities_copy.sort_unstable();
+        let mut steps = Steps::new(DAY);
+
+        // The other two entries are searched after the first one, so no entry
+        // is used twice.
+        for f in 0..entities.len().saturating_sub(2) {
+            let target_sum = NEW_YEAR - entities_copy[f];
+            let end = entities_copy.len() - 1;
+
+            if let Some((l, r)) = find_two_indexes_with_given_sum_helper(
+                &entities_copy,
+                f + 1,
+                end,
+                target_sum,
+                &mut steps,
+            )? {
+                return Ok(Some(entities_copy[f] * entities_copy[l] * entities_copy[r]));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+impl Challenge for Day01 {
+    type Input<'a> = Vec<i64>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ChallengeErr> {
+        FORMAT.validate(DAY, input)?;
+
+        Day01::parse_input(input)
+    }
+
+    fn first_part(&self, entities: &Self::Input<'_>) -> Result<Answer, ChallengeErr> {
+        Day01::find_product_of_two_numbers_equal_to_2020(entities)?
+            .map(|v| v.into())
+            .ok_or_else(|| ChallengeErr::no_solution(DAY, "no two entries sum to 2020"))
+    }
+
+    fn second_part(&self, entities: &Self::Input<'_>) -> Result<Answer, ChallengeErr> {
+        Day01::find_product_of_three_numbers_equal_to_2020(entities)?
+            .map(|v| v.into())
+            .ok_or_else(|| ChallengeErr::no_solution(DAY, "no three entries sum to 2020"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_find_product_of_two_numbers() {
+        let test_entries = [1721, 979, 366, 299, 675, 1456];
+
+        assert_eq!(
+            Day01::find_product_of_two_numbers_equal_to_2020(&test_entries).unwrap(),
+            Some(514579)
+        );
+    }
+
+    #[test]
+    fn example_find_product_of_three_numbers() {
+        let test_entries = [1721, 979, 366, 299, 675, 1456];
+
+        assert_eq!(
+            Day01::find_product_of_three_numbers_equal_to_2020(&test_entries).unwrap(),
+            Some(241861950)
+        );
+    }
+
+    #[test]
+    fn test_three_numbers_are_different_entries() {
+        let test_entries = [3, 5, 2010, 1000];
+
+        assert_eq!(
+            Day01::find_product_of_three_numbers_equal_to_2020(&test_entries).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_generated_entries_have_one_pair_and_one_triple() {
+        for seed in 0..3 {
+            let input = GENERATOR.input(seed, 100).unwrap();
+            let entries: Vec<u64> = input.lines().map(|line| line.parse().unwrap()).collect();
+
+            assert_eq!(count_sums_to_new_year(&entries), (1, 1), "seed {}", seed);
+        }
+    }
+}
@@ -0,0 +1,204 @@
+use super::format::{InputFormat, Pattern, LOWERCASE};
+use super::generator::{Generator, Rng};
+use super::registry::Example;
+use super::solution::{Answer, Challenge, Error as ChallengeErr};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+const DAY: u8 = 2;
+
+lazy_static! {
+    static ref PASSWORD_PATTERN: Regex = Regex::new(r"^(\d+)-(\d+) (\w): (\w+)$").unwrap();
+}
+
+pub struct PasswordDefinition<'a> {
+    first_number: usize,
+    second_number: usize,
+    letter: char,
+    password: &'a str,
+}
+
+impl PasswordDefinition<'_> {
+    fn from(line: usize, password_text: &str) -> Result<PasswordDefinition<'_>, ChallengeErr> {
+        let captures = PASSWORD_PATTERN.captures(password_text).ok_or_else(|| {
+            ChallengeErr::parse(
+                DAY,
+                line,
+                1,
+                format!("invalid password \"{}\"", password_text),
+            )
+        })?;
+        let number = |idx| {
+            let number = captures.get(idx).unwrap();
+            number.as_str().parse().map_err(|_| {
+                ChallengeErr::parse(
+                    DAY,
+                    line,
+                    number.start() + 1,
+                    format!("number \"{}\" is too large", number.as_str()),
+                )
+            })
+        };
+
+        let first_number = number(1)?;
+        let second_number = number(2)?;
+        let letter: char = captures.get(3).unwrap().as_str().chars().next().unwrap();
+        let password: &str = captures.get(4).unwrap().as_str();
+
+        Ok(PasswordDefinition {
+            first_number,
+            second_number,
+            letter,
+            password,
+        })
+    }
+
+    fn align_with_old_policy(&self) -> bool {
+        let occurences_of_letter = self
+            .password
+            .chars()
+            .filter(|&ch| ch == self.letter)
+            .count();
+
+        self.first_number <= occurences_of_letter && occurences_of_letter <= self.second_number
+    }
+
+    /// Positions are 1-based. A position outside of the password never
+    /// holds the letter.
+    fn has_letter_at(&self, position: usize) -> bool {
+        position
+            .checked_sub(1)
+            .and_then(|idx| self.password.chars().nth(idx))
+            == Some(self.letter)
+    }
+
+    fn align_with_current_policy(&self) -> bool {
+        self.has_letter_at(self.first_number) ^ self.has_letter_at(self.second_number)
+    }
+}
+
+fn parse_passwords(passwords_text: &str) -> Result<Vec<PasswordDefinition<'_>>, ChallengeErr> {
+    passwords_text
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| PasswordDefinition::from(idx + 1, line))
+        .collect()
+}
+
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut lines = vec![];
+
+    for _ in 0..size {
+        let password = rng.word(2, 20);
+        let letter = if rng.chance(50) {
+            password.as_bytes()[rng.index(password.len())] as char
+        } else {
+            rng.word(1, 1).as_bytes()[0] as char
+        };
+        let first = rng.between(1, password.len() as u64 - 1);
+        let second = rng.between(first + 1, password.len() as u64);
+
+        lines.push(format!("{}-{} {}: {}", first, second, letter, password));
+    }
+
+    lines.join("\n")
+}
+
+pub struct Day02 {}
+
+const EXAMPLES: &[Example] = &[Example {
+    input: "1-3 a: abcde
+1-3 b: cdefg
+2-9 c: ccccccccc",
+    first: Some("2"),
+    second: Some("1"),
+}];
+
+const FORMAT: InputFormat = InputFormat::Lines(Pattern::Seq(&[
+    Pattern::Number,
+    Pattern::Literal("-"),
+    Pattern::Number,
+    Pattern::Literal(" "),
+    Pattern::Char(LOWERCASE),
+    Pattern::Literal(": "),
+    Pattern::Word,
+]));
+
+const GENERATOR: Generator = Generator {
+    size: "number of passwords",
+    sizes: 1..=1_000_000,
+    default_size: 10_000,
+    generate,
+};
+
+register! {
+    day: DAY,
+    title: "Password Philosophy",
+    tags: [Parsing],
+    solution: Day02,
+    examples: EXAMPLES,
+    format: FORMAT,
+    generator: GENERATOR,
+}
+
+impl Day02 {
+    fn count_old_valid_passwords(passwords: &[PasswordDefinition]) -> i32 {
+        passwords
+            .iter()
+            .filter(|def| def.align_with_old_policy())
+            .count() as i32
+    }
+
+    fn count_current_valid_passwords(passwords: &[PasswordDefinition]) -> i32 {
+        passwords
+            .iter()
+            .filter(|def| def.align_with_current_policy())
+            .count() as i32
+    }
+}
+
+impl Challenge for Day02 {
+    type Input<'a> = Vec<PasswordDefinition<'a>>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ChallengeErr> {
+        FORMAT.validate(DAY, input)?;
+
+        parse_passwords(input)
+    }
+
+    fn first_part(&self, passwords_list: &Self::Input<'_>) -> Result<Answer, ChallengeErr> {
+        Ok(Day02::count_old_valid_passwords(passwords_list).into())
+    }
+
+    fn second_part(&self, passwords_list: &Self::Input<'_>) -> Result<Answer, ChallengeErr> {
+        Ok(Day02::count_current_valid_passwords(passwords_list).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_count_old_valid_passwords() {
+        let test_passwords =
+            parse_passwords("1-3 a: abcde\n1-3 b: cdefg\n2-9 c: ccccccccc").unwrap();
+
+        assert_eq!(Day02::count_old_valid_passwords(&test_passwords), 2);
+    }
+
+    #[test]
+    fn test_position_outside_of_password() {
+        let passwords = parse_passwords("0-9 a: abcde").unwrap();
+
+        assert_eq!(Day02::count_current_valid_passwords(&passwords), 0);
+    }
+
+    #[test]
+    fn example_count_current_valid_passwords() {
+        let test_passwords =
+            parse_passwords("1-3 a: abcde\n1-3 b: cdefg\n2-9 c: ccccccccc").unwrap();
+
+        assert_eq!(Day02::count_current_valid_passwords(&test_passwords), 1);
+    }
+}
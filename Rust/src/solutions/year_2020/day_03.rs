@@ -0,0 +1,164 @@
+use super::format::InputFormat;
+use super::generator::{Generator, Rng};
+use super::registry::Example;
+use super::solution::{Answer, Challenge, Error as ChallengeErr};
+
+const DAY: u8 = 3;
+
+static SLOPES: [Point; 5] = [
+    Point { x: 1, y: 1 },
+    Point { x: 3, y: 1 },
+    Point { x: 5, y: 1 },
+    Point { x: 7, y: 1 },
+    Point { x: 1, y: 2 },
+];
+
+struct Point {
+    x: i8,
+    y: i8,
+}
+
+fn generate(rng: &mut Rng, size: usize) -> String {
+    const WIDTH: usize = 31;
+
+    (0..size)
+        .map(|_| {
+            (0..WIDTH)
+                .map(|_| if rng.chance(25) { '#' } else { '.' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub struct Day03 {}
+
+const EXAMPLES: &[Example] = &[Example {
+    input: "..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#",
+    first: Some("7"),
+    second: Some("336"),
+}];
+
+const FORMAT: InputFormat = InputFormat::Grid(".#");
+
+const GENERATOR: Generator = Generator {
+    size: "number of rows",
+    sizes: 1..=1_000_000,
+    default_size: 3_000,
+    generate,
+};
+
+register! {
+    day: DAY,
+    title: "Toboggan Trajectory",
+    tags: [Simulation],
+    solution: Day03,
+    examples: EXAMPLES,
+    format: FORMAT,
+    generator: GENERATOR,
+}
+
+impl Day03 {
+    fn count_trees_on_slope(map: &[&str], slope: &Point) -> u32 {
+        let height = map.len();
+        let width = map[0].chars().count();
+        let (dc, dr) = (slope.x as usize, slope.y as usize);
+        let mut cr = 0;
+        let mut cc = 0;
+        let mut trees = 0;
+
+        while cr < height {
+            if map[cr].chars().nth(cc).unwrap() == '#' {
+                trees += 1;
+            }
+
+            cr += dr;
+            cc = (cc + dc) % width;
+        }
+
+        trees
+    }
+
+    fn find_product_of_trees_on_slopes(map: &[&str], slopes: &[Point]) -> Option<u64> {
+        slopes
+            .iter()
+            .map(|s| Day03::count_trees_on_slope(map, s) as u64)
+            .try_fold(1_u64, |product, trees| product.checked_mul(trees))
+    }
+}
+
+impl Challenge for Day03 {
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ChallengeErr> {
+        FORMAT.validate(DAY, input)?;
+
+        Ok(input.lines().collect())
+    }
+
+    fn first_part(&self, area_map: &Self::Input<'_>) -> Result<Answer, ChallengeErr> {
+        Ok(Day03::count_trees_on_slope(area_map, &SLOPES[1]).into())
+    }
+
+    fn second_part(&self, area_map: &Self::Input<'_>) -> Result<Answer, ChallengeErr> {
+        Day03::find_product_of_trees_on_slopes(area_map, &SLOPES)
+            .map(|v| v.into())
+            .ok_or_else(|| ChallengeErr::no_solution(DAY, "product of trees is too large"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_count_trees_on_slope() {
+        let test_map = [
+            "..##.......",
+            "#...#...#..",
+            ".#....#..#.",
+            "..#.#...#.#",
+            ".#...##..#.",
+            "..#.##.....",
+            ".#.#.#....#",
+            ".#........#",
+            "#.##...#...",
+            "#...##....#",
+            ".#..#...#.#",
+        ];
+
+        assert_eq!(Day03::count_trees_on_slope(&test_map, &SLOPES[1]), 7);
+    }
+
+    #[test]
+    fn example_find_product_of_trees_on_slopes() {
+        let test_map = [
+            "..##.......",
+            "#...#...#..",
+            ".#....#..#.",
+            "..#.#...#.#",
+            ".#...##..#.",
+            "..#.##.....",
+            ".#.#.#....#",
+            ".#........#",
+            "#.##...#...",
+            "#...##....#",
+            ".#..#...#.#",
+        ];
+
+        assert_eq!(
+            Day03::find_product_of_trees_on_slopes(&test_map, &SLOPES),
+            Some(336)
+        );
+    }
+}
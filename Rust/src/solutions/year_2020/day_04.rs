@@ -0,0 +1,355 @@
+use super::format::{InputFormat, Pattern};
+use super::generator::{Generator, Rng};
+use super::registry::Example;
+use super::solution::{Answer, Challenge, Error as ChallengeErr};
+use std::collections::HashMap;
+
+const DAY: u8 = 4;
+
+type Passport<'a> = HashMap<&'a str, &'a str>;
+
+fn is_number(num: &str, length: usize) -> bool {
+    num.len() == length && num.chars().all(|ch| ch.is_ascii_digit())
+}
+
+fn is_number_between(num: &str, length: usize, from: i32, to: i32) -> bool {
+    is_number(num, length) && {
+        let number: i32 = num.parse().unwrap();
+
+        from <= number && number <= to
+    }
+}
+
+fn is_year_between(num: &str, from: i32, to: i32) -> bool {
+    is_number_between(num, 4, from, to)
+}
+
+fn is_height_valid(value: &str) -> bool {
+    match (value.strip_suffix("cm"), value.strip_suffix("in")) {
+        (Some(cm), _) => is_number_between(cm, 3, 150, 193),
+        (_, Some(inches)) => is_number_between(inches, 2, 59, 76),
+        _ => false,
+    }
+}
+
+fn is_hair_color_valid(value: &str) -> bool {
+    value.len() == 7
+        && value.starts_with('#')
+        && value.chars().skip(1).all(|ch| ch.is_ascii_hexdigit())
+}
+
+fn is_eye_color_valid(value: &str) -> bool {
+    matches!(value, "amb" | "blu" | "brn" | "gry" | "grn" | "hzl" | "oth")
+}
+
+fn is_pid_valid(value: &str) -> bool {
+    is_number(value, 9)
+}
+
+fn is_passport_valid_weak(passport: &HashMap<&str, &str>) -> bool {
+    passport.len() == 8 || (passport.len() == 7 && !passport.contains_key("cid"))
+}
+
+fn is_field_valid(name: &str, value: &str) -> bool {
+    match name {
+        "byr" => is_year_between(value, 1920, 2002),
+        "iyr" => is_year_between(value, 2010, 2020),
+        "eyr" => is_year_between(value, 2020, 2030),
+        "hgt" => is_height_valid(value),
+        "hcl" => is_hair_color_valid(value),
+        "ecl" => is_eye_color_valid(value),
+        "pid" => is_pid_valid(value),
+        "cid" => true,
+        _ => false,
+    }
+}
+
+fn is_passport_valid_strong(passport: &HashMap<&str, &str>) -> bool {
+    is_passport_valid_weak(passport)
+        && passport
+            .iter()
+            .all(|(name, value)| is_field_valid(name, value))
+}
+
+fn parse_password(password_entity: &str) -> HashMap<&str, &str> {
+    password_entity
+        .split_whitespace()
+        .map(|e| e.trim().splitn(2, ':').collect::<Vec<&str>>())
+        .map(|pair| (pair[0], pair[1]))
+        .collect()
+}
+
+fn parse_passwords(passwords_text: &str) -> Vec<HashMap<&str, &str>> {
+    passwords_text.split("\n\n").map(&parse_password).collect()
+}
+
+fn count_valid_passwords(
+    passports: &[Passport],
+    password_policy: &dyn Fn(&HashMap<&str, &str>) -> bool,
+) -> i32 {
+    passports.iter().filter(|p| password_policy(p)).count() as i32
+}
+
+fn count_passwords_with_weak_validation(passports: &[Passport]) -> i32 {
+    count_valid_passwords(passports, &is_passport_valid_weak)
+}
+
+fn count_passwords_with_strong_validation(passports: &[Passport]) -> i32 {
+    count_valid_passwords(passports, &is_passport_valid_strong)
+}
+
+fn generate_field(rng: &mut Rng, name: &str, valid: bool) -> String {
+    const EYE_COLORS: [&str; 7] = ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
+    const HEX_DIGITS: &[u8] = b"0123456789abcdef";
+
+    let value = match (name, valid) {
+        ("byr", true) => rng.between(1920, 2002).to_string(),
+        ("byr", false) => rng.between(1900, 1919).to_string(),
+        ("iyr", true) => rng.between(2010, 2020).to_string(),
+        ("iyr", false) => rng.between(2000, 2009).to_string(),
+        ("eyr", true) => rng.between(2020, 2030).to_string(),
+        ("eyr", false) => rng.between(2031, 2040).to_string(),
+        ("hgt", true) => match rng.chance(50) {
+            true => format!("{}cm", rng.between(150, 193)),
+            false => format!("{}in", rng.between(59, 76)),
+        },
+        ("hgt", false) => rng.between(59, 193).to_string(),
+        ("hcl", valid) => {
+            let color: String = (0..6).map(|_| *rng.pick(HEX_DIGITS) as char).collect();
+            if valid {
+                format!("#{}", color)
+            } else {
+                color
+            }
+        }
+        ("ecl", true) => rng.pick(&EYE_COLORS).to_string(),
+        ("ecl", false) => rng.word(3, 3),
+        ("pid", valid) => {
+            let len = if valid { 9 } else { 10 };
+            (0..len).map(|_| rng.between(0, 9).to_string()).collect()
+        }
+        _ => rng.between(100, 350).to_string(),
+    };
+
+    format!("{}:{}", name, value)
+}
+
+/// Passports with a few missing or invalid fields, each one spread over
+/// several lines.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    const FIELDS: [&str; 8] = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid", "cid"];
+    let mut passports = vec![];
+
+    for _ in 0..size {
+        let missing = if rng.chance(20) {
+            Some(FIELDS[rng.index(7)])
+        } else {
+            None
+        };
+
+        let mut fields = vec![];
+        for &name in FIELDS.iter() {
+            if Some(name) == missing || (name == "cid" && rng.chance(50)) {
+                continue;
+            }
+            let valid = rng.chance(85);
+            fields.push(generate_field(rng, name, valid));
+        }
+        rng.shuffle(&mut fields);
+
+        let mut passport = String::new();
+        for (idx, field) in fields.iter().enumerate() {
+            if idx > 0 {
+                passport.push(if rng.chance(25) { '\n' } else { ' ' });
+            }
+            passport.push_str(field);
+        }
+        passports.push(passport);
+    }
+
+    passports.join("\n\n")
+}
+
+pub struct Day04 {}
+
+const EXAMPLES: &[Example] = &[
+    Example {
+        input: "ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
+byr:1937 iyr:2017 cid:147 hgt:183cm
+
+iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
+hcl:#cfa07d byr:1929
+
+hcl:#ae17e1 iyr:2013
+eyr:2024
+ecl:brn pid:760753108 byr:1931
+hgt:179cm
+
+hcl:#cfa07d eyr:2025 pid:166559648
+iyr:2011 ecl:brn hgt:59in",
+        first: Some("2"),
+        second: None,
+    },
+    Example {
+        input: "eyr:1972 cid:100
+hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926
+
+iyr:2019
+hcl:#602927 eyr:1967 hgt:170cm
+ecl:grn pid:012533040 byr:1946
+
+hcl:dab227 iyr:2012
+ecl:brn hgt:182cm pid:021572410 eyr:2020 byr:1992 cid:277
+
+hgt:59cm ecl:zzz
+eyr:2038 hcl:74454a iyr:2023
+pid:3556412378 byr:2007",
+        first: None,
+        second: Some("0"),
+    },
+    Example {
+        input: "pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980
+hcl:#623a2f
+
+eyr:2029 ecl:blu cid:129 byr:1989
+iyr:2014 pid:896056539 hcl:#a97842 hgt:165cm
+
+hcl:#888785
+hgt:164cm byr:2001 iyr:2015 cid:88
+pid:545766238 ecl:hzl
+eyr:2022
+
+iyr:2010 hgt:158cm hcl:#b6652a ecl:blu byr:1944 eyr:2021 pid:093154719",
+        first: None,
+        second: Some("4"),
+    },
+];
+
+const FORMAT: InputFormat = InputFormat::Groups(&InputFormat::Lines(Pattern::List(
+    &Pattern::Seq(&[
+        Pattern::Word,
+        Pattern::Literal(":"),
+        Pattern::Chars("#0123456789abcdefghijklmnopqrstuvwxyz"),
+    ]),
+    " ",
+)));
+
+const GENERATOR: Generator = Generator {
+    size: "number of passports",
+    sizes: 1..=1_000_000,
+    default_size: 10_000,
+    generate,
+};
+
+register! {
+    day: DAY,
+    title: "Passport Processing",
+    tags: [Parsing],
+    solution: Day04,
+    examples: EXAMPLES,
+    format: FORMAT,
+    generator: GENERATOR,
+}
+
+impl Challenge for Day04 {
+    type Input<'a> = Vec<Passport<'a>>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ChallengeErr> {
+        FORMAT.validate(DAY, input)?;
+
+        Ok(parse_passwords(input))
+    }
+
+    fn first_part(&self, passports: &Self::Input<'_>) -> Result<Answer, ChallengeErr> {
+        Ok(count_passwords_with_weak_validation(passports).into())
+    }
+
+    fn second_part(&self, passports: &Self::Input<'_>) -> Result<Answer, ChallengeErr> {
+        Ok(count_passwords_with_strong_validation(passports).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_passwords() {
+        let test_passwords_file = String::from(
+            "ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
+byr:1937 iyr:2017 cid:147 hgt:183cm
+
+iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
+hcl:#cfa07d byr:1929
+
+hcl:#ae17e1 iyr:2013
+eyr:2024
+ecl:brn pid:760753108 byr:1931
+hgt:179cm
+
+hcl:#cfa07d eyr:2025 pid:166559648
+iyr:2011 ecl:brn hgt:59in",
+        );
+
+        assert_eq!(
+            count_passwords_with_weak_validation(&parse_passwords(&test_passwords_file)),
+            2
+        );
+    }
+
+    #[test]
+    fn test_strong_invalid_passwords() {
+        let test_strong_invalid_passwords = String::from(
+            "eyr:1972 cid:100
+hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926
+
+iyr:2019
+hcl:#602927 eyr:1967 hgt:170cm
+ecl:grn pid:012533040 byr:1946
+
+hcl:dab227 iyr:2012
+ecl:brn hgt:182cm pid:021572410 eyr:2020 byr:1992 cid:277
+
+hgt:59cm ecl:zzz
+eyr:2038 hcl:74454a iyr:2023
+pid:3556412378 byr:2007",
+        );
+
+        assert_eq!(
+            count_passwords_with_strong_validation(&parse_passwords(
+                &test_strong_invalid_passwords
+            )),
+            0
+        );
+    }
+
+    #[test]
+    fn test_strong_valid_passwords() {
+        let test_strong_valid_passwords = String::from(
+            "pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980
+hcl:#623a2f
+
+eyr:2029 ecl:blu cid:129 byr:1989
+iyr:2014 pid:896056539 hcl:#a97842 hgt:165cm
+
+hcl:#888785
+hgt:164cm byr:2001 iyr:2015 cid:88
+pid:545766238 ecl:hzl
+eyr:2022
+
+iyr:2010 hgt:158cm hcl:#b6652a ecl:blu byr:1944 eyr:2021 pid:093154719",
+        );
+
+        assert_eq!(
+            count_passwords_with_strong_validation(&parse_passwords(&test_strong_valid_passwords)),
+            4
+        );
+    }
+
+    #[test]
+    fn test_height_without_unit() {
+        assert!(!is_field_valid("hgt", "7"));
+        assert!(!is_field_valid("hgt", "cm"));
+        assert!(is_field_valid("hgt", "60in"));
+    }
+}
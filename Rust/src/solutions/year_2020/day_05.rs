@@ -0,0 +1,185 @@
+use super::format::{InputFormat, Pattern};
+use super::generator::{Generator, Rng};
+use super::registry::Example;
+use super::solution::{Answer, Challenge, Error as ChallengeErr};
+
+const DAY: u8 = 5;
+
+#[derive(PartialEq, Debug)]
+pub struct Seat {
+    seat_id: i32,
+}
+
+impl Seat {
+    /// Seat of the pass, unless the pass has unexpected characters or
+    /// is too long for a seat ID.
+    fn from_seat_pass(seat_pass: &str) -> Option<Seat> {
+        let seat_id = seat_pass.chars().try_fold(0_i32, |acc, ch| {
+            let bit = match ch {
+                'F' | 'L' => 0,
+                'B' | 'R' => 1,
+                _ => return None,
+            };
+            acc.checked_mul(2)?.checked_add(bit)
+        })?;
+
+        Some(Seat { seat_id })
+    }
+}
+
+fn find_max_seat_id(seats: &[Seat]) -> Option<i32> {
+    seats.iter().map(|s| s.seat_id).max()
+}
+
+fn find_missing_seat_id(seats: &[Seat]) -> Option<i32> {
+    let mut seat_ids: Vec<i32> = seats.iter().map(|s| s.seat_id).collect();
+    seat_ids.sort_unstable();
+
+    let mut last_value = -1;
+    for val in seat_ids.iter() {
+        if last_value != -1 && *val - last_value == 2 {
+            return Some(last_value + 1);
+        }
+        last_value = *val;
+    }
+
+    None
+}
+
+fn parse_seats(input: &str) -> Result<Vec<Seat>, ChallengeErr> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            Seat::from_seat_pass(line).ok_or_else(|| {
+                ChallengeErr::parse(
+                    DAY,
+                    idx + 1,
+                    1,
+                    format!("seat pass \"{}\" is too long", line),
+                )
+            })
+        })
+        .collect()
+}
+
+/// Boarding passes for consecutive seats with one seat missing in between.
+/// Rows get more bits when the plane isn't big enough.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let row_bits = (7_u32..)
+        .find(|bits| size + 3 <= 1_usize << (bits + 3))
+        .unwrap_or(7);
+    let seats = 1_u64 << (row_bits + 3);
+    let first = rng.between(1, seats - size as u64 - 2);
+    let missing = rng.between(first + 1, first + size as u64 - 1);
+
+    let mut passes: Vec<String> = (first..=first + size as u64)
+        .filter(|&seat_id| seat_id != missing)
+        .map(|seat_id| {
+            let row = (0..row_bits).rev().map(|bit| {
+                if seat_id >> (bit + 3) & 1 == 1 {
+                    'B'
+                } else {
+                    'F'
+                }
+            });
+            let column = (0..3)
+                .rev()
+                .map(|bit| if seat_id >> bit & 1 == 1 { 'R' } else { 'L' });
+
+            row.chain(column).collect()
+        })
+        .collect();
+    rng.shuffle(&mut passes);
+
+    passes.join("\n")
+}
+
+pub struct Day05 {}
+
+const EXAMPLES: &[Example] = &[Example {
+    input: "FBFBBFFRLR
+BFFFBBFRRR
+FFFBBBFRRR
+BBFFBBFRLL",
+    first: Some("820"),
+    second: None,
+}];
+
+const FORMAT: InputFormat =
+    InputFormat::Lines(Pattern::Seq(&[Pattern::Chars("FB"), Pattern::Chars("LR")]));
+
+const GENERATOR: Generator = Generator {
+    size: "number of boarding passes",
+    sizes: 2..=1_000_000,
+    default_size: 8_000,
+    generate,
+};
+
+register! {
+    day: DAY,
+    title: "Binary Boarding",
+    tags: [Parsing],
+    solution: Day05,
+    examples: EXAMPLES,
+    format: FORMAT,
+    generator: GENERATOR,
+}
+
+impl Challenge for Day05 {
+    type Input<'a> = Vec<Seat>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ChallengeErr> {
+        FORMAT.validate(DAY, input)?;
+
+        parse_seats(input)
+    }
+
+    fn first_part(&self, available_seats: &Self::Input<'_>) -> Result<Answer, ChallengeErr> {
+        find_max_seat_id(available_seats)
+            .map(|s| Ok(s.into()))
+            .unwrap_or_else(|| Err(ChallengeErr::no_solution(DAY, "there are no seat passes")))
+    }
+
+    fn second_part(&self, available_seats: &Self::Input<'_>) -> Result<Answer, ChallengeErr> {
+        find_missing_seat_id(available_seats)
+            .map(|s| Ok(s.into()))
+            .unwrap_or_else(|| Err(ChallengeErr::no_solution(DAY, "there is no missing seat")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    impl Seat {
+        fn from_row_and_col(row: i32, col: i32) -> Seat {
+            Seat {
+                seat_id: row * 8 + col,
+            }
+        }
+    }
+
+    #[test]
+    fn example_tests() {
+        let test_seat_pass = [
+            ("FBFBBFFRLR", Seat::from_row_and_col(44, 5), 357),
+            ("BFFFBBFRRR", Seat::from_row_and_col(70, 7), 567),
+            ("FFFBBBFRRR", Seat::from_row_and_col(14, 7), 119),
+            ("BBFFBBFRLL", Seat::from_row_and_col(102, 4), 820),
+        ];
+
+        for (seat_pass, expected_seat, seat_id) in &test_seat_pass {
+            let found_seat = Seat::from_seat_pass(seat_pass).unwrap();
+
+            assert_eq!(&found_seat, expected_seat);
+            assert_eq!(found_seat.seat_id, *seat_id);
+        }
+    }
+
+    #[test]
+    fn test_seat_pass_too_long_for_seat_id() {
+        assert!(Seat::from_seat_pass(&"B".repeat(31)).is_some());
+        assert!(Seat::from_seat_pass(&"B".repeat(32)).is_none());
+    }
+}
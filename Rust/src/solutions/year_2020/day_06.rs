@@ -0,0 +1,185 @@
+use super::cancel::Steps;
+use super::format::{InputFormat, Pattern};
+use super::generator::{Generator, Rng};
+use super::registry::Example;
+use super::solution::{Answer, Challenge, Error as ChallengeErr};
+use std::collections::HashSet;
+
+const DAY: u8 = 6;
+
+type Group<'a> = Vec<&'a str>;
+
+fn parse_answers(answers_text: &str) -> Vec<Group<'_>> {
+    answers_text
+        .split("\n\n")
+        .map(|g| g.split_whitespace().collect())
+        .collect()
+}
+
+fn sum_of_unique_answers(group: &Group) -> i32 {
+    group
+        .iter()
+        .fold(&mut HashSet::with_capacity(26), |acc, el| {
+            acc.extend(el.chars());
+            acc
+        })
+        .len() as i32
+}
+
+fn sum_of_common_answers(group: &Group) -> i32 {
+    let unique_answers: HashSet<char> = "abcdefghijklmnopqrstuvwxyz".chars().collect();
+
+    group
+        .iter()
+        .fold(unique_answers, |acc, el| {
+            acc.intersection(&el.chars().collect::<HashSet<char>>())
+                .copied()
+                .collect()
+        })
+        .len() as i32
+}
+
+fn total_sum_of_unique_answers(groups: &[Group]) -> Result<i64, ChallengeErr> {
+    let mut steps = Steps::new(DAY);
+
+    groups.iter().try_fold(0, |sum, g| {
+        steps.step()?;
+        Ok(sum + sum_of_unique_answers(g) as i64)
+    })
+}
+
+fn total_sum_of_common_answers(groups: &[Group]) -> Result<i64, ChallengeErr> {
+    let mut steps = Steps::new(DAY);
+
+    groups.iter().try_fold(0, |sum, g| {
+        steps.step()?;
+        Ok(sum + sum_of_common_answers(g) as i64)
+    })
+}
+
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut groups = vec![];
+
+    for _ in 0..size {
+        let mut people = vec![];
+
+        for _ in 0..rng.between(1, 5) {
+            let mut questions: Vec<char> = ('a'..='z').collect();
+            rng.shuffle(&mut questions);
+            let answered = rng.between(1, 26) as usize;
+            people.push(questions[..answered].iter().collect::<String>());
+        }
+
+        groups.push(people.join("\n"));
+    }
+
+    groups.join("\n\n")
+}
+
+pub struct Day06 {}
+
+const EXAMPLES: &[Example] = &[Example {
+    input: "abc
+
+a
+b
+c
+
+ab
+ac
+
+a
+a
+a
+a
+
+b",
+    first: Some("11"),
+    second: Some("6"),
+}];
+
+const FORMAT: InputFormat = InputFormat::Groups(&InputFormat::Lines(Pattern::Word));
+
+const GENERATOR: Generator = Generator {
+    size: "number of groups",
+    sizes: 1..=1_000_000,
+    default_size: 5_000,
+    generate,
+};
+
+register! {
+    day: DAY,
+    title: "Custom Customs",
+    tags: [Parsing],
+    solution: Day06,
+    examples: EXAMPLES,
+    format: FORMAT,
+    generator: GENERATOR,
+}
+
+impl Challenge for Day06 {
+    type Input<'a> = Vec<Group<'a>>;
+
+    fn parse<'a>(&self, answers_text: &'a str) -> Result<Self::Input<'a>, ChallengeErr> {
+        FORMAT.validate(DAY, answers_text)?;
+
+        Ok(parse_answers(answers_text))
+    }
+
+    fn first_part(&self, parsed_answers: &Self::Input<'_>) -> Result<Answer, ChallengeErr> {
+        Ok(total_sum_of_unique_answers(parsed_answers)?.into())
+    }
+
+    fn second_part(&self, parsed_answers: &Self::Input<'_>) -> Result<Answer, ChallengeErr> {
+        Ok(total_sum_of_common_answers(parsed_answers)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_total_sum_of_unique_answers() {
+        let test_answers = "abc
+
+a
+b
+c
+
+ab
+ac
+
+a
+a
+a
+a
+
+b";
+
+        let parsed_answers = parse_answers(test_answers);
+        assert_eq!(total_sum_of_unique_answers(&parsed_answers).unwrap(), 11);
+    }
+
+    #[test]
+    fn example_total_sum_of_common_answers() {
+        let test_answers = "abc
+
+a
+b
+c
+
+ab
+ac
+
+a
+a
+a
+a
+
+b";
+
+        let parsed_answers = parse_answers(test_answers);
+        assert_eq!(total_sum_of_common_answers(&parsed_answers).unwrap(), 6);
+    }
+}
@@ -0,0 +1,389 @@
+use super::cancel::Steps;
+use super::format::{InputFormat, Pattern};
+use super::generator::{Generator, Rng};
+use super::registry::Example;
+use super::solution::{Answer, Challenge, Error as ChallengeErr};
+use std::collections::{HashMap, HashSet};
+
+const DAY: u8 = 7;
+
+const SHINY_GOLD: &str = "shiny gold";
+
+/// Container bag with the bags inside it and their counts.
+type BagRule = (String, Vec<(String, i32)>);
+
+#[derive(Debug)]
+pub struct BagsRules {
+    graph: HashMap<String, Vec<(String, i32)>>,
+}
+
+impl BagsRules {
+    fn reverse(&self, steps: &mut Steps) -> Result<BagsRules, ChallengeErr> {
+        let mut reversed_graph = BagsRules {
+            graph: HashMap::new(),
+        };
+
+        for (u, others) in self.graph.iter() {
+            for (v, c) in others {
+                steps.step()?;
+                reversed_graph
+                    .graph
+                    .entry(v.to_string())
+                    .or_default()
+                    .push((u.to_string(), *c));
+            }
+        }
+
+        Ok(reversed_graph)
+    }
+
+    fn count_nodes_reachable_from(
+        &self,
+        bag: &String,
+        steps: &mut Steps,
+    ) -> Result<i32, ChallengeErr> {
+        let mut stack = vec![bag];
+        let mut visited = HashSet::new();
+        visited.insert(bag);
+        let mut count = 0;
+
+        while let Some(curr) = stack.pop() {
+            steps.step()?;
+            count += 1;
+
+            if !self.graph.contains_key(curr) {
+                continue;
+            }
+
+            for (v, _) in &self.graph[curr] {
+                if !visited.contains(&v) {
+                    visited.insert(v);
+                    stack.push(v);
+                }
+            }
+        }
+
+        Ok(count)
+    }
+
+    fn count_bag_colors_containing(&self, bag: &str) -> Result<i32, ChallengeErr> {
+        let mut steps = Steps::new(DAY);
+
+        Ok(self
+            .reverse(&mut steps)?
+            .count_nodes_reachable_from(&String::from(bag), &mut steps)?
+            - 1)
+    }
+
+    /// Bags inside the `bag`, counting every color only once. Bags are
+    /// visited without recursion, so long chains of bags don't overflow
+    /// the stack.
+    fn count_bags_inside(&self, bag: &str) -> Result<i64, ChallengeErr> {
+        let mut counted: HashMap<&str, i64> = HashMap::new();
+        let mut in_progress: HashSet<&str> = HashSet::new();
+        let mut stack = vec![(bag, false)];
+        let mut steps = Steps::new(DAY);
+
+        while let Some((curr, expanded)) = stack.pop() {
+            steps.step()?;
+            let inside = self.graph.get(curr).ok_or_else(|| {
+                ChallengeErr::no_solution(DAY, format!("there is no rule for {} bags", curr))
+            })?;
+
+            if expanded {
+                let count = inside
+                    .iter()
+                    .try_fold(0_i64, |acc, (other, count)| {
+                        (counted[other.as_str()] + 1)
+                            .checked_mul(*count as i64)
+                            .and_then(|bags| acc.checked_add(bags))
+                    })
+                    .ok_or_else(|| {
+                        ChallengeErr::no_solution(DAY, format!("{} bags hold too many bags", curr))
+                    })?;
+                in_progress.remove(curr);
+                counted.insert(curr, count);
+                continue;
+            }
+            if counted.contains_key(curr) {
+                continue;
+            }
+
+            in_progress.insert(curr);
+            stack.push((curr, true));
+            for (other, _) in inside {
+                if in_progress.contains(other.as_str()) {
+                    return Err(ChallengeErr::no_solution(
+                        DAY,
+                        format!("{} bags contain themselves", other),
+                    ));
+                }
+                if !counted.contains_key(other.as_str()) {
+                    stack.push((other, false));
+                }
+            }
+        }
+
+        Ok(counted[bag])
+    }
+
+    fn from_rules_text(rules_text: &str) -> Result<BagsRules, ChallengeErr> {
+        let mut bags_rules = BagsRules {
+            graph: HashMap::new(),
+        };
+
+        for (idx, bag_rule) in rules_text.lines().enumerate() {
+            let (container_bag, bags_inside) = BagsRules::parse_bag_rule(bag_rule)
+                .map_err(|(column, message)| ChallengeErr::parse(DAY, idx + 1, column, message))?;
+            let graph_entry = bags_rules.graph.entry(container_bag).or_insert(vec![]);
+            for (bag_inside, count) in bags_inside {
+                graph_entry.push((bag_inside, count));
+            }
+        }
+
+        Ok(bags_rules)
+    }
+
+    /// Bags in the rule, or the column and the reason why it's wrong. The
+    /// rule must already follow the input format.
+    fn parse_bag_rule(bag_rule: &str) -> Result<BagRule, (usize, String)> {
+        let words: Vec<&str> = bag_rule.split(' ').collect();
+        let container_bag: String = format!("{} {}", words[0], words[1]);
+        let mut bags_inside: Vec<(String, i32)> = vec![];
+
+        if words[4] != "no" {
+            for i in (4..words.len()).step_by(4) {
+                let count: i32 = words[i].parse().map_err(|_| {
+                    let column = words[..i].iter().map(|w| w.len() + 1).sum::<usize>() + 1;
+                    (column, format!("number \"{}\" is too large", words[i]))
+                })?;
+                let other_bag: String = format!("{} {}", words[i + 1], words[i + 2]);
+                bags_inside.push((other_bag, count));
+            }
+        }
+
+        Ok((container_bag, bags_inside))
+    }
+}
+
+/// Rules where bags only contain bags that come a bit later in the list of
+/// colors, so bags never contain themselves. Bags inside the shiny gold bag
+/// are limited, so counting them never overflows.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    const MAX_BAGS_INSIDE: u64 = 100_000;
+
+    let mut colors: Vec<String> = vec![];
+    let mut used = HashSet::new();
+    used.insert(SHINY_GOLD.to_string());
+    while colors.len() < size - 1 {
+        let color = format!("{} {}", rng.word(3, 8), rng.word(3, 8));
+        if used.insert(color.clone()) {
+            colors.push(color);
+        }
+    }
+    let shiny_gold = rng.between(size as u64 / 4, size as u64 / 2) as usize;
+    colors.insert(shiny_gold, SHINY_GOLD.to_string());
+
+    let mut bags_inside = vec![0; size];
+    let mut rules = vec![];
+    for bag in (0..size).rev() {
+        let mut contents = vec![];
+
+        if bag + 1 < size {
+            for _ in 0..rng.between(0, 4) {
+                let last = (bag + 20).min(size - 1);
+                let inner = rng.between(bag as u64 + 1, last as u64) as usize;
+                let count = rng.between(1, 5);
+                let total = bags_inside[bag] + count * (bags_inside[inner] + 1);
+                let fits = bag < shiny_gold || total <= MAX_BAGS_INSIDE;
+                if fits && contents.iter().all(|&(other, _)| other != inner) {
+                    bags_inside[bag] = total.min(MAX_BAGS_INSIDE + 1);
+                    contents.push((inner, count));
+                }
+            }
+        }
+
+        let contents = if contents.is_empty() {
+            String::from("no other bags")
+        } else {
+            contents
+                .iter()
+                .map(|&(inner, count)| {
+                    let noun = if count == 1 { "bag" } else { "bags" };
+                    format!("{} {} {}", count, colors[inner], noun)
+                })
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        rules.push(format!("{} bags contain {}.", colors[bag], contents));
+    }
+    rng.shuffle(&mut rules);
+
+    rules.join("\n")
+}
+
+pub struct Day07 {}
+
+const EXAMPLES: &[Example] = &[
+    Example {
+        input: "light red bags contain 1 bright white bag, 2 muted yellow bags.
+dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+faded blue bags contain no other bags.
+dotted black bags contain no other bags.",
+        first: Some("4"),
+        second: Some("32"),
+    },
+    Example {
+        input: "shiny gold bags contain 2 dark red bags.
+dark red bags contain 2 dark orange bags.
+dark orange bags contain 2 dark yellow bags.
+dark yellow bags contain 2 dark green bags.
+dark green bags contain 2 dark blue bags.
+dark blue bags contain 2 dark violet bags.
+dark violet bags contain no other bags.",
+        first: None,
+        second: Some("126"),
+    },
+];
+
+const FORMAT: InputFormat = InputFormat::Lines(Pattern::Seq(&[
+    Pattern::Word,
+    Pattern::Literal(" "),
+    Pattern::Word,
+    Pattern::Literal(" bags contain "),
+    Pattern::Alt(&[
+        Pattern::Literal("no other bags"),
+        Pattern::List(
+            &Pattern::Seq(&[
+                Pattern::Number,
+                Pattern::Literal(" "),
+                Pattern::Word,
+                Pattern::Literal(" "),
+                Pattern::Word,
+                Pattern::Alt(&[Pattern::Literal(" bags"), Pattern::Literal(" bag")]),
+            ]),
+            ", ",
+        ),
+    ]),
+    Pattern::Literal("."),
+]));
+
+const GENERATOR: Generator = Generator {
+    size: "number of bag colors",
+    sizes: 1..=1_000_000,
+    default_size: 5_000,
+    generate,
+};
+
+register! {
+    day: DAY,
+    title: "Handy Haversacks",
+    tags: [Graph, Parsing],
+    solution: Day07,
+    examples: EXAMPLES,
+    format: FORMAT,
+    generator: GENERATOR,
+}
+
+impl Challenge for Day07 {
+    type Input<'a> = BagsRules;
+
+    fn parse<'a>(&self, bags_rules_text: &'a str) -> Result<Self::Input<'a>, ChallengeErr> {
+        FORMAT.validate(DAY, bags_rules_text)?;
+
+        BagsRules::from_rules_text(bags_rules_text)
+    }
+
+    fn first_part(&self, bags_rules: &Self::Input<'_>) -> Result<Answer, ChallengeErr> {
+        Ok(bags_rules.count_bag_colors_containing(SHINY_GOLD)?.into())
+    }
+
+    fn second_part(&self, bags_rules: &Self::Input<'_>) -> Result<Answer, ChallengeErr> {
+        Ok(bags_rules.count_bags_inside(SHINY_GOLD)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_count_bag_colors_containing() {
+        let test_bags_rules_1 = "light red bags contain 1 bright white bag, 2 muted yellow bags.
+dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+faded blue bags contain no other bags.
+dotted black bags contain no other bags.";
+
+        let bags_rules = BagsRules::from_rules_text(test_bags_rules_1).unwrap();
+
+        assert_eq!(
+            bags_rules.count_bag_colors_containing(SHINY_GOLD).unwrap(),
+            4
+        );
+    }
+
+    #[test]
+    fn example_count_bags_inside_1() {
+        let test_bags_rules_1 = "light red bags contain 1 bright white bag, 2 muted yellow bags.
+dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+faded blue bags contain no other bags.
+dotted black bags contain no other bags.";
+
+        let bags_rules = BagsRules::from_rules_text(test_bags_rules_1).unwrap();
+
+        assert_eq!(bags_rules.count_bags_inside(SHINY_GOLD).unwrap(), 32);
+    }
+
+    #[test]
+    fn example_count_bags_inside_2() {
+        let test_bags_rules_2 = "shiny gold bags contain 2 dark red bags.
+dark red bags contain 2 dark orange bags.
+dark orange bags contain 2 dark yellow bags.
+dark yellow bags contain 2 dark green bags.
+dark green bags contain 2 dark blue bags.
+dark blue bags contain 2 dark violet bags.
+dark violet bags contain no other bags.";
+
+        assert_eq!(
+            BagsRules::from_rules_text(test_bags_rules_2)
+                .unwrap()
+                .count_bags_inside(SHINY_GOLD)
+                .unwrap(),
+            126
+        );
+    }
+
+    #[test]
+    fn test_bags_containing_themselves() {
+        let bags_rules = BagsRules::from_rules_text(
+            "shiny gold bags contain 2 dark red bags.
+dark red bags contain 1 shiny gold bag.",
+        )
+        .unwrap();
+
+        assert!(bags_rules.count_bags_inside(SHINY_GOLD).is_err());
+    }
+
+    #[test]
+    fn test_bags_without_rule() {
+        let bags_rules =
+            BagsRules::from_rules_text("shiny gold bags contain 2 dark red bags.").unwrap();
+
+        assert!(bags_rules.count_bags_inside(SHINY_GOLD).is_err());
+    }
+}
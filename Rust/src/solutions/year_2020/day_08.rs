@@ -0,0 +1,346 @@
+use super::cancel::Steps;
+use super::format::{InputFormat, Pattern};
+use super::generator::{Generator, Rng};
+use super::registry::Example;
+use super::solution::{Answer, Challenge, Error as ChallengeErr};
+use std::collections::HashSet;
+use std::str::FromStr;
+
+const DAY: u8 = 8;
+
+#[derive(Clone)]
+pub enum Command {
+    Nop(i32),
+    Jmp(i32),
+    Acc(i32),
+}
+
+impl FromStr for Command {
+    /// 1-based column of the problem and its description.
+    type Err = (usize, String);
+
+    fn from_str(command: &str) -> Result<Self, Self::Err> {
+        let mut parts = command.splitn(2, ' ');
+        let opcode = parts.next().unwrap_or_default();
+        let argument = parts
+            .next()
+            .ok_or_else(|| (command.len() + 1, String::from("missing command argument")))?;
+        let val = argument.parse().map_err(|_| {
+            (
+                opcode.len() + 2,
+                format!("invalid argument \"{}\"", argument),
+            )
+        })?;
+
+        match opcode {
+            "jmp" => Ok(Command::Jmp(val)),
+            "acc" => Ok(Command::Acc(val)),
+            "nop" => Ok(Command::Nop(val)),
+            code => Err((1, format!("unsupported command opcode \"{}\"", code))),
+        }
+    }
+}
+
+impl Command {
+    fn try_to_fix(&self) -> Command {
+        match self {
+            Command::Nop(val) => Command::Jmp(*val),
+            Command::Jmp(val) => Command::Nop(*val),
+            Command::Acc(val) => Command::Acc(*val),
+        }
+    }
+}
+
+type Program = Vec<Command>;
+
+/// Registers are wider than arguments, so neither jumps nor accumulating
+/// every instruction once can overflow them.
+struct Registers {
+    acc: i64,
+    ip: i64,
+}
+
+impl Registers {
+    fn new() -> Registers {
+        Registers { acc: 0, ip: 0 }
+    }
+}
+
+struct Emulation<'a> {
+    program: &'a Program,
+    registers: Registers,
+}
+
+impl Emulation<'_> {
+    fn new(program: &Program) -> Emulation<'_> {
+        Emulation {
+            program,
+            registers: Registers::new(),
+        }
+    }
+
+    /// Whether the program loops, with the registers at the end of the run.
+    /// Every executed instruction is a step.
+    fn run_with_loop_detection(self, steps: &mut Steps) -> Result<(bool, Registers), ChallengeErr> {
+        let mut visited_ips: HashSet<i64> = HashSet::new();
+        let mut last_state = Registers::new();
+
+        for registers in self {
+            steps.step()?;
+
+            if visited_ips.contains(&registers.ip) {
+                return Ok((true, registers));
+            }
+
+            visited_ips.insert(registers.ip);
+            last_state = registers
+        }
+
+        Ok((false, last_state))
+    }
+}
+
+impl Iterator for Emulation<'_> {
+    type Item = Registers;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut ip = self.registers.ip;
+        let mut acc = self.registers.acc;
+        if ip as usize >= self.program.len() {
+            return None;
+        }
+
+        let next_command = &self.program[ip as usize];
+        match next_command {
+            Command::Nop(_) => {
+                ip += 1;
+            }
+            Command::Jmp(jump) => {
+                ip += *jump as i64;
+            }
+            Command::Acc(diff) => {
+                ip += 1;
+                acc += *diff as i64;
+            }
+        }
+
+        self.registers.acc = acc;
+        self.registers.ip = ip;
+
+        Some(Registers { acc, ip })
+    }
+}
+
+fn parse_program(program: &str) -> Result<Program, ChallengeErr> {
+    program
+        .lines()
+        .enumerate()
+        .map(|(idx, command)| {
+            command
+                .parse()
+                .map_err(|(column, message)| ChallengeErr::parse(DAY, idx + 1, column, message))
+        })
+        .collect()
+}
+
+fn find_acc_value_before_entering_loop(program: &Program) -> Result<Option<i64>, ChallengeErr> {
+    let (has_loop, registers) =
+        Emulation::new(program).run_with_loop_detection(&mut Steps::new(DAY))?;
+    if has_loop {
+        Ok(Some(registers.acc))
+    } else {
+        Ok(None)
+    }
+}
+
+fn find_acc_value_in_correct_program(program: &mut Program) -> Result<Option<i64>, ChallengeErr> {
+    let length = program.len();
+    let mut steps = Steps::new(DAY);
+
+    for i in 0..length {
+        program[i] = program[i].try_to_fix();
+
+        let (has_loop, registers) = Emulation::new(program).run_with_loop_detection(&mut steps)?;
+        if !has_loop && registers.ip as usize == length {
+            return Ok(Some(registers.acc));
+        }
+
+        program[i] = program[i].try_to_fix();
+    }
+
+    Ok(None)
+}
+
+/// Program made of chunks of instructions, each one ending with a jump to
+/// the next chunk and the last one jumping right after the end. Then one
+/// "nop" is turned into a jump back to an instruction that ran before it,
+/// which makes the program loop.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut chunks = vec![];
+    let mut start = 0;
+    while start < size {
+        let min_len = if start == 0 { 3 } else { 1 };
+        let len = (rng.between(min_len, 8) as usize).min(size - start);
+        chunks.push(start..start + len);
+        start += len;
+    }
+    rng.shuffle(&mut chunks[1..]);
+
+    let mut program = vec![("nop", 0_i64); size];
+    let mut executed = vec![];
+    for (idx, chunk) in chunks.iter().enumerate() {
+        let next = chunks.get(idx + 1).map_or(size, |next| next.start);
+
+        for position in chunk.clone() {
+            program[position] = if position + 1 == chunk.end {
+                ("jmp", next as i64 - position as i64)
+            } else if rng.chance(50) {
+                ("acc", rng.between(0, 100) as i64 - 50)
+            } else {
+                ("nop", rng.index(size) as i64 - position as i64)
+            };
+            executed.push(position);
+        }
+    }
+
+    let corrupted = loop {
+        let order = rng.between(1, size as u64 - 1) as usize;
+        if program[executed[order]].0 != "jmp" {
+            break order;
+        }
+    };
+    let position = executed[corrupted] as i64;
+    let target = executed[rng.index(corrupted)] as i64;
+    program[position as usize] = ("jmp", target - position);
+
+    program
+        .iter()
+        .map(|(opcode, argument)| format!("{} {:+}", opcode, argument))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub struct Day08 {}
+
+const EXAMPLES: &[Example] = &[Example {
+    input: "nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6",
+    first: Some("5"),
+    second: Some("8"),
+}];
+
+const FORMAT: InputFormat = InputFormat::Lines(Pattern::Seq(&[
+    Pattern::Alt(&[
+        Pattern::Literal("nop"),
+        Pattern::Literal("acc"),
+        Pattern::Literal("jmp"),
+    ]),
+    Pattern::Literal(" "),
+    Pattern::SignedNumber,
+]));
+
+const GENERATOR: Generator = Generator {
+    size: "number of instructions",
+    sizes: 3..=1_000_000,
+    default_size: 10_000,
+    generate,
+};
+
+register! {
+    day: DAY,
+    title: "Handheld Halting",
+    tags: [Simulation, Parsing],
+    solution: Day08,
+    examples: EXAMPLES,
+    format: FORMAT,
+    generator: GENERATOR,
+}
+
+impl Challenge for Day08 {
+    type Input<'a> = Program;
+
+    fn parse<'a>(&self, program_text: &'a str) -> Result<Self::Input<'a>, ChallengeErr> {
+        FORMAT.validate(DAY, program_text)?;
+
+        parse_program(program_text)
+    }
+
+    fn first_part(&self, program: &Self::Input<'_>) -> Result<Answer, ChallengeErr> {
+        find_acc_value_before_entering_loop(program)?
+            .map(|v| Ok(v.into()))
+            .unwrap_or_else(|| Err(ChallengeErr::no_solution(DAY, "program never loops")))
+    }
+
+    fn second_part(&self, program: &Self::Input<'_>) -> Result<Answer, ChallengeErr> {
+        find_acc_value_in_correct_program(&mut program.clone())?
+            .map(|v| Ok(v.into()))
+            .unwrap_or_else(|| {
+                Err(ChallengeErr::no_solution(
+                    DAY,
+                    "no single nop/jmp swap makes the program terminate",
+                ))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_find_acc_value_before_entering_loop() {
+        let test_program_text = "nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6";
+
+        let program = parse_program(test_program_text).unwrap();
+
+        assert_eq!(
+            find_acc_value_before_entering_loop(&program).unwrap(),
+            Some(5)
+        );
+    }
+
+    #[test]
+    fn example_find_acc_value_in_correct_program() {
+        let test_program_text = "nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6";
+
+        let mut program = parse_program(test_program_text).unwrap();
+
+        assert_eq!(
+            find_acc_value_in_correct_program(&mut program).unwrap(),
+            Some(8)
+        );
+    }
+
+    #[test]
+    fn test_parse_program_reports_unknown_opcode() {
+        let err = parse_program("nop +0\nxyz +1").err().unwrap();
+
+        assert_eq!(
+            err.to_string(),
+            "day 8: cannot parse input at line 2, column 1: unsupported command opcode \"xyz\""
+        );
+    }
+}
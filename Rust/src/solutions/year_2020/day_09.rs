@@ -0,0 +1,301 @@
+use super::format::{InputFormat, Pattern};
+use super::generator::{Generator, Rng};
+use super::registry::Example;
+use super::solution::{Answer, Challenge, Error as ChallengeErr};
+use std::collections::{HashMap, VecDeque};
+
+const DAY: u8 = 9;
+
+fn parse_cypher(cypher_text: &str) -> Result<Vec<u64>, ChallengeErr> {
+    cypher_text
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            line.parse().map_err(|_| {
+                ChallengeErr::parse(DAY, idx + 1, 1, format!("invalid number \"{}\"", line))
+            })
+        })
+        .collect()
+}
+
+/// Sums of two numbers are kept in `u128`, so they never overflow.
+fn find_first_incorrect_cypher_number(cypher: &[u64], preamble: usize) -> Option<u64> {
+    let mut unique_sums_counter: HashMap<u128, u64> = HashMap::new();
+    let mut l = 0;
+
+    for (r, new_num) in cypher.iter().take(preamble).enumerate() {
+        for existing_num in cypher.iter().take(r) {
+            let occurences = unique_sums_counter
+                .entry(*new_num as u128 + *existing_num as u128)
+                .or_insert(0);
+            *occurences += 1;
+        }
+    }
+
+    for (r, new_num) in cypher.iter().skip(preamble).enumerate() {
+        let new_num_entry = unique_sums_counter.entry(*new_num as u128).or_insert(0);
+        if *new_num_entry == 0 {
+            return Some(*new_num);
+        }
+
+        let num_to_remove = cypher[l];
+
+        for existing_num in &cypher[l + 1..r + preamble] {
+            let old_summa_entry = unique_sums_counter
+                .entry(num_to_remove as u128 + *existing_num as u128)
+                .or_insert(1);
+            *old_summa_entry -= 1;
+
+            let new_summa_entry = unique_sums_counter
+                .entry(*new_num as u128 + *existing_num as u128)
+                .or_insert(0);
+            *new_summa_entry += 1;
+        }
+
+        l += 1;
+    }
+
+    None
+}
+
+fn find_ecryption_weakness_value(cypher: &[u64], target_sum: u64) -> Option<u128> {
+    let target_sum = target_sum as u128;
+    let mut l = 0;
+    let mut temp_sum = 0_u128;
+    let mut min_deque: VecDeque<u64> = VecDeque::new();
+    let mut max_deque: VecDeque<u64> = VecDeque::new();
+
+    for next_num in cypher.iter() {
+        temp_sum += *next_num as u128;
+
+        while temp_sum > target_sum {
+            let number_to_remove = cypher[l];
+
+            temp_sum -= number_to_remove as u128;
+
+            if !min_deque.is_empty() && min_deque[0] == number_to_remove {
+                min_deque.pop_front();
+            }
+
+            if !max_deque.is_empty() && max_deque[0] == number_to_remove {
+                max_deque.pop_front();
+            }
+
+            l += 1;
+        }
+
+        while !min_deque.is_empty() && min_deque.back().unwrap() > next_num {
+            min_deque.pop_back();
+        }
+        min_deque.push_back(*next_num);
+
+        while !max_deque.is_empty() && max_deque.back().unwrap() < next_num {
+            max_deque.pop_back();
+        }
+        max_deque.push_back(*next_num);
+
+        if temp_sum == target_sum {
+            return Some(min_deque[0] as u128 + max_deque[0] as u128);
+        }
+    }
+
+    None
+}
+
+fn find_encryption_weakness_of(cypher: &[u64], preamble: usize) -> Option<u128> {
+    find_first_incorrect_cypher_number(cypher, preamble)
+        .and_then(|val| find_ecryption_weakness_value(cypher, val))
+}
+
+/// Every number is a sum of two of the smallest numbers among the previous
+/// 25, which keeps them from overflowing. The last number is a sum of
+/// a contiguous range from the beginning instead.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    const PREAMBLE: usize = 25;
+
+    let mut numbers: Vec<u64> = (1..=2 * PREAMBLE as u64).collect();
+    rng.shuffle(&mut numbers);
+    numbers.truncate(PREAMBLE);
+
+    while numbers.len() < size - 1 {
+        let mut smallest = numbers[numbers.len() - PREAMBLE..].to_vec();
+        smallest.sort_unstable();
+        smallest.dedup();
+        let last = smallest.len().min(5) - 1;
+        let first = rng.index(last);
+        let second = rng.between(first as u64 + 1, last as u64) as usize;
+        numbers.push(smallest[first] + smallest[second]);
+    }
+
+    let window = &numbers[numbers.len() - PREAMBLE..];
+    let is_sum_of_two = |number: u64| {
+        window.iter().any(|&first| {
+            window
+                .iter()
+                .any(|&second| first != second && first + second == number)
+        })
+    };
+    let invalid = loop {
+        let start = rng.index(PREAMBLE);
+        let len = rng.between(3, 10) as usize;
+        let sum = numbers[start..start + len].iter().sum::<u64>();
+        if !is_sum_of_two(sum) {
+            break sum;
+        }
+    };
+    numbers.push(invalid);
+
+    numbers
+        .iter()
+        .map(|number| number.to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub struct Day09 {}
+
+const EXAMPLES: &[Example] = &[Example {
+    input: "1
+5
+2
+11
+7
+24
+12
+21
+17
+4
+25
+20
+6
+8
+9
+14
+3
+10
+22
+18
+16
+13
+15
+23
+19
+13
+28
+30
+31
+29
+101",
+    first: Some("101"),
+    second: Some("29"),
+}];
+
+const FORMAT: InputFormat = InputFormat::Lines(Pattern::Number);
+
+const GENERATOR: Generator = Generator {
+    size: "number of numbers",
+    sizes: 30..=1_000,
+    default_size: 1_000,
+    generate,
+};
+
+register! {
+    day: DAY,
+    title: "Encoding Error",
+    tags: [],
+    solution: Day09,
+    examples: EXAMPLES,
+    format: FORMAT,
+    generator: GENERATOR,
+}
+
+impl Challenge for Day09 {
+    type Input<'a> = Vec<u64>;
+
+    fn parse<'a>(&self, cypher_text: &'a str) -> Result<Self::Input<'a>, ChallengeErr> {
+        FORMAT.validate(DAY, cypher_text)?;
+
+        parse_cypher(cypher_text)
+    }
+
+    fn first_part(&self, cypher: &Self::Input<'_>) -> Result<Answer, ChallengeErr> {
+        find_first_incorrect_cypher_number(cypher, 25)
+            .map(|v| Ok(v.into()))
+            .unwrap_or_else(|| Err(ChallengeErr::no_solution(DAY, "every number is valid")))
+    }
+
+    fn second_part(&self, cypher: &Self::Input<'_>) -> Result<Answer, ChallengeErr> {
+        find_encryption_weakness_of(cypher, 25)
+            .map(|v| Ok(v.into()))
+            .unwrap_or_else(|| {
+                Err(ChallengeErr::no_solution(
+                    DAY,
+                    "no contiguous range sums to the invalid number",
+                ))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_find_first_incorrect_cypher_number() {
+        let test_cypher_text = "35
+20
+15
+25
+47
+40
+62
+55
+65
+95
+102
+117
+150
+182
+127
+219
+299
+277
+309
+576";
+
+        let test_cypher = parse_cypher(test_cypher_text).unwrap();
+
+        assert_eq!(
+            find_first_incorrect_cypher_number(&test_cypher, 5),
+            Some(127_u64)
+        );
+    }
+
+    #[test]
+    fn example_find_encryption_weakness_of() {
+        let test_cypher_text = "35
+20
+15
+25
+47
+40
+62
+55
+65
+95
+102
+117
+150
+182
+127
+219
+299
+277
+309
+576";
+
+        let test_cypher = parse_cypher(test_cypher_text).unwrap();
+
+        assert_eq!(find_encryption_weakness_of(&test_cypher, 5), Some(62));
+    }
+}
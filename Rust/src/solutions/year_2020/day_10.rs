@@ -0,0 +1,282 @@
+use super::format::{InputFormat, Pattern};
+use super::generator::{Generator, Rng};
+use super::registry::Example;
+use super::solution::{Answer, Challenge, Error as ChallengeErr};
+
+const DAY: u8 = 10;
+
+/// Joltages fit into `u32`, so the device joltage never overflows.
+fn parse_adapters(adapters_text: &str) -> Result<Vec<u64>, ChallengeErr> {
+    adapters_text
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            line.parse::<u32>().map(u64::from).map_err(|_| {
+                ChallengeErr::parse(DAY, idx + 1, 1, format!("invalid joltage \"{}\"", line))
+            })
+        })
+        .collect()
+}
+
+fn prepare_devices(adapters: &[u64]) -> Vec<u64> {
+    let mut cloned = adapters.to_owned();
+    cloned.push(0);
+    cloned.push(*adapters.iter().max().unwrap() + 3);
+
+    cloned.sort_unstable();
+
+    cloned
+}
+
+/// Differences between the chained adapters, unless some adapters differ
+/// by more than 3 jolts and can't be chained.
+fn find_jolt_differences(adapters: &[u64]) -> Option<(u64, u64, u64, u64)> {
+    let devices = prepare_devices(adapters);
+    let mut diffs = [0, 0, 0, 0];
+
+    for i in 1..devices.len() {
+        *diffs.get_mut((devices[i] - devices[i - 1]) as usize)? += 1
+    }
+
+    Some((diffs[0], diffs[1], diffs[2], diffs[3]))
+}
+
+fn find_product_of_jolt_differences(adapters: &[u64]) -> Option<u64> {
+    let (_, ones, _, threes) = find_jolt_differences(adapters)?;
+    Some(ones * threes)
+}
+
+/// Number of arrangements, unless it doesn't fit into `u64`.
+fn count_number_of_ways_to_connect(adapters: &[u64]) -> Option<u64> {
+    let devices = prepare_devices(adapters);
+    let mut ways = vec![0_u64; devices.len()];
+    ways[0] = 1;
+
+    for i in 1..devices.len() {
+        let lower_limit = i.saturating_sub(4);
+        for j in (lower_limit..i).rev() {
+            if devices[i] - devices[j] < 4 {
+                ways[i] = ways[j].checked_add(ways[i])?;
+            }
+        }
+    }
+
+    Some(ways[ways.len() - 1])
+}
+
+/// Adapters with differences of 1, 2 and 3 jolts. Once there are too many
+/// arrangements, only differences of 3 jolts are used, which don't add new
+/// arrangements.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    const MAX_ARRANGEMENTS: u64 = 1 << 50;
+
+    let mut adapters = vec![];
+    let mut recent: Vec<(u64, u64)> = vec![(0, 1)];
+    for _ in 0..size {
+        let (joltage, arrangements) = recent[recent.len() - 1];
+        let difference = if arrangements > MAX_ARRANGEMENTS {
+            3
+        } else {
+            *rng.pick(&[1, 1, 1, 2, 3, 3])
+        };
+
+        let joltage = joltage + difference;
+        let arrangements = recent
+            .iter()
+            .filter(|(other, _)| joltage - other <= 3)
+            .map(|(_, arrangements)| arrangements)
+            .sum();
+        recent.push((joltage, arrangements));
+        if recent.len() > 3 {
+            recent.remove(0);
+        }
+        adapters.push(joltage);
+    }
+    rng.shuffle(&mut adapters);
+
+    adapters
+        .iter()
+        .map(|adapter| adapter.to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub struct Day10 {}
+
+const EXAMPLES: &[Example] = &[
+    Example {
+        input: "16
+10
+15
+5
+1
+11
+7
+19
+6
+12
+4",
+        first: Some("35"),
+        second: Some("8"),
+    },
+    Example {
+        input: "28
+33
+18
+42
+31
+14
+46
+20
+48
+47
+24
+23
+49
+45
+19
+38
+39
+11
+1
+32
+25
+35
+8
+17
+7
+9
+4
+2
+34
+10
+3",
+        first: Some("220"),
+        second: Some("19208"),
+    },
+];
+
+const FORMAT: InputFormat = InputFormat::Lines(Pattern::Number);
+
+const GENERATOR: Generator = Generator {
+    size: "number of adapters",
+    sizes: 1..=1_000_000,
+    default_size: 1_000,
+    generate,
+};
+
+register! {
+    day: DAY,
+    title: "Adapter Array",
+    tags: [Graph],
+    solution: Day10,
+    examples: EXAMPLES,
+    format: FORMAT,
+    generator: GENERATOR,
+}
+
+impl Challenge for Day10 {
+    type Input<'a> = Vec<u64>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ChallengeErr> {
+        FORMAT.validate(DAY, input)?;
+
+        parse_adapters(input)
+    }
+
+    fn first_part(&self, adapters: &Self::Input<'_>) -> Result<Answer, ChallengeErr> {
+        find_product_of_jolt_differences(adapters)
+            .map(|v| v.into())
+            .ok_or_else(|| ChallengeErr::no_solution(DAY, "adapters differ by more than 3 jolts"))
+    }
+
+    fn second_part(&self, adapters: &Self::Input<'_>) -> Result<Answer, ChallengeErr> {
+        count_number_of_ways_to_connect(adapters)
+            .map(|v| v.into())
+            .ok_or_else(|| ChallengeErr::no_solution(DAY, "there are too many arrangements"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_1() {
+        let test_adapters_1_text = "16
+10
+15
+5
+1
+11
+7
+19
+6
+12
+4";
+
+        let test_adapters_1 = parse_adapters(test_adapters_1_text).unwrap();
+        assert_eq!(find_jolt_differences(&test_adapters_1), Some((0, 7, 0, 5)));
+        assert_eq!(count_number_of_ways_to_connect(&test_adapters_1), Some(8));
+    }
+
+    #[test]
+    fn test_example_2() {
+        let test_adapters_2_text = "28
+33
+18
+42
+31
+14
+46
+20
+48
+47
+24
+23
+49
+45
+19
+38
+39
+11
+1
+32
+25
+35
+8
+17
+7
+9
+4
+2
+34
+10
+3";
+
+        let test_adapters_2 = parse_adapters(test_adapters_2_text).unwrap();
+        assert_eq!(
+            find_jolt_differences(&test_adapters_2),
+            Some((0, 22, 0, 10))
+        );
+        assert_eq!(
+            count_number_of_ways_to_connect(&test_adapters_2),
+            Some(19208)
+        );
+    }
+
+    #[test]
+    fn test_adapters_too_far_apart() {
+        let adapters = parse_adapters("1\n2\n6").unwrap();
+
+        assert_eq!(find_jolt_differences(&adapters), None);
+        assert_eq!(count_number_of_ways_to_connect(&adapters), Some(0));
+    }
+
+    #[test]
+    fn test_too_many_arrangements() {
+        let adapters: Vec<u64> = (1..=100).collect();
+
+        assert_eq!(count_number_of_ways_to_connect(&adapters), None);
+    }
+}
@@ -0,0 +1,623 @@
+use super::cancel::Steps;
+use super::format::InputFormat;
+use super::generator::{Generator, Rng};
+use super::registry::Example;
+use super::solution::{Answer, Challenge, Error as ChallengeErr};
+use core::fmt;
+use itertools::iproduct;
+
+const DAY: u8 = 11;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Cell {
+    Empty,
+    Free,
+    Occupied,
+}
+
+impl Cell {
+    /// Cell at the 1-based `line` and `column` of the layout.
+    fn from_char(ch: char, line: usize, column: usize) -> Result<Cell, ChallengeErr> {
+        match ch {
+            '#' => Ok(Cell::Occupied),
+            'L' => Ok(Cell::Free),
+            '.' => Ok(Cell::Empty),
+            val => Err(ChallengeErr::parse(
+                DAY,
+                line,
+                column,
+                format!("unrecognized cell value '{}'", val),
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            Cell::Occupied => write!(f, "#"),
+            Cell::Free => write!(f, "L"),
+            Cell::Empty => write!(f, "."),
+        }
+    }
+}
+
+/// Result of a round of seating.
+#[derive(Default)]
+struct Step {
+    has_changes: bool,
+    /// Whether the layout differs from the one before the round.
+    differs_from_previous: bool,
+    occupied_seats: i32,
+}
+
+#[derive(Clone)]
+pub struct WaitingArea {
+    width: usize,
+    height: usize,
+    area: Vec<Vec<Cell>>,
+    buffer: Vec<Vec<Cell>>,
+}
+
+impl fmt::Display for WaitingArea {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        for (r, row) in self.area.iter().enumerate() {
+            for cell in row {
+                if let err @ Err(_) = write!(f, "{}", cell) {
+                    return err;
+                }
+            }
+            if r + 1 < self.height {
+                if let err @ Err(_) = writeln!(f) {
+                    return err;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+static ADJACENT_DIRECTIONS: [(isize, isize); 8] = [
+    (0, 1),
+    (1, 0),
+    (0, -1),
+    (-1, 0),
+    (1, 1),
+    (1, -1),
+    (-1, 1),
+    (-1, -1),
+];
+
+impl WaitingArea {
+    /// Layout with rows of the same width.
+    fn from_str(s: &str) -> Result<WaitingArea, ChallengeErr> {
+        let area = s
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(column, ch)| Cell::from_char(ch, idx + 1, column + 1))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let height = area.len();
+        let width = area.first().map_or(0, Vec::len);
+
+        if width == 0 {
+            return Err(ChallengeErr::parse(DAY, 1, 1, "expected a row of cells"));
+        }
+        if let Some(idx) = area.iter().position(|row| row.len() != width) {
+            return Err(ChallengeErr::parse(
+                DAY,
+                idx + 1,
+                1,
+                format!(
+                    "expected {} cells in the row, found {}",
+                    width,
+                    area[idx].len()
+                ),
+            ));
+        }
+
+        Ok(WaitingArea {
+            width,
+            height,
+            buffer: area.clone(),
+            area,
+        })
+    }
+
+    fn is_in_area(&self, r: isize, c: isize) -> bool {
+        0 <= r && r < self.height as isize && 0 <= c && c < self.width as isize
+    }
+
+    fn adjacent_neighbors(&self, r: usize, c: usize) -> impl Iterator<Item = Cell> + '_ {
+        ADJACENT_DIRECTIONS
+            .iter()
+            .map(move |(dr, dc)| (r as isize + *dr, c as isize + *dc))
+            .filter(move |(nr, nc)| self.is_in_area(*nr, *nc))
+            .map(move |(nr, nc)| self.area[nr as usize][nc as usize])
+            .filter(|&cell| !matches!(cell, Cell::Empty))
+    }
+
+    fn visible_seats_from(&self, r: usize, c: usize) -> impl Iterator<Item = Cell> + '_ {
+        ADJACENT_DIRECTIONS
+            .iter()
+            .map(move |(dr, dc)| {
+                let (mut nr, mut nc) = (r as isize + dr, c as isize + dc);
+                while self.is_in_area(nr, nc) {
+                    if self.area[nr as usize][nc as usize] != Cell::Empty {
+                        return self.area[nr as usize][nc as usize];
+                    }
+
+                    nr += dr;
+                    nc += dc;
+                }
+
+                Cell::Empty
+            })
+            .filter(|&cell| !matches!(cell, Cell::Empty))
+    }
+
+    fn simulate_intolerant_step(&mut self, steps: &mut Steps) -> Result<Step, ChallengeErr> {
+        let mut step = Step::default();
+
+        for (r, row) in self.area.iter().enumerate() {
+            for (c, seat) in row.iter().enumerate() {
+                steps.step()?;
+
+                let new_seat = match self.area[r][c] {
+                    Cell::Empty => Cell::Empty,
+                    Cell::Free => {
+                        if self
+                            .adjacent_neighbors(r, c)
+                            .filter(|c| matches!(c, Cell::Occupied))
+                            .count()
+                            == 0
+                        {
+                            Cell::Occupied
+                        } else {
+                            Cell::Free
+                        }
+                    }
+                    Cell::Occupied => {
+                        if self
+                            .adjacent_neighbors(r, c)
+                            .filter(|c| matches!(c, Cell::Occupied))
+                            .count()
+                            >= 4
+                        {
+                            Cell::Free
+                        } else {
+                            Cell::Occupied
+                        }
+                    }
+                };
+                if new_seat != *seat {
+                    step.has_changes = true
+                }
+                if new_seat != self.buffer[r][c] {
+                    step.differs_from_previous = true
+                }
+                if new_seat == Cell::Occupied {
+                    step.occupied_seats += 1;
+                }
+                self.buffer[r][c] = new_seat;
+            }
+        }
+
+        std::mem::swap(&mut self.area, &mut self.buffer);
+
+        Ok(step)
+    }
+
+    fn simulate_tolerant_step(&mut self, steps: &mut Steps) -> Result<Step, ChallengeErr> {
+        let mut step = Step::default();
+
+        for (r, row) in self.area.iter().enumerate() {
+            for (c, seat) in row.iter().enumerate() {
+                steps.step()?;
+
+                let new_seat = match self.area[r][c] {
+                    Cell::Empty => Cell::Empty,
+                    Cell::Free => {
+                        if self
+                            .visible_seats_from(r, c)
+                            .filter(|c| matches!(c, Cell::Occupied))
+                            .count()
+                            == 0
+                        {
+                            Cell::Occupied
+                        } else {
+                            Cell::Free
+                        }
+                    }
+                    Cell::Occupied => {
+                        if self
+                            .visible_seats_from(r, c)
+                            .filter(|c| matches!(c, Cell::Occupied))
+                            .count()
+                            >= 5
+                        {
+                            Cell::Free
+                        } else {
+                            Cell::Occupied
+                        }
+                    }
+                };
+                if new_seat != *seat {
+                    step.has_changes = true
+                }
+                if new_seat != self.buffer[r][c] {
+                    step.differs_from_previous = true
+                }
+                if new_seat == Cell::Occupied {
+                    step.occupied_seats += 1;
+                }
+                self.buffer[r][c] = new_seat;
+            }
+        }
+
+        std::mem::swap(&mut self.area, &mut self.buffer);
+
+        Ok(step)
+    }
+
+    fn intolerant_equilibrium(&mut self) -> Result<Option<i32>, ChallengeErr> {
+        let mut steps = Steps::new(DAY);
+        Self::equilibrium(|| self.simulate_intolerant_step(&mut steps))
+    }
+
+    fn tolerant_equilibrium(&mut self) -> Result<Option<i32>, ChallengeErr> {
+        let mut steps = Steps::new(DAY);
+        Self::equilibrium(|| self.simulate_tolerant_step(&mut steps))
+    }
+
+    /// Occupied seats once nothing changes, or `None` if seats keep
+    /// changing back and forth. Seats either settle down or repeat every
+    /// two rounds, so the loop always ends.
+    fn equilibrium(
+        mut simulate_step: impl FnMut() -> Result<Step, ChallengeErr>,
+    ) -> Result<Option<i32>, ChallengeErr> {
+        loop {
+            let step = simulate_step()?;
+            if !step.has_changes {
+                return Ok(Some(step.occupied_seats));
+            }
+            if !step.differs_from_previous {
+                return Ok(None);
+            }
+        }
+    }
+}
+
+/// Seats seen from every cell of the layout, either the adjacent ones or the
+/// first ones in each direction. Cells are numbered row by row.
+fn seen_seats(seats: &[bool], size: usize, visible: bool) -> Vec<Vec<usize>> {
+    let size = size as isize;
+
+    iproduct!(0..size, 0..size)
+        .map(|(r, c)| {
+            ADJACENT_DIRECTIONS
+                .iter()
+                .filter_map(|(dr, dc)| {
+                    let (mut nr, mut nc) = (r + dr, c + dc);
+                    while 0 <= nr && nr < size && 0 <= nc && nc < size {
+                        let cell = (nr * size + nc) as usize;
+                        if seats[cell] {
+                            return Some(cell);
+                        }
+                        if !visible {
+                            break;
+                        }
+                        nr += dr;
+                        nc += dc;
+                    }
+                    None
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Seats that keep changing when people follow the rules, e.g. the ones that
+/// get occupied and free again every other round. Only seats next to the
+/// ones changed in the last round are checked in each round.
+fn restless_seats(seats: &[bool], size: usize, visible: bool, tolerance: usize) -> Vec<usize> {
+    let seen = seen_seats(seats, size, visible);
+    let mut occupied = vec![false; seats.len()];
+    let mut counts = vec![0; seats.len()];
+    let mut checked = vec![false; seats.len()];
+    let mut candidates: Vec<usize> = (0..seats.len()).collect();
+    let mut changed = vec![];
+
+    for _ in 0..10 * size + 100 {
+        let next_changed: Vec<usize> = candidates
+            .iter()
+            .copied()
+            .filter(|&cell| {
+                let limit = if occupied[cell] { tolerance } else { 1 };
+                seats[cell] && occupied[cell] != (counts[cell] < limit)
+            })
+            .collect();
+
+        if next_changed.is_empty() {
+            return vec![];
+        }
+        // The same seats changing twice in a row change back and forth forever.
+        if next_changed == changed {
+            break;
+        }
+
+        for &cell in &next_changed {
+            occupied[cell] = !occupied[cell];
+            for &other in &seen[cell] {
+                if occupied[cell] {
+                    counts[other] += 1;
+                } else {
+                    counts[other] -= 1;
+                }
+            }
+        }
+
+        candidates.clear();
+        for &cell in &next_changed {
+            for &other in seen[cell].iter().chain(std::iter::once(&cell)) {
+                if !checked[other] {
+                    checked[other] = true;
+                    candidates.push(other);
+                }
+            }
+        }
+        for &cell in &candidates {
+            checked[cell] = false;
+        }
+        candidates.sort_unstable();
+        changed = next_changed;
+    }
+
+    changed
+}
+
+/// Random seat layout. Seats that would never settle are replaced with floor,
+/// so people find their seats in both parts.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut seats: Vec<bool> = (0..size * size).map(|_| rng.chance(75)).collect();
+
+    // Adjacent seats settle much faster, so the visible ones are checked
+    // only when the adjacent ones are fine.
+    loop {
+        let mut restless = restless_seats(&seats, size, false, 4);
+        if restless.is_empty() {
+            restless = restless_seats(&seats, size, true, 5);
+        }
+        if restless.is_empty() {
+            break;
+        }
+
+        for cell in restless {
+            seats[cell] = false;
+        }
+    }
+
+    seats
+        .chunks(size)
+        .map(|row| {
+            row.iter()
+                .map(|&seat| if seat { 'L' } else { '.' })
+                .collect()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+pub struct Day11 {}
+
+const EXAMPLES: &[Example] = &[Example {
+    input: "L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL",
+    first: Some("37"),
+    second: Some("26"),
+}];
+
+const FORMAT: InputFormat = InputFormat::Grid("L.#");
+
+const GENERATOR: Generator = Generator {
+    size: "width and height of the seat layout",
+    sizes: 1..=2_000,
+    default_size: 300,
+    generate,
+};
+
+register! {
+    day: DAY,
+    title: "Seating System",
+    tags: [Simulation],
+    solution: Day11,
+    examples: EXAMPLES,
+    format: FORMAT,
+    generator: GENERATOR,
+}
+
+impl Challenge for Day11 {
+    type Input<'a> = WaitingArea;
+
+    fn parse<'a>(&self, seats_str: &'a str) -> Result<Self::Input<'a>, ChallengeErr> {
+        FORMAT.validate(DAY, seats_str)?;
+
+        WaitingArea::from_str(seats_str)
+    }
+
+    fn first_part(&self, waiting_area: &Self::Input<'_>) -> Result<Answer, ChallengeErr> {
+        waiting_area
+            .clone()
+            .intolerant_equilibrium()?
+            .map(|v| v.into())
+            .ok_or_else(|| ChallengeErr::no_solution(DAY, "seats never stop changing"))
+    }
+
+    fn second_part(&self, waiting_area: &Self::Input<'_>) -> Result<Answer, ChallengeErr> {
+        waiting_area
+            .clone()
+            .tolerant_equilibrium()?
+            .map(|v| v.into())
+            .ok_or_else(|| ChallengeErr::no_solution(DAY, "seats never stop changing"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intolerant_step_simulation() {
+        let test_area_str = "L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL";
+
+        let mut test_area = WaitingArea::from_str(test_area_str).unwrap();
+        let intolerant_simulation_steps = [
+            "#.##.##.##
+#######.##
+#.#.#..#..
+####.##.##
+#.##.##.##
+#.#####.##
+..#.#.....
+##########
+#.######.#
+#.#####.##",
+            "#.LL.L#.##
+#LLLLLL.L#
+L.L.L..L..
+#LLL.LL.L#
+#.LL.LL.LL
+#.LLLL#.##
+..L.L.....
+#LLLLLLLL#
+#.LLLLLL.L
+#.#LLLL.##",
+            "#.##.L#.##
+#L###LL.L#
+L.#.#..#..
+#L##.##.L#
+#.##.LL.LL
+#.###L#.##
+..#.#.....
+#L######L#
+#.LL###L.L
+#.#L###.##",
+            "#.#L.L#.##
+#LLL#LL.L#
+L.L.L..#..
+#LLL.##.L#
+#.LL.LL.LL
+#.LL#L#.##
+..L.L.....
+#L#LLLL#L#
+#.LLLLLL.L
+#.#L#L#.##",
+            "#.#L.L#.##
+#LLL#LL.L#
+L.#.L..#..
+#L##.##.L#
+#.#L.LL.LL
+#.#L#L#.##
+..L.L.....
+#L#L##L#L#
+#.LLLLLL.L
+#.#L#L#.##",
+        ];
+
+        let mut steps = Steps::new(DAY);
+        for simulation_map in intolerant_simulation_steps.iter() {
+            test_area.simulate_intolerant_step(&mut steps).unwrap();
+            assert_eq!(format!("{}", test_area), String::from(*simulation_map));
+        }
+    }
+
+    #[test]
+    fn test_intolerant_equilibrium() {
+        let test_area_str = "L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL";
+
+        let mut test_area = WaitingArea::from_str(test_area_str).unwrap();
+        assert_eq!(test_area.intolerant_equilibrium().unwrap(), Some(37));
+    }
+
+    #[test]
+    fn test_tolerant_equilibrium() {
+        let test_area_str = "L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL";
+
+        let mut test_area = WaitingArea::from_str(test_area_str).unwrap();
+        assert_eq!(test_area.tolerant_equilibrium().unwrap(), Some(26));
+    }
+
+    #[test]
+    fn test_seats_changing_back_and_forth() {
+        let test_area_str = "..LLLL..
+.LLLLLL.
+LLLLLLLL
+LLLLLLLL
+LLLLLLLL
+LLLLLLLL
+.LLLLLL.
+..LLLL..";
+
+        let test_area = WaitingArea::from_str(test_area_str).unwrap();
+
+        assert_eq!(test_area.clone().intolerant_equilibrium().unwrap(), None);
+        assert_eq!(test_area.clone().tolerant_equilibrium().unwrap(), Some(12));
+    }
+
+    #[test]
+    fn test_reject_malformed_layouts() {
+        let err = |layout: &str| WaitingArea::from_str(layout).err().unwrap().to_string();
+
+        assert_eq!(
+            err("L.\n.?"),
+            "day 11: cannot parse input at line 2, column 2: unrecognized cell value '?'"
+        );
+        assert_eq!(
+            err("L.L\n.L"),
+            "day 11: cannot parse input at line 2, column 1: expected 3 cells in the row, found 2"
+        );
+        assert_eq!(
+            err(""),
+            "day 11: cannot parse input at line 1, column 1: expected a row of cells"
+        );
+    }
+}
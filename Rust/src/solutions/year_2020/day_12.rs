@@ -0,0 +1,308 @@
+use super::format::{InputFormat, Pattern};
+use super::generator::{Generator, Rng};
+use super::registry::Example;
+use super::solution::{Answer, Challenge, Error as ChallengeErr};
+use num_complex::Complex;
+use std::str::FromStr;
+
+const DAY: u8 = 12;
+
+static DIRECTIONS: [Complex<i64>; 4] = [
+    Complex::new(1, 0),
+    Complex::new(0, 1),
+    Complex::new(-1, 0),
+    Complex::new(0, -1),
+];
+
+pub enum Direction {
+    East,
+    North,
+    West,
+    South,
+}
+
+impl Direction {
+    fn get_direction_vector(&self) -> Complex<i64> {
+        use Direction::*;
+
+        match self {
+            East => DIRECTIONS[0],
+            North => DIRECTIONS[1],
+            West => DIRECTIONS[2],
+            South => DIRECTIONS[3],
+        }
+    }
+}
+
+/// Turns are kept as the number of quarter turns to the left.
+pub enum Instruction {
+    Forward(i32),
+    Turn(i32),
+    Move(Direction, i32),
+}
+
+impl FromStr for Instruction {
+    /// 1-based column of the problem and its description.
+    type Err = (usize, String);
+
+    fn from_str(val: &str) -> Result<Self, Self::Err> {
+        use Direction::*;
+        use Instruction::*;
+
+        let action = val.chars().next().unwrap_or_default();
+        let argument = &val[action.len_utf8()..];
+        let value: i32 = argument
+            .parse()
+            .map_err(|_| (2, format!("invalid argument \"{}\"", argument)))?;
+        let quarter_turns = || match value % 90 {
+            0 => Ok(value / 90 % 4),
+            _ => Err((2, format!("{} degrees is not a multiple of 90", value))),
+        };
+
+        match action {
+            'F' => Ok(Forward(value)),
+            'L' => Ok(Turn(quarter_turns()?)),
+            'R' => Ok(Turn((4 - quarter_turns()?) % 4)),
+            'N' => Ok(Move(North, value)),
+            'E' => Ok(Move(East, value)),
+            'W' => Ok(Move(West, value)),
+            'S' => Ok(Move(South, value)),
+            instr => Err((1, format!("unsupported instruction '{}'", instr))),
+        }
+    }
+}
+
+fn manhattan_distance(vector: &Complex<i64>) -> Option<i64> {
+    vector
+        .re
+        .checked_abs()?
+        .checked_add(vector.im.checked_abs()?)
+}
+
+/// The `vector` moved `times` times along the `direction`, unless it
+/// overflows.
+fn move_along(vector: Complex<i64>, direction: Complex<i64>, times: i32) -> Option<Complex<i64>> {
+    Some(Complex::new(
+        direction
+            .re
+            .checked_mul(times as i64)?
+            .checked_add(vector.re)?,
+        direction
+            .im
+            .checked_mul(times as i64)?
+            .checked_add(vector.im)?,
+    ))
+}
+
+fn parse_instructions(instructions_text: &str) -> Result<Vec<Instruction>, ChallengeErr> {
+    instructions_text
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            line.parse()
+                .map_err(|(column, message)| ChallengeErr::parse(DAY, idx + 1, column, message))
+        })
+        .collect()
+}
+
+/// Position of the ship after the instructions, unless it drifts too far
+/// away to be represented.
+fn simulate_instructions(
+    instructions: &[Instruction],
+    waypoint: Complex<i64>,
+    move_waypoint: bool,
+) -> Option<Complex<i64>> {
+    use Instruction::*;
+
+    let mut position = Complex::new(0, 0);
+    let mut waypoint = waypoint;
+
+    for instruction in instructions.iter() {
+        match instruction {
+            Forward(val) => position = move_along(position, waypoint, *val)?,
+            Turn(quarter_turns) => {
+                for _ in 0..*quarter_turns {
+                    waypoint = Complex::new(waypoint.im.checked_neg()?, waypoint.re);
+                }
+            }
+            Move(dir, val) => {
+                let direction_vector = dir.get_direction_vector();
+
+                if move_waypoint {
+                    waypoint = move_along(waypoint, direction_vector, *val)?;
+                } else {
+                    position = move_along(position, direction_vector, *val)?;
+                }
+            }
+        }
+    }
+
+    Some(position)
+}
+
+fn simulate_instructions_with_rotation(instructions: &[Instruction]) -> Option<i64> {
+    manhattan_distance(&simulate_instructions(
+        instructions,
+        Complex::new(1, 0),
+        false,
+    )?)
+}
+
+fn simulate_instructions_with_waypoint(instructions: &[Instruction]) -> Option<i64> {
+    manhattan_distance(&simulate_instructions(
+        instructions,
+        Complex::new(10, 1),
+        true,
+    )?)
+}
+
+/// Navigation instructions that keep the waypoint close to the ship, so
+/// the distance fits into `i32`.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    const MAX_OFFSET: i64 = 100;
+
+    let mut waypoint = (10_i64, 1_i64);
+    let mut instructions = vec![];
+    for _ in 0..size {
+        let instruction = match rng.between(0, 9) {
+            0..=3 => {
+                let direction = match waypoint {
+                    (x, _) if x > MAX_OFFSET => 'W',
+                    (x, _) if x < -MAX_OFFSET => 'E',
+                    (_, y) if y > MAX_OFFSET => 'S',
+                    (_, y) if y < -MAX_OFFSET => 'N',
+                    _ => *rng.pick(&['N', 'E', 'S', 'W']),
+                };
+                let value = rng.between(1, 10) as i64;
+                match direction {
+                    'N' => waypoint.1 += value,
+                    'E' => waypoint.0 += value,
+                    'S' => waypoint.1 -= value,
+                    _ => waypoint.0 -= value,
+                }
+                format!("{}{}", direction, value)
+            }
+            4..=5 => {
+                let direction = *rng.pick(&['L', 'R']);
+                let degrees = 90 * rng.between(1, 3);
+                for _ in 0..degrees / 90 {
+                    waypoint = match direction {
+                        'L' => (-waypoint.1, waypoint.0),
+                        _ => (waypoint.1, -waypoint.0),
+                    };
+                }
+                format!("{}{}", direction, degrees)
+            }
+            _ => format!("F{}", rng.between(1, 50)),
+        };
+        instructions.push(instruction);
+    }
+
+    instructions.join("\n")
+}
+
+pub struct Day12 {}
+
+const EXAMPLES: &[Example] = &[Example {
+    input: "F10
+N3
+F7
+R90
+F11",
+    first: Some("25"),
+    second: Some("286"),
+}];
+
+const FORMAT: InputFormat =
+    InputFormat::Lines(Pattern::Seq(&[Pattern::Char("NSEWLRF"), Pattern::Number]));
+
+const GENERATOR: Generator = Generator {
+    size: "number of instructions",
+    sizes: 1..=100_000,
+    default_size: 10_000,
+    generate,
+};
+
+register! {
+    day: DAY,
+    title: "Rain Risk",
+    tags: [Simulation],
+    solution: Day12,
+    examples: EXAMPLES,
+    format: FORMAT,
+    generator: GENERATOR,
+}
+
+impl Challenge for Day12 {
+    type Input<'a> = Vec<Instruction>;
+
+    fn parse<'a>(&self, instructions_text: &'a str) -> Result<Self::Input<'a>, ChallengeErr> {
+        FORMAT.validate(DAY, instructions_text)?;
+
+        parse_instructions(instructions_text)
+    }
+
+    fn first_part(&self, instructions: &Self::Input<'_>) -> Result<Answer, ChallengeErr> {
+        simulate_instructions_with_rotation(instructions)
+            .map(|v| v.into())
+            .ok_or_else(|| ChallengeErr::no_solution(DAY, "ship goes too far away"))
+    }
+
+    fn second_part(&self, instructions: &Self::Input<'_>) -> Result<Answer, ChallengeErr> {
+        simulate_instructions_with_waypoint(instructions)
+            .map(|v| v.into())
+            .ok_or_else(|| ChallengeErr::no_solution(DAY, "ship goes too far away"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simulate_instructions_with_rotation() {
+        let test_instructions_1_text = "F10
+N3
+F7
+R90
+F11";
+
+        let test_instructions_1 = parse_instructions(test_instructions_1_text).unwrap();
+        assert_eq!(
+            simulate_instructions_with_rotation(&test_instructions_1),
+            Some(25)
+        );
+    }
+
+    #[test]
+    fn test_simulate_instructions_with_waypoint() {
+        let test_instructions_1_text = "F10
+N3
+F7
+R90
+F11";
+
+        let test_instructions_1 = parse_instructions(test_instructions_1_text).unwrap();
+        assert_eq!(
+            simulate_instructions_with_waypoint(&test_instructions_1),
+            Some(286)
+        );
+    }
+
+    #[test]
+    fn test_turn_not_multiple_of_90() {
+        assert!(parse_instructions("F10\nR45").is_err());
+    }
+
+    #[test]
+    fn test_ship_too_far_away() {
+        let instructions_text = format!("{}F2147483647", "N2147483647\n".repeat(4));
+        let instructions = parse_instructions(&instructions_text).unwrap();
+
+        assert_eq!(
+            simulate_instructions_with_rotation(&instructions),
+            Some(5 * 2147483647)
+        );
+        assert_eq!(simulate_instructions_with_waypoint(&instructions), None);
+    }
+}
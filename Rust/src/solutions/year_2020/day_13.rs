@@ -0,0 +1,330 @@
+use super::format::{InputFormat, Pattern};
+use super::generator::{Generator, Rng};
+use super::registry::Example;
+use super::solution::{Answer, Challenge, Error as ChallengeErr};
+
+const DAY: u8 = 13;
+
+pub struct BusInfo {
+    id: u64,
+    idx: u64,
+}
+
+/// Buses in the schedule, or the column and the reason why the schedule
+/// is wrong.
+fn parse_buses(buses_text: &str) -> Result<Vec<BusInfo>, (usize, String)> {
+    let mut buses = vec![];
+    let mut column = 1;
+
+    for (idx, el) in buses_text.split(',').enumerate() {
+        if el != "x" {
+            let id = el
+                .parse()
+                .ok()
+                .filter(|&id| id > 0)
+                .ok_or_else(|| (column, format!("invalid bus ID \"{}\"", el)))?;
+            buses.push(BusInfo {
+                id,
+                idx: idx as u64,
+            });
+        }
+        column += el.len() + 1;
+    }
+
+    Ok(buses)
+}
+
+fn parse_notes(notes_text: &str) -> Result<(u64, Vec<BusInfo>), ChallengeErr> {
+    let mut notes_lines = notes_text.lines();
+    let timestamp_text = notes_lines.next().unwrap_or_default();
+    let timestamp: u64 = timestamp_text.parse().map_err(|_| {
+        ChallengeErr::parse(
+            DAY,
+            1,
+            1,
+            format!("invalid timestamp \"{}\"", timestamp_text),
+        )
+    })?;
+    let buses = parse_buses(notes_lines.next().unwrap_or_default())
+        .map_err(|(column, message)| ChallengeErr::parse(DAY, 2, column, message))?;
+
+    Ok((timestamp, buses))
+}
+
+/// ID of the earliest bus multiplied by the time to wait for it, if there
+/// are any buses at all.
+fn find_earliest_bus_estimation(timestamp: &u64, buses: &[BusInfo]) -> Option<u128> {
+    buses
+        .iter()
+        .map(|bus| ((bus.id - timestamp % bus.id) % bus.id, bus.id))
+        .min_by_key(|&(time_to_wait, _)| time_to_wait)
+        .map(|(time_to_wait, bus_id)| time_to_wait as u128 * bus_id as u128)
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    match b {
+        0 => a,
+        b => gcd(b, a % b),
+    }
+}
+
+/// Inverse of `n` modulo `m` found with the extended Euclidean algorithm,
+/// if `n` and `m` are coprime.
+fn mod_inverse(n: u64, m: u64) -> Option<u64> {
+    let (mut old_r, mut r) = ((n % m) as i128, m as i128);
+    let (mut old_s, mut s) = (1_i128, 0_i128);
+
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_s, s) = (s, old_s - quotient * s);
+    }
+
+    match old_r {
+        1 => Some(old_s.rem_euclid(m as i128) as u64),
+        _ => None,
+    }
+}
+
+/// The smallest number with remainders `r_i` modulo `a_i`. Moduli don't
+/// have to be coprime, so congruences are merged one by one.
+fn chinese_reminder_theorem_solver(r_i: &[u64], a_i: &[u64]) -> Result<u64, ChallengeErr> {
+    let (mut value, mut modulo) = (0_u64, 1_u64);
+
+    for (&r, &a) in r_i.iter().zip(a_i.iter()) {
+        let divisor = gcd(modulo, a);
+        let difference = ((r % a) as u128 + a as u128 - (value % a) as u128) % a as u128;
+        if !difference.is_multiple_of(divisor as u128) {
+            return Err(ChallengeErr::no_solution(
+                DAY,
+                "buses never depart at the required times",
+            ));
+        }
+
+        let step = a / divisor;
+        let inverse = mod_inverse(modulo / divisor, step).ok_or_else(|| {
+            ChallengeErr::internal("quotients of numbers by their GCD must be coprime")
+        })?;
+        let multiplier = difference / divisor as u128 * inverse as u128 % step as u128;
+        let next_modulo = modulo
+            .checked_mul(step)
+            .ok_or_else(|| ChallengeErr::no_solution(DAY, "timestamp is too large"))?;
+
+        value = ((value as u128 + modulo as u128 * multiplier) % next_modulo as u128) as u64;
+        modulo = next_modulo;
+    }
+
+    Ok(value)
+}
+
+fn find_gold_coin_timestamp(buses: &[BusInfo]) -> Result<u64, ChallengeErr> {
+    let r_i: Vec<u64> = buses.iter().map(|b| b.id - b.idx % b.id).collect();
+    let a_i: Vec<u64> = buses.iter().map(|b| b.id).collect();
+
+    chinese_reminder_theorem_solver(&r_i, &a_i)
+}
+
+/// Buses with distinct prime IDs. Buses are added while the product of IDs
+/// stays small enough for the Chinese remainder theorem in `u64`.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    const LIMIT: u64 = 10_000_000_000_000_000_000;
+
+    let mut primes: Vec<u64> = (11..1000_u64)
+        .filter(|&n| (2..n).take_while(|d| d * d <= n).all(|d| n % d != 0))
+        .collect();
+    rng.shuffle(&mut primes);
+
+    let mut slots: Vec<usize> = (1..size).collect();
+    rng.shuffle(&mut slots);
+    slots.insert(0, 0);
+
+    let mut schedule = vec![String::from("x"); size];
+    let (mut product, mut sum) = (1_u64, 0_u64);
+    for (&slot, &id) in slots.iter().zip(primes.iter()) {
+        let fits = product
+            .checked_mul(id)
+            .and_then(|product| product.checked_mul(sum + id))
+            .is_some_and(|bound| bound <= LIMIT);
+        if !fits {
+            break;
+        }
+        product *= id;
+        sum += id;
+        schedule[slot] = id.to_string();
+    }
+
+    format!(
+        "{}\n{}",
+        rng.between(100_000, 10_000_000),
+        schedule.join(",")
+    )
+}
+
+pub struct Day13 {}
+
+const EXAMPLES: &[Example] = &[Example {
+    input: "939
+7,13,x,x,59,x,31,19",
+    first: Some("295"),
+    second: Some("1068781"),
+}];
+
+const FORMAT: InputFormat = InputFormat::Header(
+    Pattern::Number,
+    &InputFormat::Line(Pattern::List(
+        &Pattern::Alt(&[Pattern::Number, Pattern::Literal("x")]),
+        ",",
+    )),
+);
+
+const GENERATOR: Generator = Generator {
+    size: "number of places in the schedule",
+    sizes: 1..=1_000_000,
+    default_size: 100,
+    generate,
+};
+
+register! {
+    day: DAY,
+    title: "Shuttle Search",
+    tags: [NumberTheory],
+    solution: Day13,
+    examples: EXAMPLES,
+    format: FORMAT,
+    generator: GENERATOR,
+}
+
+impl Challenge for Day13 {
+    type Input<'a> = (u64, Vec<BusInfo>);
+
+    fn parse<'a>(&self, notes_text: &'a str) -> Result<Self::Input<'a>, ChallengeErr> {
+        FORMAT.validate(DAY, notes_text)?;
+
+        parse_notes(notes_text)
+    }
+
+    fn first_part(&self, notes: &Self::Input<'_>) -> Result<Answer, ChallengeErr> {
+        let (timestamp, buses) = notes;
+
+        find_earliest_bus_estimation(timestamp, buses)
+            .map(|v| v.into())
+            .ok_or_else(|| ChallengeErr::no_solution(DAY, "there are no buses"))
+    }
+
+    fn second_part(&self, notes: &Self::Input<'_>) -> Result<Answer, ChallengeErr> {
+        let (_, buses) = notes;
+
+        Ok(find_gold_coin_timestamp(buses)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_example_both_values() {
+        let test_notes_1_text = "939
+7,13,x,x,59,x,31,19";
+
+        let (test_timestamp, test_buses) = parse_notes(test_notes_1_text).unwrap();
+
+        assert_eq!(
+            find_earliest_bus_estimation(&test_timestamp, &test_buses),
+            Some(295)
+        );
+        assert_eq!(find_gold_coin_timestamp(&test_buses).unwrap(), 1068781);
+    }
+
+    #[test]
+    fn test_find_gold_coin_timestamp_extended() {
+        let test_gold_timestamps = vec![
+            ("17,x,13,19", 3417),
+            ("67,7,59,61", 754018),
+            ("67,x,7,59,61", 779210),
+            ("67,7,x,59,61", 1261476),
+            ("1789,37,47,1889", 1202161486),
+        ];
+
+        for (buses_text, gold_timestamp) in test_gold_timestamps {
+            assert_eq!(
+                find_gold_coin_timestamp(&parse_buses(buses_text).unwrap()).unwrap(),
+                gold_timestamp
+            );
+        }
+    }
+
+    #[test]
+    fn test_bus_ids_with_common_divisors() {
+        let buses = parse_buses("4,x,6").unwrap();
+        assert_eq!(find_gold_coin_timestamp(&buses).unwrap(), 4);
+
+        let buses = parse_buses("4,6").unwrap();
+        assert!(find_gold_coin_timestamp(&buses).is_err());
+    }
+
+    #[test]
+    fn test_reject_bus_without_id() {
+        assert_eq!(
+            parse_buses("7,x,0").err(),
+            Some((5, String::from("invalid bus ID \"0\"")))
+        );
+    }
+
+    /// Earliest timestamp found by checking every timestamp in turn.
+    fn brute_force_gold_coin_timestamp(buses: &[BusInfo]) -> u64 {
+        (0..)
+            .find(|t| buses.iter().all(|bus| (t + bus.idx) % bus.id == 0))
+            .unwrap()
+    }
+
+    fn brute_force_earliest_bus(timestamp: u64, buses: &[BusInfo]) -> u64 {
+        let (wait, id) = buses
+            .iter()
+            .map(|bus| ((bus.id - timestamp % bus.id) % bus.id, bus.id))
+            .min_by_key(|&(wait, _)| wait)
+            .unwrap();
+
+        wait * id
+    }
+
+    /// Schedule with a few small prime IDs at random places.
+    fn schedule() -> impl Strategy<Value = String> {
+        let ids = prop::sample::subsequence(vec![2, 3, 5, 7, 11, 13, 17, 19], 1..=4);
+        let places = Just((0..20).collect::<Vec<usize>>()).prop_shuffle();
+
+        (ids, places).prop_map(|(ids, places)| {
+            let mut schedule = vec![String::from("x"); 20];
+            for (id, &place) in ids.iter().zip(places.iter()) {
+                schedule[place] = id.to_string();
+            }
+            schedule.join(",")
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_crt_matches_brute_force(schedule in schedule()) {
+            let buses = parse_buses(&schedule).unwrap();
+
+            prop_assert_eq!(
+                find_gold_coin_timestamp(&buses).unwrap(),
+                brute_force_gold_coin_timestamp(&buses)
+            );
+        }
+
+        #[test]
+        fn test_earliest_bus_matches_brute_force(
+            timestamp in 1..100_000_u64,
+            schedule in schedule()
+        ) {
+            let buses = parse_buses(&schedule).unwrap();
+
+            prop_assert_eq!(
+                find_earliest_bus_estimation(&timestamp, &buses),
+                Some(brute_force_earliest_bus(timestamp, &buses) as u128)
+            );
+        }
+    }
+}
@@ -0,0 +1,450 @@
+use super::cancel::Steps;
+use super::format::{InputFormat, Pattern};
+use super::generator::{Generator, Rng};
+use super::registry::Example;
+use super::solution::{Answer, Challenge, Error as ChallengeErr};
+use std::collections::HashMap;
+
+const DAY: u8 = 14;
+
+pub enum Command<'a> {
+    Mask { mask: &'a str },
+    MemorySet { addr: u64, value: i64 },
+}
+
+fn parse_36_bits(number: &str) -> Option<u64> {
+    number.parse().ok().filter(|&number| number < 1 << 36)
+}
+
+impl Command<'_> {
+    /// Command on the line, or the column and the reason why it's wrong.
+    /// Masks, addresses and values have 36 bits.
+    fn from_str(s: &str) -> Result<Command<'_>, (usize, String)> {
+        let (target, argument) = s.split_once(" = ").unwrap_or((s, ""));
+        let column = target.len() + 4;
+
+        if target == "mask" {
+            return match argument.len() {
+                36 => Ok(Command::Mask { mask: argument }),
+                len => Err((column, format!("expected 36 bits in mask, found {}", len))),
+            };
+        }
+
+        let addr = target.trim_start_matches("mem[").trim_end_matches(']');
+        Ok(Command::MemorySet {
+            addr: parse_36_bits(addr)
+                .ok_or_else(|| (5, format!("address {} doesn't fit into 36 bits", addr)))?,
+            value: parse_36_bits(argument).ok_or_else(|| {
+                (
+                    column,
+                    format!("value {} doesn't fit into 36 bits", argument),
+                )
+            })? as i64,
+        })
+    }
+}
+
+/// Largest number of disjoint sets of addresses kept in memory by
+/// `evaluate_program_v2`. Writes with floating bits that overlap in many
+/// ways split the sets written before, and the number of sets can grow
+/// quickly.
+const MAX_ADDRESS_SETS: usize = 1 << 20;
+
+const BITS_36: u64 = (1 << 36) - 1;
+
+/// Addresses of a write with floating bits. Bits of `floating` take both
+/// values, the other bits are the bits of `ones`.
+#[derive(Clone, Copy)]
+struct Addresses {
+    ones: u64,
+    floating: u64,
+}
+
+impl Addresses {
+    fn new(mask: &str, addr: u64) -> Addresses {
+        let bits = |bit| {
+            mask.bytes()
+                .fold(0, |bits, b| bits << 1 | (b == bit) as u64)
+        };
+        let floating = bits(b'X');
+
+        Addresses {
+            ones: (addr | bits(b'1')) & !floating,
+            floating,
+        }
+    }
+
+    /// Sums are kept in `i128`, as every one of 2^36 addresses can hold
+    /// a 36-bit value.
+    fn count(&self) -> i128 {
+        1 << self.floating.count_ones()
+    }
+
+    fn overlaps(&self, other: &Addresses) -> bool {
+        let fixed = !self.floating & !other.floating & BITS_36;
+
+        (self.ones ^ other.ones) & fixed == 0
+    }
+
+    /// Disjoint sets with every address of `self` that isn't in `other`.
+    /// Every bit floating in `self` but not in `other` splits off the
+    /// addresses where the bit differs from the one in `other`.
+    fn without(&self, other: &Addresses) -> Vec<Addresses> {
+        let mut rest = *self;
+        let mut pieces = vec![];
+        let mut bits = self.floating & !other.floating;
+
+        while bits != 0 {
+            let bit = bits & bits.wrapping_neg();
+            bits &= bits - 1;
+
+            rest.floating &= !bit;
+            pieces.push(Addresses {
+                ones: rest.ones | (!other.ones & bit),
+                floating: rest.floating,
+            });
+            rest.ones |= other.ones & bit;
+        }
+
+        pieces
+    }
+}
+
+fn parse_program(program_text: &str) -> Result<Vec<Command<'_>>, ChallengeErr> {
+    program_text
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            Command::from_str(line)
+                .map_err(|(column, message)| ChallengeErr::parse(DAY, idx + 1, column, message))
+        })
+        .collect()
+}
+
+fn evaluate_program_v1(program: &[Command]) -> i64 {
+    use Command::*;
+
+    let mut memory: HashMap<u64, i64> = HashMap::new();
+    let mut mask = "";
+
+    for command in program {
+        match command {
+            Mask { mask: m } => mask = m,
+            MemorySet { addr, value } => {
+                let mut value = *value;
+
+                for (i, v) in mask.chars().rev().enumerate() {
+                    match v {
+                        '1' => value |= 1 << i,
+                        '0' => value &= !(1 << i),
+                        _ => (),
+                    };
+                }
+
+                memory.insert(*addr, value);
+            }
+        }
+    }
+
+    memory.values().sum()
+}
+
+/// Sum of memory after the program, without listing every address a
+/// floating address stands for. Memory is kept as disjoint sets of
+/// addresses with the value written to them, and every write takes its
+/// addresses out of the sets written before.
+fn evaluate_program_v2(program: &[Command], max_sets: usize) -> Result<i128, ChallengeErr> {
+    use Command::*;
+
+    let mut memory: Vec<(Addresses, i64)> = vec![];
+    let mut mask = "";
+    let mut steps = Steps::new(DAY);
+
+    for command in program {
+        match command {
+            Mask { mask: m } => mask = m,
+            MemorySet { addr, value } => {
+                let addresses = Addresses::new(mask, *addr);
+                let mut pieces = vec![];
+                let mut idx = 0;
+
+                while idx < memory.len() {
+                    steps.step()?;
+
+                    if memory[idx].0.overlaps(&addresses) {
+                        let (written, written_value) = memory.swap_remove(idx);
+                        pieces.extend(
+                            written
+                                .without(&addresses)
+                                .into_iter()
+                                .map(|piece| (piece, written_value)),
+                        );
+                    } else {
+                        idx += 1;
+                    }
+                }
+                memory.extend(pieces);
+                memory.push((addresses, *value));
+
+                if memory.len() > max_sets {
+                    return Err(ChallengeErr::no_solution(
+                        DAY,
+                        format!("floating addresses overlap in more than {} ways", max_sets),
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(memory
+        .iter()
+        .map(|(addresses, value)| addresses.count() * *value as i128)
+        .sum())
+}
+
+fn find_memory_values_sum_v1(program: &[Command]) -> i64 {
+    evaluate_program_v1(program)
+}
+
+fn find_memory_values_sum_v2(program: &[Command]) -> Result<i128, ChallengeErr> {
+    evaluate_program_v2(program, MAX_ADDRESS_SETS)
+}
+
+/// Masks followed by a few writes each. Masks have at most 9 floating bits,
+/// like the ones in puzzle inputs.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut lines = vec![];
+
+    while lines.len() < size {
+        let mut mask: Vec<char> = (0..36)
+            .map(|_| if rng.chance(50) { '1' } else { '0' })
+            .collect();
+        for _ in 0..rng.between(0, 9) {
+            let bit = rng.index(mask.len());
+            mask[bit] = 'X';
+        }
+        lines.push(format!("mask = {}", mask.iter().collect::<String>()));
+
+        for _ in 0..rng.between(1, 6).min((size - lines.len()) as u64) {
+            lines.push(format!(
+                "mem[{}] = {}",
+                rng.between(0, 65_535),
+                rng.between(0, (1 << 36) - 1)
+            ));
+        }
+    }
+
+    lines.join("\n")
+}
+
+pub struct Day14 {}
+
+const EXAMPLES: &[Example] = &[
+    Example {
+        input: "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X
+mem[8] = 11
+mem[7] = 101
+mem[8] = 0",
+        first: Some("165"),
+        second: None,
+    },
+    Example {
+        input: "mask = 000000000000000000000000000000X1001X
+mem[42] = 100
+mask = 00000000000000000000000000000000X0XX
+mem[26] = 1",
+        first: None,
+        second: Some("208"),
+    },
+];
+
+const FORMAT: InputFormat = InputFormat::Lines(Pattern::Alt(&[
+    Pattern::Seq(&[Pattern::Literal("mask = "), Pattern::Chars("01X")]),
+    Pattern::Seq(&[
+        Pattern::Literal("mem["),
+        Pattern::Number,
+        Pattern::Literal("] = "),
+        Pattern::Number,
+    ]),
+]));
+
+const GENERATOR: Generator = Generator {
+    size: "number of lines",
+    sizes: 2..=1_000_000,
+    default_size: 5_000,
+    generate,
+};
+
+register! {
+    day: DAY,
+    title: "Docking Data",
+    tags: [Simulation, Parsing],
+    solution: Day14,
+    examples: EXAMPLES,
+    format: FORMAT,
+    generator: GENERATOR,
+}
+
+impl Challenge for Day14 {
+    type Input<'a> = Vec<Command<'a>>;
+
+    fn parse<'a>(&self, program_text: &'a str) -> Result<Self::Input<'a>, ChallengeErr> {
+        FORMAT.validate(DAY, program_text)?;
+
+        parse_program(program_text)
+    }
+
+    fn first_part(&self, program: &Self::Input<'_>) -> Result<Answer, ChallengeErr> {
+        Ok(find_memory_values_sum_v1(program).into())
+    }
+
+    fn second_part(&self, program: &Self::Input<'_>) -> Result<Answer, ChallengeErr> {
+        Ok(find_memory_values_sum_v2(program)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_memory_sum_v1() {
+        let test_program_1_text = "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X
+mem[8] = 11
+mem[7] = 101
+mem[8] = 0";
+
+        let test_program_1 = parse_program(test_program_1_text).unwrap();
+        assert_eq!(find_memory_values_sum_v1(&test_program_1), 165);
+    }
+
+    #[test]
+    fn test_memory_sum_v2() {
+        let test_program_2_text = "mask = 000000000000000000000000000000X1001X
+mem[42] = 100
+mask = 00000000000000000000000000000000X0XX
+mem[26] = 1";
+
+        let test_program_2 = parse_program(test_program_2_text).unwrap();
+        assert_eq!(find_memory_values_sum_v2(&test_program_2).unwrap(), 208);
+    }
+
+    #[test]
+    fn test_reject_values_wider_than_36_bits() {
+        assert!(parse_program("mask = 0X1\nmem[8] = 11").is_err());
+        assert!(parse_program("mem[68719476736] = 11").is_err());
+        assert!(parse_program("mem[8] = 68719476736").is_err());
+        assert!(parse_program("mem[68719476735] = 68719476735").is_ok());
+    }
+
+    #[test]
+    fn test_many_floating_bits() {
+        let program_text = format!("mask = {}{}\nmem[8] = 11", "0".repeat(24), "X".repeat(12));
+        let program = parse_program(&program_text).unwrap();
+
+        assert_eq!(find_memory_values_sum_v2(&program).unwrap(), 11 << 12);
+    }
+
+    #[test]
+    fn test_all_bits_floating() {
+        let program_text = format!("mask = {}\nmem[8] = 11", "X".repeat(36));
+        let program = parse_program(&program_text).unwrap();
+
+        assert_eq!(find_memory_values_sum_v1(&program), 11);
+        assert_eq!(find_memory_values_sum_v2(&program).unwrap(), 11 << 36);
+    }
+
+    #[test]
+    fn test_too_many_overlapping_writes() {
+        let program_text = format!(
+            "mask = {}\nmem[8] = 11\nmask = {}\nmem[8] = 1",
+            "X".repeat(36),
+            "0".repeat(36)
+        );
+        let program = parse_program(&program_text).unwrap();
+
+        assert_eq!(
+            evaluate_program_v2(&program, 40).unwrap(),
+            ((1 << 36) - 1) * 11 + 1
+        );
+        assert_eq!(
+            evaluate_program_v2(&program, 3).unwrap_err().to_string(),
+            "day 14: no solution found: floating addresses overlap in more than 3 ways"
+        );
+    }
+
+    /// Sum of memory after writing to every address a floating address
+    /// stands for, one by one.
+    fn brute_force_sum_v2(program: &[Command]) -> i64 {
+        let mut memory: HashMap<u64, i64> = HashMap::new();
+        let mut mask = "";
+
+        for command in program {
+            match command {
+                Command::Mask { mask: m } => mask = m,
+                Command::MemorySet { addr, value } => {
+                    let ones = u64::from_str_radix(&mask.replace('X', "0"), 2).unwrap();
+                    let floating: Vec<usize> = (0..36)
+                        .filter(|&bit| mask.as_bytes()[35 - bit] == b'X')
+                        .collect();
+
+                    for combination in 0..1_u64 << floating.len() {
+                        let mut address = addr | ones;
+                        for (idx, &bit) in floating.iter().enumerate() {
+                            address &= !(1 << bit);
+                            address |= (combination >> idx & 1) << bit;
+                        }
+                        memory.insert(address, *value);
+                    }
+                }
+            }
+        }
+
+        memory.values().sum()
+    }
+
+    /// Mask with a few floating bits. Masks and addresses use only the
+    /// lowest bits, so writes often overlap.
+    fn mask() -> impl Strategy<Value = String> {
+        (0..1024_u64, prop::collection::btree_set(0..10_usize, 0..5)).prop_map(
+            |(ones, floating)| {
+                (0..36)
+                    .rev()
+                    .map(|bit| match (floating.contains(&bit), ones >> bit & 1) {
+                        (true, _) => 'X',
+                        (_, 1) => '1',
+                        _ => '0',
+                    })
+                    .collect()
+            },
+        )
+    }
+
+    fn program() -> impl Strategy<Value = String> {
+        let writes = prop::collection::vec((0..1024_u64, 0..1000_i64), 1..4);
+
+        prop::collection::vec((mask(), writes), 1..6).prop_map(|blocks| {
+            let mut lines = vec![];
+            for (mask, writes) in blocks {
+                lines.push(format!("mask = {}", mask));
+                for (addr, value) in writes {
+                    lines.push(format!("mem[{}] = {}", addr, value));
+                }
+            }
+            lines.join("\n")
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_address_sets_match_brute_force(program_text in program()) {
+            let program = parse_program(&program_text).unwrap();
+
+            prop_assert_eq!(
+                find_memory_values_sum_v2(&program).unwrap(),
+                brute_force_sum_v2(&program) as i128
+            );
+        }
+    }
+}
@@ -0,0 +1,234 @@
+use super::cancel::Steps;
+use super::format::{InputFormat, Pattern};
+use super::generator::{Generator, Rng};
+use super::registry::Example;
+use super::solution::{Answer, Challenge, Error as ChallengeErr};
+use std::collections::HashMap;
+use std::num::{IntErrorKind, ParseIntError};
+use std::str::FromStr;
+
+const DAY: u8 = 15;
+
+const LAST_MOVE: usize = 30_000_000;
+
+/// Moves when numbers were last spoken. Spoken numbers are never larger
+/// than the number of moves, so only starting numbers can be larger than
+/// `seen` and they are kept aside.
+struct NumbersGame {
+    numbers: Vec<usize>,
+    seen: Vec<usize>,
+    seen_large: HashMap<usize, usize>,
+    last_move: usize,
+    last_number: usize,
+}
+
+/// Starting numbers on the only line, which may end with "\n".
+fn parse_numbers(numbers_text: &str) -> Result<Vec<usize>, ChallengeErr> {
+    let numbers_text = numbers_text.strip_suffix('\n').unwrap_or(numbers_text);
+    let mut column = 1;
+
+    numbers_text
+        .split(',')
+        .map(|el| {
+            let number = el.parse().map_err(|err: ParseIntError| {
+                let message = match err.kind() {
+                    IntErrorKind::PosOverflow => format!("number {} is too large", el),
+                    _ => format!("invalid number \"{}\"", el),
+                };
+                ChallengeErr::parse(DAY, 1, column, message)
+            });
+            column += el.len() + 1;
+            number
+        })
+        .collect()
+}
+
+impl FromStr for NumbersGame {
+    type Err = ChallengeErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(NumbersGame::new(parse_numbers(s)?))
+    }
+}
+
+struct GameState {
+    number: usize,
+}
+
+impl Iterator for NumbersGame {
+    type Item = GameState;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.last_move == 0 {
+            self.last_move = 1;
+            self.last_number = self.numbers[0];
+        }
+
+        let result = GameState {
+            number: self.last_number,
+        };
+
+        let seen_at = self.mark_seen(self.last_number, self.last_move);
+        if self.last_move < self.numbers.len() {
+            self.last_number = self.numbers[self.last_move];
+        } else if seen_at == 0 {
+            self.last_number = 0;
+        } else {
+            self.last_number = self.last_move - seen_at;
+        }
+
+        self.last_move += 1;
+
+        Some(result)
+    }
+}
+
+impl NumbersGame {
+    fn new(numbers: Vec<usize>) -> NumbersGame {
+        NumbersGame {
+            numbers,
+            seen: vec![0; LAST_MOVE + 1],
+            seen_large: HashMap::new(),
+            last_move: 0,
+            last_number: 0,
+        }
+    }
+
+    /// Remember that the `number` was spoken at the `at_move`, returning
+    /// the move it was spoken at before or 0.
+    fn mark_seen(&mut self, number: usize, at_move: usize) -> usize {
+        match self.seen.get_mut(number) {
+            Some(seen_at) => std::mem::replace(seen_at, at_move),
+            None => self.seen_large.insert(number, at_move).unwrap_or(0),
+        }
+    }
+
+    fn find_number_at_move(&mut self, at_move: usize) -> Result<usize, ChallengeErr> {
+        let mut steps = Steps::new(DAY);
+        for _ in 1..at_move {
+            steps.step()?;
+            self.next();
+        }
+
+        Ok(self.next().unwrap().number)
+    }
+}
+
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut numbers: Vec<usize> = (0..10 * size).collect();
+    rng.shuffle(&mut numbers);
+
+    numbers[..size]
+        .iter()
+        .map(|number| number.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+pub struct Day15 {}
+
+const EXAMPLES: &[Example] = &[Example {
+    input: "0,3,6",
+    first: Some("436"),
+    second: Some("175594"),
+}];
+
+const FORMAT: InputFormat = InputFormat::Line(Pattern::List(&Pattern::Number, ","));
+
+const GENERATOR: Generator = Generator {
+    size: "number of starting numbers",
+    sizes: 1..=100_000,
+    default_size: 7,
+    generate,
+};
+
+register! {
+    day: DAY,
+    title: "Rambunctious Recitation",
+    tags: [Simulation],
+    solution: Day15,
+    examples: EXAMPLES,
+    format: FORMAT,
+    generator: GENERATOR,
+}
+
+impl Challenge for Day15 {
+    type Input<'a> = Vec<usize>;
+
+    fn parse<'a>(&self, numbers_text: &'a str) -> Result<Self::Input<'a>, ChallengeErr> {
+        FORMAT.validate(DAY, numbers_text)?;
+
+        parse_numbers(numbers_text)
+    }
+
+    fn first_part(&self, numbers: &Self::Input<'_>) -> Result<Answer, ChallengeErr> {
+        Ok(NumbersGame::new(numbers.clone())
+            .find_number_at_move(2020)?
+            .into())
+    }
+
+    fn second_part(&self, numbers: &Self::Input<'_>) -> Result<Answer, ChallengeErr> {
+        Ok(NumbersGame::new(numbers.clone())
+            .find_number_at_move(LAST_MOVE)?
+            .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_number_at_move_2020_extended() {
+        let numbers_game_tests = [
+            ("0,3,6", 436),
+            ("1,3,2", 1),
+            ("2,1,3", 10),
+            ("1,2,3", 27),
+            ("2,3,1", 78),
+            ("3,2,1", 438),
+            ("3,1,2", 1836),
+        ];
+
+        for (numbers_text, number_2020) in &numbers_game_tests {
+            let mut numbers_game: NumbersGame = numbers_text.parse().unwrap();
+            assert_eq!(
+                numbers_game.find_number_at_move(2020).unwrap(),
+                *number_2020
+            );
+        }
+    }
+
+    #[test]
+    fn test_find_number_at_move_30000000() {
+        assert_eq!(
+            "0,3,6"
+                .parse::<NumbersGame>()
+                .unwrap()
+                .find_number_at_move(30000000)
+                .unwrap(),
+            175594
+        );
+    }
+
+    #[test]
+    fn test_large_starting_numbers() {
+        let mut numbers_game: NumbersGame = "100000000000,100000000000".parse().unwrap();
+
+        assert_eq!(numbers_game.find_number_at_move(3).unwrap(), 1);
+        assert!("0,100000000000000000000".parse::<NumbersGame>().is_err());
+    }
+
+    #[test]
+    fn test_parse_numbers() {
+        assert_eq!(parse_numbers("0,3,6\n").unwrap(), vec![0, 3, 6]);
+        assert_eq!(
+            parse_numbers("0,100000000000000000000").unwrap_err().to_string(),
+            "day 15: cannot parse input at line 1, column 3: number 100000000000000000000 is too large"
+        );
+        assert_eq!(
+            parse_numbers("0,3,x").unwrap_err().to_string(),
+            "day 15: cannot parse input at line 1, column 5: invalid number \"x\""
+        );
+    }
+}
@@ -0,0 +1,497 @@
+use super::cancel::Steps;
+use super::format::{InputFormat, Pattern};
+use super::generator::{Generator, Rng};
+use super::registry::Example;
+use super::solution::{Answer, Challenge, Error};
+use itertools::Itertools;
+use std::collections::HashSet;
+use std::str::FromStr;
+
+const DAY: u8 = 16;
+
+type Ticket = Vec<i32>;
+
+struct Rule {
+    name: String,
+    intervals: Vec<(i32, i32)>,
+}
+
+/// Value at the 1-based `column`.
+fn parse_value(value: &str, column: usize) -> Result<i32, (usize, String)> {
+    value
+        .parse()
+        .map_err(|_| (column, format!("invalid value \"{}\"", value)))
+}
+
+impl FromStr for Rule {
+    /// 1-based column of the problem and its description.
+    type Err = (usize, String);
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, intevals_text) = s
+            .split_once(": ")
+            .ok_or_else(|| (1, String::from("missing field name")))?;
+        let mut column = name.len() + 3;
+
+        let intervals: Vec<(i32, i32)> = intevals_text
+            .split(" or ")
+            .map(|el| {
+                let (from, to) = el
+                    .split_once('-')
+                    .ok_or_else(|| (column, format!("invalid range \"{}\"", el)))?;
+                let interval = (
+                    parse_value(from, column)?,
+                    parse_value(to, column + from.len() + 1)?,
+                );
+                column += el.len() + 4;
+                Ok(interval)
+            })
+            .collect::<Result<_, Self::Err>>()?;
+
+        Ok(Rule {
+            name: name.to_string(),
+            intervals,
+        })
+    }
+}
+
+impl Rule {
+    fn apply_to(&self, value: i32) -> bool {
+        self.intervals
+            .iter()
+            .any(|(f, t)| *f <= value && value <= *t)
+    }
+}
+
+struct Graph {
+    g: Vec<Vec<usize>>,
+}
+
+impl Graph {
+    fn new(size: usize) -> Graph {
+        Graph {
+            g: vec![vec![]; size],
+        }
+    }
+
+    fn add_edge(&mut self, u: usize, v: usize) {
+        self.g[u].push(v);
+        self.g[v].push(u);
+    }
+
+    fn find_matching_using_kuhn_algorithm(&self) -> Vec<usize> {
+        let mut matching = vec![usize::MAX; self.g.len()];
+        let mut visited = vec![false; self.g.len()];
+        let mut has_augmented = true;
+
+        while has_augmented {
+            has_augmented = false;
+            visited.fill(false);
+
+            for u in 0..self.g.len() {
+                if matching[u] == usize::MAX && !visited[u] {
+                    has_augmented |= self.dfs(u, &mut visited, &mut matching);
+                }
+            }
+        }
+
+        matching
+    }
+
+    fn dfs(&self, u: usize, visited: &mut Vec<bool>, matching: &mut Vec<usize>) -> bool {
+        if visited[u] {
+            return false;
+        }
+
+        visited[u] = true;
+        for &v in &self.g[u] {
+            if matching[v] == usize::MAX || self.dfs(matching[v], visited, matching) {
+                matching[v] = u;
+                matching[u] = v;
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+pub struct Notes {
+    rules: Vec<Rule>,
+    my_ticket: Ticket,
+    nearby_tickets: Vec<Ticket>,
+}
+
+impl Notes {
+    fn find_error_rate(&self) -> i64 {
+        self.nearby_tickets
+            .iter()
+            .flat_map(|ticket| ticket.iter())
+            .filter(|&&num| self.rules.iter().all(|r| !r.apply_to(num)))
+            .map(|&el| el as i64)
+            .sum()
+    }
+
+    fn find_product_of_departure_fields(&self) -> Result<i64, Error> {
+        self.find_field_order()?
+            .ok_or_else(|| Error::no_solution(DAY, "fields don't match the columns"))?
+            .iter()
+            .zip(self.my_ticket.iter())
+            .filter(|(name, _)| name.starts_with("departure"))
+            .try_fold(1_i64, |product, (_, &v)| product.checked_mul(v as i64))
+            .ok_or_else(|| Error::no_solution(DAY, "product of departure fields is too large"))
+    }
+
+    /// Name of the field in every column, if every field fits its own
+    /// column. Every value checked against a rule is a step.
+    fn find_field_order(&self) -> Result<Option<Vec<&String>>, Error> {
+        let valid_tickets = self.find_valid_tickets();
+        let rules_count = self.rules.len();
+        let mut graph = Graph::new(2 * rules_count);
+        let mut steps = Steps::new(DAY);
+
+        for column in 0..self.my_ticket.len() {
+            for (rule_idx, rule) in self.rules.iter().enumerate() {
+                let mut acceptable_field = true;
+                for ticket in valid_tickets.iter() {
+                    steps.step()?;
+
+                    if !rule.apply_to(ticket[column]) {
+                        acceptable_field = false;
+                        break;
+                    }
+                }
+
+                if acceptable_field {
+                    graph.add_edge(column, rule_idx + rules_count);
+                }
+            }
+        }
+
+        let matching = graph.find_matching_using_kuhn_algorithm();
+
+        Ok((0..rules_count)
+            .map(|idx| match matching[idx] {
+                usize::MAX => None,
+                rule_idx => Some(&self.rules[rule_idx - rules_count].name),
+            })
+            .collect())
+    }
+
+    fn find_valid_tickets(&self) -> Vec<&Ticket> {
+        let mut valid_tickets: Vec<&Ticket> = self
+            .nearby_tickets
+            .iter()
+            .filter(|ticket| self.is_ticket_valid(ticket))
+            .collect();
+
+        valid_tickets.push(&self.my_ticket);
+
+        valid_tickets
+    }
+
+    fn is_ticket_valid(&self, ticket: &Ticket) -> bool {
+        ticket
+            .iter()
+            .all(|&num| self.rules.iter().any(|r| r.apply_to(num)))
+    }
+
+    /// Ticket on the `line`, which must have a value for every field.
+    fn parse_ticket(ticket_text: &str, line: usize, fields: usize) -> Result<Ticket, Error> {
+        let mut column = 1;
+        let ticket = ticket_text
+            .split(',')
+            .map(|el| {
+                let value = parse_value(el, column);
+                column += el.len() + 1;
+                value
+            })
+            .collect::<Result<Ticket, _>>()
+            .map_err(|(column, message)| Error::parse(DAY, line, column, message))?;
+
+        if ticket.len() != fields {
+            return Err(Error::parse(
+                DAY,
+                line,
+                1,
+                format!(
+                    "expected {} values, one for every field, found {}",
+                    fields,
+                    ticket.len()
+                ),
+            ));
+        }
+
+        Ok(ticket)
+    }
+}
+
+impl FromStr for Notes {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (rules, my_ticket, nearby_tickets) = s
+            .split("\n\n")
+            .collect_tuple()
+            .ok_or_else(|| Error::parse(DAY, 1, 1, "expected rules and two sections of tickets"))?;
+        let rules: Vec<Rule> = rules
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| {
+                line.parse()
+                    .map_err(|(column, message)| Error::parse(DAY, idx + 1, column, message))
+            })
+            .collect::<Result<_, _>>()?;
+
+        let my_ticket_line = rules.len() + 3;
+        let my_ticket = Notes::parse_ticket(
+            my_ticket.trim_start_matches("your ticket:\n"),
+            my_ticket_line,
+            rules.len(),
+        )?;
+        let nearby_tickets = nearby_tickets
+            .trim_start_matches("nearby tickets:\n")
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| Notes::parse_ticket(line, my_ticket_line + 3 + idx, rules.len()))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Notes {
+            rules,
+            my_ticket,
+            nearby_tickets,
+        })
+    }
+}
+
+/// Every field gets its own band of values. A rule accepts values from
+/// the bands of its field and of all fields before it, so the first field
+/// fits every column and the last one fits only its own column. Fields are
+/// found one by one starting from the last.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    const FIELDS: usize = 20;
+    const DEPARTURES: usize = 6;
+
+    let mut names = vec![];
+    let mut used = HashSet::new();
+    while names.len() < FIELDS {
+        let name = match names.len() {
+            idx if idx < DEPARTURES => format!("departure {}", rng.word(3, 8)),
+            _ => format!("{} {}", rng.word(3, 8), rng.word(0, 8))
+                .trim()
+                .to_string(),
+        };
+        if used.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+    rng.shuffle(&mut names);
+
+    let mut bands = vec![];
+    let mut start = 1;
+    for _ in 0..FIELDS {
+        let end = start + rng.between(20, 50);
+        bands.push((start, end));
+        start = end + 1;
+    }
+
+    let mut rules: Vec<String> = names
+        .iter()
+        .zip(bands.iter())
+        .map(|(name, &(_, end))| {
+            let split = rng.between(1, end - 1);
+            format!("{}: 1-{} or {}-{}", name, split, split + 1, end)
+        })
+        .collect();
+    rng.shuffle(&mut rules);
+
+    let mut columns: Vec<usize> = (0..FIELDS).collect();
+    rng.shuffle(&mut columns);
+    let ticket = |rng: &mut Rng| {
+        columns
+            .iter()
+            .map(|&field| rng.between(bands[field].0, bands[field].1))
+            .collect::<Vec<_>>()
+    };
+    let format_ticket = |ticket: &[u64]| {
+        ticket
+            .iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    };
+
+    let my_ticket = format_ticket(&ticket(rng));
+    let mut nearby_tickets = vec![];
+    for _ in 0..size {
+        let mut nearby_ticket = ticket(rng);
+        if rng.chance(20) {
+            let column = rng.index(FIELDS);
+            nearby_ticket[column] = start + rng.between(0, 100);
+        }
+        nearby_tickets.push(format_ticket(&nearby_ticket));
+    }
+
+    format!(
+        "{}\n\nyour ticket:\n{}\n\nnearby tickets:\n{}",
+        rules.join("\n"),
+        my_ticket,
+        nearby_tickets.join("\n")
+    )
+}
+
+pub struct Day16 {}
+
+const EXAMPLES: &[Example] = &[Example {
+    input: "class: 1-3 or 5-7
+row: 6-11 or 33-44
+seat: 13-40 or 45-50
+
+your ticket:
+7,1,14
+
+nearby tickets:
+7,3,47
+40,4,50
+55,2,20
+38,6,12",
+    first: Some("71"),
+    second: None,
+}];
+
+const TICKET: Pattern = Pattern::List(&Pattern::Number, ",");
+
+const FORMAT: InputFormat = InputFormat::Sections(&[
+    InputFormat::Lines(Pattern::Seq(&[
+        Pattern::List(&Pattern::Word, " "),
+        Pattern::Literal(": "),
+        Pattern::Number,
+        Pattern::Literal("-"),
+        Pattern::Number,
+        Pattern::Literal(" or "),
+        Pattern::Number,
+        Pattern::Literal("-"),
+        Pattern::Number,
+    ])),
+    InputFormat::Header(Pattern::Literal("your ticket:"), &InputFormat::Line(TICKET)),
+    InputFormat::Header(
+        Pattern::Literal("nearby tickets:"),
+        &InputFormat::Lines(TICKET),
+    ),
+]);
+
+const GENERATOR: Generator = Generator {
+    size: "number of nearby tickets",
+    sizes: 1..=1_000_000,
+    default_size: 2_500,
+    generate,
+};
+
+register! {
+    day: DAY,
+    title: "Ticket Translation",
+    tags: [Graph, Parsing],
+    solution: Day16,
+    examples: EXAMPLES,
+    format: FORMAT,
+    generator: GENERATOR,
+}
+
+impl Challenge for Day16 {
+    type Input<'a> = Notes;
+
+    fn parse<'a>(&self, notes_text: &'a str) -> Result<Self::Input<'a>, Error> {
+        FORMAT.validate(DAY, notes_text)?;
+
+        notes_text.parse()
+    }
+
+    fn first_part(&self, notes: &Self::Input<'_>) -> Result<Answer, Error> {
+        Ok(notes.find_error_rate().into())
+    }
+
+    fn second_part(&self, notes: &Self::Input<'_>) -> Result<Answer, Error> {
+        Ok(notes.find_product_of_departure_fields()?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_error_rate() {
+        let notes_text = "class: 1-3 or 5-7
+row: 6-11 or 33-44
+seat: 13-40 or 45-50
+
+your ticket:
+7,1,14
+
+nearby tickets:
+7,3,47
+40,4,50
+55,2,20
+38,6,12";
+        let notes: Notes = notes_text.parse().unwrap();
+
+        assert_eq!(71, notes.find_error_rate());
+    }
+
+    #[test]
+    fn test_example_row_order() {
+        let notes_text = "class: 0-1 or 4-19
+row: 0-5 or 8-19
+seat: 0-13 or 16-19
+
+your ticket:
+11,12,13
+
+nearby tickets:
+3,9,18
+15,1,5
+5,14,9";
+        let notes: Notes = notes_text.parse().unwrap();
+
+        assert_eq!(
+            vec!["row", "class", "seat"],
+            notes.find_field_order().unwrap().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_tickets_with_missing_values() {
+        let notes_text = "class: 0-1 or 4-19
+row: 0-5 or 8-19
+
+your ticket:
+11,12
+
+nearby tickets:
+3,9
+15";
+
+        assert_eq!(
+            notes_text.parse::<Notes>().err().map(|err| err.to_string()),
+            Some(String::from(
+                "day 16: cannot parse input at line 9, column 1: \
+                 expected 2 values, one for every field, found 1"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_fields_not_matching_columns() {
+        let notes_text = "class: 0-1 or 4-19
+row: 0-1 or 4-19
+
+your ticket:
+11,2
+
+nearby tickets:
+3,9";
+        let notes: Notes = notes_text.parse().unwrap();
+
+        assert_eq!(notes.find_field_order().unwrap(), None);
+    }
+}
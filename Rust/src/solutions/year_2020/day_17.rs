@@ -0,0 +1,295 @@
+use super::cancel::Steps;
+use super::format::InputFormat;
+use super::generator::{Generator, Rng};
+use super::registry::Example;
+use super::solution::{Answer, Challenge, Error};
+use itertools::{iproduct, Itertools};
+use std::collections::{HashMap, HashSet};
+use std::mem;
+
+const DAY: u8 = 17;
+
+#[derive(Clone)]
+pub struct ConwayCube {
+    dimensions: usize,
+    state: HashSet<Vec<i32>>,
+    buffer: HashSet<Vec<i32>>,
+    neighbours: HashMap<Vec<i32>, i8>,
+    neighbours_buffer: HashMap<Vec<i32>, i8>,
+}
+
+impl ConwayCube {
+    fn from_str(s: &str, dimensions: usize) -> ConwayCube {
+        let mut cube = ConwayCube {
+            state: HashSet::new(),
+            buffer: HashSet::new(),
+            neighbours: HashMap::new(),
+            neighbours_buffer: HashMap::new(),
+            dimensions,
+        };
+
+        for (y, line) in s.lines().enumerate() {
+            for (x, v) in line.chars().enumerate() {
+                if v == '#' {
+                    let mut point = vec![0; dimensions];
+                    point[0] = x as i32;
+                    point[1] = y as i32;
+
+                    add_point(
+                        &mut cube.state,
+                        &mut cube.neighbours,
+                        cube.dimensions,
+                        &point,
+                    );
+                }
+            }
+        }
+
+        cube
+    }
+
+    /// Active cubes after the step. Every cube next to an active one is a
+    /// step of `steps`.
+    fn simulate_step(&mut self, steps: &mut Steps) -> Result<usize, Error> {
+        self.buffer.clear();
+        self.neighbours_buffer.clear();
+        let mut total = 0;
+
+        for (point, &active_neighbours) in self.neighbours.iter() {
+            steps.step()?;
+
+            if (self.state.contains(point) && (2..=3).contains(&active_neighbours))
+                || active_neighbours == 3
+            {
+                total += 1;
+
+                add_point(
+                    &mut self.buffer,
+                    &mut self.neighbours_buffer,
+                    self.dimensions,
+                    point,
+                );
+            }
+        }
+
+        mem::swap(&mut self.buffer, &mut self.state);
+        mem::swap(&mut self.neighbours, &mut self.neighbours_buffer);
+
+        Ok(total)
+    }
+
+    fn simulate_six_times(&mut self) -> Result<usize, Error> {
+        let mut steps = Steps::new(DAY);
+        (0..6).try_fold(0, |_, _| self.simulate_step(&mut steps))
+    }
+}
+
+fn add_point(
+    state: &mut HashSet<Vec<i32>>,
+    neighbours: &mut HashMap<Vec<i32>, i8>,
+    dimensions: usize,
+    point: &[i32],
+) {
+    state.insert(point.to_vec());
+
+    for neighbour in neighbour_cubes(point, dimensions) {
+        *neighbours.entry(neighbour).or_insert(0) += 1;
+    }
+}
+
+fn neighbour_cubes(point: &[i32], dimensions: usize) -> Box<dyn Iterator<Item = Vec<i32>>> {
+    match dimensions {
+        3 => {
+            let (&px, &py, &pz) = point.iter().collect_tuple().unwrap();
+            Box::new(
+                iproduct!((-1..=1), (-1..=1), (-1..=1))
+                    .filter(|&p| p != (0, 0, 0))
+                    .map(move |(x, y, z)| vec![px + x, py + y, pz + z]),
+            )
+        }
+        4 => {
+            let (&px, &py, &pz, &pw) = point.iter().collect_tuple().unwrap();
+            Box::new(
+                iproduct!((-1..=1), (-1..=1), (-1..=1), (-1..=1))
+                    .filter(|&p| p != (0, 0, 0, 0))
+                    .map(move |(x, y, z, w)| vec![px + x, py + y, pz + z, pw + w]),
+            )
+        }
+        _ => panic!("Unsupported dimension: {}", dimensions),
+    }
+}
+
+fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| if rng.chance(40) { '#' } else { '.' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub struct Day17 {}
+
+const EXAMPLES: &[Example] = &[Example {
+    input: ".#.
+..#
+###",
+    first: Some("112"),
+    second: Some("848"),
+}];
+
+const FORMAT: InputFormat = InputFormat::Grid(".#");
+
+const GENERATOR: Generator = Generator {
+    size: "width and height of the initial slice",
+    sizes: 1..=100,
+    default_size: 16,
+    generate,
+};
+
+register! {
+    day: DAY,
+    title: "Conway Cubes",
+    tags: [Simulation],
+    solution: Day17,
+    examples: EXAMPLES,
+    format: FORMAT,
+    generator: GENERATOR,
+}
+
+impl Challenge for Day17 {
+    type Input<'a> = [ConwayCube; 2];
+
+    fn parse<'a>(&self, cube_text: &'a str) -> Result<Self::Input<'a>, Error> {
+        FORMAT.validate(DAY, cube_text)?;
+
+        Ok([
+            ConwayCube::from_str(cube_text, 3),
+            ConwayCube::from_str(cube_text, 4),
+        ])
+    }
+
+    fn first_part(&self, cubes: &Self::Input<'_>) -> Result<Answer, Error> {
+        Ok(cubes[0].clone().simulate_six_times()?.into())
+    }
+
+    fn second_part(&self, cubes: &Self::Input<'_>) -> Result<Answer, Error> {
+        Ok(cubes[1].clone().simulate_six_times()?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_3_dimensions() {
+        let test_initial_state = ".#.
+..#
+###";
+
+        let mut test_cube = ConwayCube::from_str(test_initial_state, 3);
+        let mut steps = Steps::new(DAY);
+        for &active_cubes in &[11, 21, 38] {
+            assert_eq!(
+                test_cube.simulate_step(&mut steps).unwrap(),
+                active_cubes as usize
+            );
+        }
+    }
+
+    #[test]
+    fn test_4_dimensions() {
+        let test_initial_state = ".#.
+..#
+###";
+
+        let mut test_cube = ConwayCube::from_str(test_initial_state, 4);
+        let mut steps = Steps::new(DAY);
+        for &active_cubes in &[29, 60] {
+            assert_eq!(
+                test_cube.simulate_step(&mut steps).unwrap(),
+                active_cubes as usize
+            );
+        }
+    }
+
+    /// Active cubes after each step, counting active neighbours of every
+    /// cube next to an active one from scratch.
+    fn brute_force_active_cubes(state_text: &str, dimensions: usize, steps: usize) -> Vec<usize> {
+        // Points always have four coordinates, the unused ones stay 0.
+        let offsets: Vec<[i32; 4]> = (0..dimensions)
+            .map(|_| -1..=1)
+            .multi_cartesian_product()
+            .filter(|offset| offset.iter().any(|&d| d != 0))
+            .map(|offset| {
+                let mut padded = [0; 4];
+                padded[..dimensions].copy_from_slice(&offset);
+                padded
+            })
+            .collect();
+        let shift = |point: &[i32; 4], offset: &[i32; 4]| -> [i32; 4] {
+            [0, 1, 2, 3].map(|idx| point[idx] + offset[idx])
+        };
+
+        let mut active: HashSet<[i32; 4]> = HashSet::new();
+        for (y, line) in state_text.lines().enumerate() {
+            for (x, ch) in line.chars().enumerate() {
+                if ch == '#' {
+                    active.insert([x as i32, y as i32, 0, 0]);
+                }
+            }
+        }
+
+        let mut counts = vec![];
+        for _ in 0..steps {
+            let candidates: HashSet<[i32; 4]> = active
+                .iter()
+                .flat_map(|point| offsets.iter().map(move |offset| shift(point, offset)))
+                .collect();
+
+            active = candidates
+                .into_iter()
+                .filter(|point| {
+                    let neighbours = offsets
+                        .iter()
+                        .filter(|offset| active.contains(&shift(point, offset)))
+                        .count();
+                    neighbours == 3 || (neighbours == 2 && active.contains(point))
+                })
+                .collect();
+            counts.push(active.len());
+        }
+
+        counts
+    }
+
+    fn initial_state() -> impl Strategy<Value = String> {
+        prop::collection::vec(prop::collection::vec(any::<bool>(), 1..=4), 1..=4).prop_map(|rows| {
+            rows.iter()
+                .map(|row| row.iter().map(|&on| if on { '#' } else { '.' }).collect())
+                .collect::<Vec<String>>()
+                .join("\n")
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_neighbour_counts_match_brute_force(
+            state_text in initial_state(),
+            dimensions in 3..=4_usize,
+            steps in 1..=3_usize
+        ) {
+            let mut cube = ConwayCube::from_str(&state_text, dimensions);
+            let mut cube_steps = Steps::new(DAY);
+            let counts: Vec<usize> = (0..steps)
+                .map(|_| cube.simulate_step(&mut cube_steps).unwrap())
+                .collect();
+
+            prop_assert_eq!(counts, brute_force_active_cubes(&state_text, dimensions, steps));
+        }
+    }
+}
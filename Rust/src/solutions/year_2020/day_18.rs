@@ -0,0 +1,411 @@
+use super::format::{InputFormat, Pattern};
+use super::generator::{Generator, Rng};
+use super::registry::Example;
+use super::solution::{Answer, Challenge, Error};
+
+const DAY: u8 = 18;
+
+#[derive(Clone, Copy, Debug)]
+pub enum Token {
+    OpenBracket,
+    CloseBracket,
+    Number(u64),
+    Plus,
+    Multiply,
+}
+
+impl Token {
+    fn is_operation(&self) -> bool {
+        use Token::*;
+
+        matches!(self, Plus | Multiply)
+    }
+}
+
+/// Split the expression into tokens, or give the 1-based column of the
+/// first unsupported character or misplaced token and the description of
+/// the problem. Numbers and operators must alternate and brackets must be
+/// balanced, so the tokens can always be evaluated.
+fn tokenize(expression: &str) -> Result<Vec<Token>, (usize, String)> {
+    use Token::*;
+
+    let mut tokens = vec![];
+    let mut chars = expression.chars().enumerate().peekable();
+    let (mut expect_operand, mut depth) = (true, 0);
+
+    while let Some((idx, ch)) = chars.next() {
+        let token = match ch {
+            '+' => Plus,
+            '*' => Multiply,
+            '(' => OpenBracket,
+            ')' => CloseBracket,
+            ch if ch.is_whitespace() => continue,
+            ch if ch.is_ascii_digit() => {
+                let mut number = ch.to_digit(10).unwrap_or_default() as u64;
+                while let Some(digit) = chars.peek().and_then(|(_, ch)| ch.to_digit(10)) {
+                    number = number
+                        .checked_mul(10)
+                        .and_then(|number| number.checked_add(digit as u64))
+                        .ok_or_else(|| (idx + 1, String::from("number is too large")))?;
+                    chars.next();
+                }
+                Number(number)
+            }
+            ch => return Err((idx + 1, format!("unsupported token '{}'", ch))),
+        };
+
+        match (expect_operand, token) {
+            (true, Number(_)) => expect_operand = false,
+            (true, OpenBracket) => depth += 1,
+            (false, Plus) | (false, Multiply) => expect_operand = true,
+            (false, CloseBracket) if depth > 0 => depth -= 1,
+            (true, _) => return Err((idx + 1, format!("expected number or '(', found '{}'", ch))),
+            (false, _) => {
+                let closing = if depth > 0 { " or ')'" } else { "" };
+                return Err((
+                    idx + 1,
+                    format!("expected '+' or '*'{}, found '{}'", closing, ch),
+                ));
+            }
+        }
+        tokens.push(token);
+    }
+
+    let end = expression.chars().count() + 1;
+    if expect_operand {
+        return Err((
+            end,
+            String::from("expected number or '(', found end of line"),
+        ));
+    }
+    if depth > 0 {
+        return Err((end, String::from("expected ')', found end of line")));
+    }
+
+    Ok(tokens)
+}
+
+fn tokenize_expressions(expressions_text: &str) -> Result<Vec<Vec<Token>>, Error> {
+    expressions_text
+        .lines()
+        .enumerate()
+        .map(|(idx, expression)| {
+            tokenize(expression)
+                .map_err(|(column, message)| Error::parse(DAY, idx + 1, column, message))
+        })
+        .collect()
+}
+
+const INCORRECT_EXPRESSION: &str = "Incorrect expression";
+const TOO_LARGE: &str = "Value is too large";
+
+fn eval_op(l: &Token, op: &Token, r: &Token) -> Result<Token, &'static str> {
+    use Token::*;
+
+    match (l, op, r) {
+        (Number(l), Plus, Number(r)) => l.checked_add(*r).map(Number).ok_or(TOO_LARGE),
+        (Number(l), Multiply, Number(r)) => l.checked_mul(*r).map(Number).ok_or(TOO_LARGE),
+        _ => Err(INCORRECT_EXPRESSION),
+    }
+}
+
+fn try_swallow(stack: &mut Vec<Token>, value: Token) -> Result<(), &'static str> {
+    if let Some(t) = stack.last() {
+        if t.is_operation() {
+            let op = stack.pop().ok_or(INCORRECT_EXPRESSION)?;
+            let first = stack.pop().ok_or(INCORRECT_EXPRESSION)?;
+            stack.push(eval_op(&first, &op, &value)?);
+
+            return Ok(());
+        }
+    }
+
+    stack.push(value);
+
+    Ok(())
+}
+
+fn evaluate_expression(expression: &[Token]) -> Result<u64, &str> {
+    use Token::*;
+
+    let mut stack: Vec<Token> = vec![];
+
+    for token in expression {
+        match token {
+            CloseBracket => {
+                let res = stack.pop().ok_or(INCORRECT_EXPRESSION)?;
+                stack.pop();
+                try_swallow(&mut stack, res)?;
+            }
+            &n @ Number(_) => try_swallow(&mut stack, n)?,
+            &token => stack.push(token),
+        }
+    }
+
+    if let Some(&Number(val)) = stack.first() {
+        return Ok(val);
+    }
+
+    Err(INCORRECT_EXPRESSION)
+}
+
+fn try_swallow_advanced<T>(stack: &mut Vec<Token>, predicate: T) -> Result<(), &'static str>
+where
+    T: Fn(&Token) -> bool,
+{
+    while stack.len() > 1 && predicate(&stack[stack.len() - 2]) {
+        let s = stack.pop().ok_or(INCORRECT_EXPRESSION)?;
+        let op = stack.pop().ok_or(INCORRECT_EXPRESSION)?;
+        let f = stack.pop().ok_or(INCORRECT_EXPRESSION)?;
+
+        stack.push(eval_op(&s, &op, &f)?);
+    }
+
+    Ok(())
+}
+
+fn simplify(stack: &mut Vec<Token>) -> Result<(), &'static str> {
+    try_swallow_advanced(stack, |t: &Token| t.is_operation())
+}
+
+fn evaluate_expression_advanced(expression: &[Token]) -> Result<u64, &str> {
+    use Token::*;
+
+    let mut stack: Vec<Token> = vec![];
+
+    for token in expression {
+        match token {
+            OpenBracket => stack.push(OpenBracket),
+            CloseBracket => {
+                simplify(&mut stack)?;
+
+                let res = stack.pop().ok_or(INCORRECT_EXPRESSION)?;
+                stack.pop();
+                stack.push(res);
+            }
+            &n @ Number(_) => stack.push(n),
+            Plus => {
+                try_swallow_advanced(&mut stack, |t| matches!(t, Plus))?;
+                stack.push(Plus);
+            }
+            Multiply => {
+                simplify(&mut stack)?;
+                stack.push(Multiply);
+            }
+        }
+    }
+
+    simplify(&mut stack)?;
+
+    if let Some(&Number(val)) = stack.first() {
+        return Ok(val);
+    }
+
+    Err(INCORRECT_EXPRESSION)
+}
+
+/// Sum of the values of the expressions, or the first problem found while
+/// evaluating them.
+fn sum_with<'a>(
+    expressions: &'a [Vec<Token>],
+    evaluate: impl Fn(&'a [Token]) -> Result<u64, &'a str>,
+) -> Result<u64, Error> {
+    expressions
+        .iter()
+        .enumerate()
+        .try_fold(0_u64, |sum, (idx, e)| {
+            evaluate(e)
+                .and_then(|value| sum.checked_add(value).ok_or(TOO_LARGE))
+                .map_err(|message| {
+                    Error::no_solution(DAY, format!("expression {}: {}", idx + 1, message))
+                })
+        })
+}
+
+fn sum_of_expressions(expressions: &[Vec<Token>]) -> Result<u64, Error> {
+    sum_with(expressions, evaluate_expression)
+}
+
+fn sum_of_expressions_advanced(expressions: &[Vec<Token>]) -> Result<u64, Error> {
+    sum_with(expressions, evaluate_expression_advanced)
+}
+
+fn generate_expression(rng: &mut Rng, depth: usize, multiplications: &mut usize) -> String {
+    let mut expression = String::new();
+
+    for idx in 0..rng.between(2, 4) {
+        if idx > 0 {
+            let operation = if *multiplications > 0 && rng.chance(40) {
+                *multiplications -= 1;
+                " * "
+            } else {
+                " + "
+            };
+            expression.push_str(operation);
+        }
+
+        if depth < 2 && rng.chance(25) {
+            let inner = generate_expression(rng, depth + 1, multiplications);
+            expression.push_str(&format!("({})", inner));
+        } else {
+            expression.push_str(&rng.between(1, 9).to_string());
+        }
+    }
+
+    expression
+}
+
+/// Expressions with at most 4 multiplications. Whatever the precedence,
+/// the value is at most the sum of all numbers to the power of 5, so the
+/// sum of all expressions fits into `u64`.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| generate_expression(rng, 0, &mut 4))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub struct Day18 {}
+
+const EXAMPLES: &[Example] = &[
+    Example {
+        input: "1 + 2 * 3 + 4 * 5 + 6",
+        first: Some("71"),
+        second: Some("231"),
+    },
+    Example {
+        input: "2 * 3 + (4 * 5)
+5 + (8 * 3 + 9 + 3 * 4 * 3)",
+        first: Some("463"),
+        second: Some("1491"),
+    },
+];
+
+/// Characters of expressions only, malformed ones are found by `parse`,
+/// which `lint` runs as well.
+const FORMAT: InputFormat = InputFormat::Lines(Pattern::Chars("0123456789+*() "));
+
+const GENERATOR: Generator = Generator {
+    size: "number of expressions",
+    sizes: 1..=100_000,
+    default_size: 4_000,
+    generate,
+};
+
+register! {
+    day: DAY,
+    title: "Operation Order",
+    tags: [Parsing],
+    solution: Day18,
+    examples: EXAMPLES,
+    format: FORMAT,
+    generator: GENERATOR,
+}
+
+impl Challenge for Day18 {
+    type Input<'a> = Vec<Vec<Token>>;
+
+    fn parse<'a>(&self, expressions_text: &'a str) -> Result<Self::Input<'a>, Error> {
+        FORMAT.validate(DAY, expressions_text)?;
+
+        tokenize_expressions(expressions_text)
+    }
+
+    fn first_part(&self, expressions: &Self::Input<'_>) -> Result<Answer, Error> {
+        Ok(sum_of_expressions(expressions)?.into())
+    }
+
+    fn second_part(&self, expressions: &Self::Input<'_>) -> Result<Answer, Error> {
+        Ok(sum_of_expressions_advanced(expressions)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evaluate_expression() {
+        let test_expressions = [
+            ("1 + 2 * 3 + 4 * 5 + 6", 71_u64),
+            ("1 + (2 * 3) + (4 * (5 + 6))", 51_u64),
+            ("2 * 3 + (4 * 5)", 26_u64),
+            ("5 + (8 * 3 + 9 + 3 * 4 * 3)", 437_u64),
+            ("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))", 12240_u64),
+            ("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2", 13632_u64),
+        ];
+
+        for (expr, result) in &test_expressions {
+            let expr = tokenize(expr).unwrap();
+            assert_eq!(evaluate_expression(&expr), Ok(*result));
+        }
+    }
+
+    #[test]
+    fn test_evaluate_expression_advanced() {
+        let test_expressions = [
+            ("1 + 2 * 3 + 4 * 5 + 6", 231_u64),
+            ("1 + (2 * 3) + (4 * (5 + 6))", 51_u64),
+            ("2 * 3 + (4 * 5)", 46_u64),
+            ("5 + (8 * 3 + 9 + 3 * 4 * 3)", 1445_u64),
+            ("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))", 669060_u64),
+            ("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2", 23340_u64),
+        ];
+
+        for (expr, result) in &test_expressions {
+            let expr = tokenize(expr).unwrap();
+            assert_eq!(evaluate_expression_advanced(&expr), Ok(*result));
+        }
+    }
+
+    #[test]
+    fn test_tokenize_multi_digit_numbers() {
+        use Token::*;
+
+        assert!(matches!(
+            tokenize("12 * (3 + 405)").unwrap()[..],
+            [
+                Number(12),
+                Multiply,
+                OpenBracket,
+                Number(3),
+                Plus,
+                Number(405),
+                CloseBracket
+            ]
+        ));
+        assert_eq!(
+            tokenize("1 + 2 - 3").err(),
+            Some((7, String::from("unsupported token '-'")))
+        );
+    }
+
+    #[test]
+    fn test_tokenize_malformed_expressions() {
+        let error = |expr| tokenize(expr).err().map(|(column, _)| column);
+
+        assert_eq!(error("1 +"), Some(4));
+        assert_eq!(error("(1 + 2"), Some(7));
+        assert_eq!(error("1 2"), Some(3));
+        assert_eq!(error(") + 1"), Some(1));
+        assert_eq!(error("1 + 2)"), Some(6));
+        assert_eq!(error("()"), Some(2));
+        assert_eq!(error(""), Some(1));
+    }
+
+    #[test]
+    fn test_values_too_large() {
+        let expressions = vec![
+            tokenize("4294967296 * 4294967296").unwrap(),
+            tokenize("1").unwrap(),
+        ];
+
+        assert!(sum_of_expressions(&expressions).is_err());
+        assert!(sum_of_expressions_advanced(&expressions).is_err());
+        assert!(sum_of_expressions(&[
+            tokenize("18446744073709551615").unwrap(),
+            tokenize("1").unwrap()
+        ])
+        .is_err());
+    }
+}
@@ -0,0 +1,704 @@
+use super::cancel::Steps;
+use super::format::{InputFormat, Pattern};
+use super::generator::{Generator, Rng};
+use super::registry::Example;
+use super::solution::{Answer, Challenge, Error};
+use std::collections::{HashMap, HashSet};
+
+const DAY: u8 = 19;
+
+#[derive(Clone, Debug)]
+enum Match {
+    Letter { letter: char },
+    RuleName { name: i32 },
+    And { rules: Vec<Match> },
+    Or { rules: Vec<Match> },
+}
+
+/// Maximum depth of rules referring to other rules while matching a message.
+const MAX_DEPTH: usize = 1_000;
+
+/// Matches parts of a message, remembering where the parts matched by every
+/// rule from every position end. Every rule tried at a position is a step.
+/// Errors are boxed to keep frames of deeply nested rules small.
+struct Matcher<'a> {
+    rules: &'a Rules,
+    message: Vec<char>,
+    ends: HashMap<(i32, usize), Vec<usize>>,
+    in_progress: HashSet<(i32, usize)>,
+    steps: &'a mut Steps,
+}
+
+impl<'a> Matcher<'a> {
+    fn new(rules: &'a Rules, message: &str, steps: &'a mut Steps) -> Matcher<'a> {
+        Matcher {
+            rules,
+            message: message.chars().collect(),
+            ends: HashMap::new(),
+            in_progress: HashSet::new(),
+            steps,
+        }
+    }
+
+    fn match_rule(
+        &mut self,
+        name: i32,
+        idx: usize,
+        depth: usize,
+    ) -> Result<Vec<usize>, Box<Error>> {
+        if let Some(ends) = self.ends.get(&(name, idx)) {
+            return Ok(ends.clone());
+        }
+        if depth > MAX_DEPTH {
+            return Err(Box::new(Error::no_solution(
+                DAY,
+                "rules are nested too deeply",
+            )));
+        }
+        if !self.in_progress.insert((name, idx)) {
+            return Err(Box::new(Error::no_solution(
+                DAY,
+                format!("rule {} refers to itself before matching any letter", name),
+            )));
+        }
+
+        let rules = self.rules;
+        let rule = rules.graph.get(&name).ok_or_else(|| {
+            Box::new(Error::no_solution(
+                DAY,
+                format!("there is no rule {}", name),
+            ))
+        })?;
+        let mut ends = self.try_match(rule, idx, depth + 1)?;
+        ends.sort_unstable();
+        ends.dedup();
+
+        self.in_progress.remove(&(name, idx));
+        self.ends.insert((name, idx), ends.clone());
+
+        Ok(ends)
+    }
+
+    fn try_match(
+        &mut self,
+        rule: &Match,
+        idx: usize,
+        depth: usize,
+    ) -> Result<Vec<usize>, Box<Error>> {
+        use Match::*;
+
+        self.steps.step().map_err(Box::new)?;
+
+        if idx >= self.message.len() {
+            return Ok(vec![]);
+        }
+
+        match rule {
+            Letter { letter } => {
+                if *letter == self.message[idx] {
+                    Ok(vec![idx + 1])
+                } else {
+                    Ok(vec![])
+                }
+            }
+
+            RuleName { name } => self.match_rule(*name, idx, depth),
+
+            And { rules } => {
+                let mut ends = vec![idx];
+                for el in rules {
+                    let mut next = vec![];
+                    for i in ends {
+                        next.extend(self.try_match(el, i, depth)?);
+                    }
+                    next.sort_unstable();
+                    next.dedup();
+                    ends = next;
+                }
+
+                Ok(ends)
+            }
+
+            Or { rules } => {
+                let mut ends = vec![];
+                for rule in rules {
+                    ends.extend(self.try_match(rule, idx, depth)?);
+                }
+
+                Ok(ends)
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Rules {
+    graph: HashMap<i32, Match>,
+}
+
+impl Rules {
+    /// Whether the whole message matches the rule, or the problem with the
+    /// rules.
+    fn matches_rule(&self, message: &str, rule: i32, steps: &mut Steps) -> Result<bool, Error> {
+        let mut matcher = Matcher::new(self, message, steps);
+        let idxs = matcher.match_rule(rule, 0, 0).map_err(|err| *err)?;
+
+        Ok(idxs.contains(&matcher.message.len()))
+    }
+
+    fn make_changes_in_rules(&mut self) {
+        use Match::*;
+
+        self.graph.insert(
+            8,
+            Or {
+                rules: vec![
+                    RuleName { name: 42 },
+                    And {
+                        rules: vec![RuleName { name: 42 }, RuleName { name: 8 }],
+                    },
+                ],
+            },
+        );
+
+        self.graph.insert(
+            11,
+            Or {
+                rules: vec![
+                    And {
+                        rules: vec![RuleName { name: 42 }, RuleName { name: 31 }],
+                    },
+                    And {
+                        rules: vec![
+                            RuleName { name: 42 },
+                            RuleName { name: 11 },
+                            RuleName { name: 31 },
+                        ],
+                    },
+                ],
+            },
+        );
+    }
+
+    fn new(s: &str) -> Result<Rules, Error> {
+        let mut rules: Rules = Rules {
+            graph: HashMap::new(),
+        };
+
+        for (idx, line) in s.lines().enumerate() {
+            let (name, rule) = parse_rule(line)
+                .map_err(|(column, message)| Error::parse(DAY, idx + 1, column, message))?;
+
+            if rules.graph.insert(name, rule).is_some() {
+                return Err(Error::parse(
+                    DAY,
+                    idx + 1,
+                    1,
+                    format!("rule {} is defined twice", name),
+                ));
+            }
+        }
+
+        Ok(rules)
+    }
+}
+
+/// Rule name at the 1-based `column`.
+fn parse_name(name: &str, column: usize) -> Result<i32, (usize, String)> {
+    name.parse()
+        .map_err(|_| (column, format!("invalid rule name \"{}\"", name)))
+}
+
+/// Definition starting at the 1-based `column`.
+fn parse_definition(definition_text: &str, column: usize) -> Result<Match, (usize, String)> {
+    if let Some(letter) = definition_text.strip_prefix('"') {
+        let mut chars = letter.chars();
+
+        return match (chars.next(), chars.next(), chars.next()) {
+            (Some(letter), Some('"'), None) => Ok(Match::Letter { letter }),
+            _ => Err((column, format!("invalid letter {}", definition_text))),
+        };
+    }
+
+    let mut column = column;
+    let mut rules = vec![];
+    for num in definition_text.split(' ') {
+        rules.push(Match::RuleName {
+            name: parse_name(num, column)?,
+        });
+        column += num.len() + 1;
+    }
+
+    if rules.len() > 1 {
+        return Ok(Match::And { rules });
+    }
+
+    Ok(rules.remove(0))
+}
+
+/// Name and definition of the rule, or the 1-based column of the problem and
+/// its description.
+fn parse_rule(rule_text: &str) -> Result<(i32, Match), (usize, String)> {
+    let (name, definition) = rule_text
+        .split_once(": ")
+        .ok_or_else(|| (1, String::from("expected \"<name>: <rule>\"")))?;
+    let name = parse_name(name, 1)?;
+
+    let mut column = rule_text.len() - definition.len() + 1;
+    let mut rules = vec![];
+    for el in definition.split(" | ") {
+        rules.push(parse_definition(el, column)?);
+        column += el.len() + 3;
+    }
+
+    let rule = if rules.len() > 1 {
+        Match::Or { rules }
+    } else {
+        rules.remove(0)
+    };
+
+    Ok((name, rule))
+}
+
+fn parse_input_messages(input_messages: &str) -> Result<(Rules, Vec<&str>), Error> {
+    let mut parts = input_messages.split("\n\n");
+    let rules_text = parts.next().unwrap_or_default();
+    let rules = Rules::new(rules_text)?;
+    let messages = parts
+        .next()
+        .ok_or_else(|| {
+            let line = rules_text.lines().count() + 1;
+            Error::parse(DAY, line, 1, "expected messages after the rules")
+        })?
+        .lines()
+        .collect();
+
+    Ok((rules, messages))
+}
+
+fn count_messages_match_rule_0(rules: &Rules, messages: &[&str]) -> Result<usize, Error> {
+    let mut steps = Steps::new(DAY);
+    messages.iter().try_fold(0, |count, m| {
+        let matches = rules.matches_rule(m, 0, &mut steps)?;
+
+        Ok(count + matches as usize)
+    })
+}
+
+/// Rules 42 and 31 both match words of `LEN` letters and never the same word.
+/// For every length there is a pair of rules "a X | b Y" and "a X' | b Y'",
+/// where X and X', Y and Y' are the two rules of the pair for one letter less,
+/// so the pair splits all words of the length between its rules.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    const LEN: usize = 8;
+
+    let mut ids: Vec<u64> = (1..=150)
+        .filter(|id| ![8, 11, 31, 42].contains(id))
+        .collect();
+    rng.shuffle(&mut ids);
+
+    // For every length, whether the first rule of the pair continues with the
+    // first rule of the shorter pair after "a" and after "b".
+    let mut keeps = vec![(true, true)];
+    let mut rules = vec![format!("{}: \"a\"", ids[0]), format!("{}: \"b\"", ids[1])];
+    for len in 2..=LEN {
+        let (first, second) = if len == LEN {
+            (42, 31)
+        } else {
+            (ids[2 * len - 2], ids[2 * len - 1])
+        };
+        let shorter = [ids[2 * len - 4], ids[2 * len - 3]];
+        let (after_a, after_b) = (rng.chance(50), rng.chance(50));
+        let pick = |keep: bool| if keep { shorter[0] } else { shorter[1] };
+
+        rules.push(format!(
+            "{}: {} {} | {} {}",
+            first,
+            ids[0],
+            pick(after_a),
+            ids[1],
+            pick(after_b)
+        ));
+        rules.push(format!(
+            "{}: {} {} | {} {}",
+            second,
+            ids[0],
+            pick(!after_a),
+            ids[1],
+            pick(!after_b)
+        ));
+        keeps.push((after_a, after_b));
+    }
+    rules.push(String::from("0: 8 11"));
+    rules.push(String::from("8: 42"));
+    rules.push(String::from("11: 42 31"));
+    rng.shuffle(&mut rules);
+
+    // Word matched by rule 42 or by rule 31. Changing the last letter of a
+    // word moves it to the other rule of every pair.
+    let word = |rng: &mut Rng, by_42: bool| {
+        let mut letters: Vec<u8> = (0..LEN).map(|_| *rng.pick(b"ab")).collect();
+        let mut first = letters[LEN - 1] == b'a';
+        for len in 2..=LEN {
+            let (after_a, after_b) = keeps[len - 1];
+            let keep = if letters[LEN - len] == b'a' {
+                after_a
+            } else {
+                after_b
+            };
+            first = first == keep;
+        }
+        if first != by_42 {
+            letters[LEN - 1] = b'a' + b'b' - letters[LEN - 1];
+        }
+
+        String::from_utf8(letters).unwrap()
+    };
+
+    let mut messages = vec![];
+    for _ in 0..size {
+        let (repeats, ends) = match rng.between(0, 9) {
+            0..=3 => (2, 1),
+            4..=6 => {
+                let ends = rng.between(1, 3);
+                (rng.between(ends + 1, ends + 3), ends)
+            }
+            _ => {
+                let len = rng.between(LEN as u64, 5 * LEN as u64);
+                messages.push((0..len).map(|_| *rng.pick(&['a', 'b'])).collect());
+                continue;
+            }
+        };
+
+        let mut message = String::new();
+        for _ in 0..repeats {
+            message.push_str(&word(rng, true));
+        }
+        for _ in 0..ends {
+            message.push_str(&word(rng, false));
+        }
+        messages.push(message);
+    }
+
+    format!("{}\n\n{}", rules.join("\n"), messages.join("\n"))
+}
+
+pub struct Day19 {}
+
+const EXAMPLES: &[Example] = &[
+    Example {
+        input: "0: 4 1 5
+1: 2 3 | 3 2
+2: 4 4 | 5 5
+3: 4 5 | 5 4
+4: \"a\"
+5: \"b\"
+
+ababbb
+bababa
+abbbab
+aaabbb
+aaaabbb",
+        first: Some("2"),
+        second: None,
+    },
+    Example {
+        input: "42: 9 14 | 10 1
+9: 14 27 | 1 26
+10: 23 14 | 28 1
+1: \"a\"
+11: 42 31
+5: 1 14 | 15 1
+19: 14 1 | 14 14
+12: 24 14 | 19 1
+16: 15 1 | 14 14
+31: 14 17 | 1 13
+6: 14 14 | 1 14
+2: 1 24 | 14 4
+0: 8 11
+13: 14 3 | 1 12
+15: 1 | 14
+17: 14 2 | 1 7
+23: 25 1 | 22 14
+28: 16 1
+4: 1 1
+20: 14 14 | 1 15
+3: 5 14 | 16 1
+27: 1 6 | 14 18
+14: \"b\"
+21: 14 1 | 1 14
+25: 1 1 | 1 14
+22: 14 14
+8: 42
+26: 14 22 | 1 20
+18: 15 15
+7: 14 5 | 1 21
+24: 14 1
+
+abbbbbabbbaaaababbaabbbbabababbbabbbbbbabaaaa
+bbabbbbaabaabba
+babbbbaabbbbbabbbbbbaabaaabaaa
+aaabbbbbbaaaabaababaabababbabaaabbababababaaa
+bbbbbbbaaaabbbbaaabbabaaa
+bbbababbbbaaaaaaaabbababaaababaabab
+ababaaaaaabaaab
+ababaaaaabbbaba
+baabbaaaabbaaaababbaababb
+abbbbabbbbaaaababbbbbbaaaababb
+aaaaabbaabaaaaababaa
+aaaabbaaaabbaaa
+aaaabbaabbaaaaaaabbbabbbaaabbaabaaa
+babaaabbbaaabaababbaabababaaab
+aabbbbbaabbbaaaaaabbbbbababaaaaabbaaabba",
+        first: Some("3"),
+        second: Some("12"),
+    },
+];
+
+const FORMAT: InputFormat = InputFormat::Sections(&[
+    InputFormat::Lines(Pattern::Seq(&[
+        Pattern::Number,
+        Pattern::Literal(": "),
+        Pattern::Alt(&[
+            Pattern::Seq(&[
+                Pattern::Literal("\""),
+                Pattern::Char("ab"),
+                Pattern::Literal("\""),
+            ]),
+            Pattern::List(&Pattern::List(&Pattern::Number, " "), " | "),
+        ]),
+    ])),
+    InputFormat::Lines(Pattern::Chars("ab")),
+]);
+
+const GENERATOR: Generator = Generator {
+    size: "number of messages",
+    sizes: 1..=1_000_000,
+    default_size: 5_000,
+    generate,
+};
+
+register! {
+    day: DAY,
+    title: "Monster Messages",
+    tags: [Parsing],
+    solution: Day19,
+    examples: EXAMPLES,
+    format: FORMAT,
+    generator: GENERATOR,
+}
+
+impl Challenge for Day19 {
+    type Input<'a> = (Rules, Vec<&'a str>);
+
+    fn parse<'a>(&self, messages_text: &'a str) -> Result<Self::Input<'a>, Error> {
+        FORMAT.validate(DAY, messages_text)?;
+
+        parse_input_messages(messages_text)
+    }
+
+    fn first_part(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        let (rules, messages) = input;
+
+        Ok(count_messages_match_rule_0(rules, messages)?.into())
+    }
+
+    fn second_part(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        let (rules, messages) = input;
+        let mut rules = rules.clone();
+        rules.make_changes_in_rules();
+
+        Ok(count_messages_match_rule_0(&rules, messages)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(rules: &Rules, message: &str, rule: i32) -> Result<bool, Error> {
+        rules.matches_rule(message, rule, &mut Steps::new(DAY))
+    }
+
+    #[test]
+    fn test_simple_rules() {
+        let rules_text = "0: 1 2
+1: \"a\"
+2: 1 3 | 3 1
+3: \"b\"
+";
+
+        let rules = Rules::new(rules_text).unwrap();
+
+        let messages = [
+            ("a", 1, true),
+            ("b", 3, true),
+            ("ab", 2, true),
+            ("ba", 2, true),
+            ("aab", 0, true),
+            ("aba", 0, true),
+            ("b", 1, false),
+        ];
+        for (message, rule_name, is_valid) in &messages {
+            assert_eq!(matches(&rules, message, *rule_name).unwrap(), *is_valid);
+        }
+    }
+
+    #[test]
+    fn test_interesting_rules() {
+        let rules_text = "0: 4 1 5
+1: 2 3 | 3 2
+2: 4 4 | 5 5
+3: 4 5 | 5 4
+4: \"a\"
+5: \"b\"
+";
+
+        let rules = Rules::new(rules_text).unwrap();
+
+        let messages = [("abbbab", 0, true), ("aaaabbb", 0, false)];
+        for (message, rule_name, is_valid) in &messages {
+            assert_eq!(matches(&rules, message, *rule_name).unwrap(), *is_valid);
+        }
+    }
+
+    #[test]
+    fn test_second_task_before_changes() {
+        let test_rules = "42: 9 14 | 10 1
+9: 14 27 | 1 26
+10: 23 14 | 28 1
+1: \"a\"
+11: 42 31
+5: 1 14 | 15 1
+19: 14 1 | 14 14
+12: 24 14 | 19 1
+16: 15 1 | 14 14
+31: 14 17 | 1 13
+6: 14 14 | 1 14
+2: 1 24 | 14 4
+0: 8 11
+13: 14 3 | 1 12
+15: 1 | 14
+17: 14 2 | 1 7
+23: 25 1 | 22 14
+28: 16 1
+4: 1 1
+20: 14 14 | 1 15
+3: 5 14 | 16 1
+27: 1 6 | 14 18
+14: \"b\"
+21: 14 1 | 1 14
+25: 1 1 | 1 14
+22: 14 14
+8: 42
+26: 14 22 | 1 20
+18: 15 15
+7: 14 5 | 1 21
+24: 14 1";
+
+        let rules = Rules::new(test_rules).unwrap();
+
+        let messages = [
+            ("abbbbbabbbaaaababbaabbbbabababbbabbbbbbabaaaa", false),
+            ("bbabbbbaabaabba", true),
+            ("babbbbaabbbbbabbbbbbaabaaabaaa", false),
+            ("aaabbbbbbaaaabaababaabababbabaaabbababababaaa", false),
+            ("bbbbbbbaaaabbbbaaabbabaaa", false),
+            ("bbbababbbbaaaaaaaabbababaaababaabab", false),
+            ("ababaaaaaabaaab", true),
+            ("ababaaaaabbbaba", true),
+            ("baabbaaaabbaaaababbaababb", false),
+            ("abbbbabbbbaaaababbbbbbaaaababb", false),
+            ("aaaaabbaabaaaaababaa", false),
+            ("aaaabbaaaabbaaa", false),
+            ("aaaabbaabbaaaaaaabbbabbbaaabbaabaaa", false),
+            ("babaaabbbaaabaababbaabababaaab", false),
+            ("aabbbbbaabbbaaaaaabbbbbababaaaaabbaaabba", false),
+        ];
+        for (message, is_valid) in &messages {
+            assert_eq!(matches(&rules, message, 0).unwrap(), *is_valid);
+        }
+    }
+
+    #[test]
+    fn test_second_task_after_changes() {
+        let test_rules = "42: 9 14 | 10 1
+9: 14 27 | 1 26
+10: 23 14 | 28 1
+1: \"a\"
+11: 42 31
+5: 1 14 | 15 1
+19: 14 1 | 14 14
+12: 24 14 | 19 1
+16: 15 1 | 14 14
+31: 14 17 | 1 13
+6: 14 14 | 1 14
+2: 1 24 | 14 4
+0: 8 11
+13: 14 3 | 1 12
+15: 1 | 14
+17: 14 2 | 1 7
+23: 25 1 | 22 14
+28: 16 1
+4: 1 1
+20: 14 14 | 1 15
+3: 5 14 | 16 1
+27: 1 6 | 14 18
+14: \"b\"
+21: 14 1 | 1 14
+25: 1 1 | 1 14
+22: 14 14
+8: 42
+26: 14 22 | 1 20
+18: 15 15
+7: 14 5 | 1 21
+24: 14 1";
+
+        let mut rules = Rules::new(test_rules).unwrap();
+        rules.make_changes_in_rules();
+
+        let messages = [
+            "bbabbbbaabaabba",
+            "babbbbaabbbbbabbbbbbaabaaabaaa",
+            "aaabbbbbbaaaabaababaabababbabaaabbababababaaa",
+            "bbbbbbbaaaabbbbaaabbabaaa",
+            "bbbababbbbaaaaaaaabbababaaababaabab",
+            "ababaaaaaabaaab",
+            "ababaaaaabbbaba",
+            "baabbaaaabbaaaababbaababb",
+            "abbbbabbbbaaaababbbbbbaaaababb",
+            "aaaaabbaabaaaaababaa",
+            "aaaabbaabbaaaaaaabbbabbbaaabbaabaaa",
+            "aabbbbbaabbbaaaaaabbbbbababaaaaabbaaabba",
+        ];
+        for message in &messages {
+            assert!(matches(&rules, message, 0).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_invalid_rules() {
+        assert!(Rules::new("0: 1\n0: 2").is_err());
+        assert!(Rules::new("99999999999: 1").is_err());
+        assert!(Rules::new("0: 1 99999999999").is_err());
+
+        let rules = Rules::new("0: 1 2\n1: 0 2 | \"a\"\n2: \"b\"").unwrap();
+        assert!(matches(&rules, "ab", 0).is_err());
+
+        let rules = Rules::new("0: 1 2\n1: \"a\"").unwrap();
+        assert!(matches(&rules, "ab", 0).is_err());
+        assert!(!matches(&rules, "b", 0).unwrap());
+    }
+
+    #[test]
+    fn test_rules_nested_too_deeply() {
+        let rules: Vec<String> = (0..2 * MAX_DEPTH)
+            .map(|name| format!("{}: {}", name, name + 1))
+            .chain(Some(format!("{}: \"a\"", 2 * MAX_DEPTH)))
+            .collect();
+        let rules = Rules::new(&rules.join("\n")).unwrap();
+
+        assert!(matches(&rules, "a", 0).is_err());
+        assert!(matches(&rules, "a", MAX_DEPTH as i32 + 1).unwrap());
+    }
+}
@@ -1,8 +1,18 @@
-use super::solution::{Error, Solution};
+use super::format::{InputFormat, Pattern};
+use super::generator::{Generator, Rng};
+use super::registry::Example;
+use super::solution::{Answer, Challenge, Error};
 use std::cmp::{Eq, PartialEq};
 use std::collections::{HashMap, HashSet};
 use std::hash::{Hash, Hasher};
 
+const DAY: u8 = 20;
+
+/// Borders of tiles wider than this don't fit into border values.
+const MAX_TILE_SIZE: usize = 64;
+/// Tiles narrower than this have nothing inside their borders.
+const MIN_TILE_SIZE: usize = 3;
+
 type Border = usize;
 type Borders = [Border; 4];
 type Grid<T> = Vec<Vec<T>>;
@@ -145,7 +155,7 @@ impl Tile {
         self.image
             .possible_images()
             .iter()
-            .flat_map(|img| img.borders().iter().copied().collect::<Vec<usize>>())
+            .flat_map(|img| img.borders().to_vec())
             .collect::<HashSet<usize>>()
             .iter()
             .copied()
@@ -156,39 +166,53 @@ impl Tile {
         self.image.possible_images()
     }
 
-    fn from_str(tile_text: &str) -> Tile {
+    /// Tile from its text, or the 1-based column of the problem in the
+    /// header and its description.
+    fn from_str(tile_text: &str) -> Result<Tile, (usize, String)> {
         let mut lines = tile_text.lines();
-        let id_value = lines
-            .next()
-            .unwrap()
-            .trim_start_matches("Tile ")
-            .trim_end_matches(':')
+        let header = lines.next().unwrap_or_default();
+        let id_text = header.trim_start_matches("Tile ").trim_end_matches(':');
+        let id_value = id_text
             .parse::<usize>()
-            .unwrap();
+            .map_err(|_| (6, format!("invalid tile ID \"{}\"", id_text)))?;
 
         let image_text = lines.collect::<Vec<&str>>().join("\n");
         let image = Image::from_str(&image_text);
 
-        Tile {
+        let size = image.size();
+        if !(MIN_TILE_SIZE..=MAX_TILE_SIZE).contains(&size) {
+            return Err((
+                1,
+                format!(
+                    "tile {} must be from {} to {} pixels wide, got {}",
+                    id_value, MIN_TILE_SIZE, MAX_TILE_SIZE, size
+                ),
+            ));
+        }
+        if image.image.iter().any(|row| row.len() != size) {
+            return Err((1, format!("tile {} must be square", id_value)));
+        }
+
+        Ok(Tile {
             id: id_value,
             image,
-        }
+        })
     }
 }
 
-struct Arrangement {
+pub struct Arrangement {
     size: usize,
     grid: Grid<Tile>,
 }
 
-fn collect_border2tiles(tiles: &[Tile]) -> Border2Tiles {
+fn collect_border2tiles(tiles: &[Tile]) -> Border2Tiles<'_> {
     let mut border2tiles: Border2Tiles = HashMap::new();
 
     for tile in tiles {
         for border in tile.unique_border_sides() {
-            let entry = border2tiles.entry(border).or_insert_with(HashSet::new);
+            let entry = border2tiles.entry(border).or_default();
 
-            entry.insert(&tile);
+            entry.insert(tile);
         }
     }
 
@@ -207,18 +231,10 @@ fn recover_tile_graph<'a>(border2tiles: &'a Border2Tiles) -> ArrangementGraph<'a
         let first = tiles_list[0];
         let second = tiles_list[1];
 
-        let first_entry = graph
-            .entry(*first)
-            .or_insert_with(HashMap::new)
-            .entry(*second)
-            .or_insert_with(Vec::new);
+        let first_entry = graph.entry(*first).or_default().entry(*second).or_default();
         first_entry.push(*side);
 
-        let second_entry = graph
-            .entry(*second)
-            .or_insert_with(HashMap::new)
-            .entry(*first)
-            .or_insert_with(Vec::new);
+        let second_entry = graph.entry(*second).or_default().entry(*first).or_default();
         second_entry.push(*side);
     }
 
@@ -249,7 +265,7 @@ fn recover_arrangement_grid<'a>(
     size: usize,
     starting_tile: &'a Tile,
     graph: &'a ArrangementGraph,
-) -> Grid<Tile> {
+) -> Option<Grid<Tile>> {
     let mut visited: HashSet<&Tile> = HashSet::new();
     visited.insert(starting_tile);
 
@@ -257,8 +273,8 @@ fn recover_arrangement_grid<'a>(
     let mut current_row: Vec<&Tile> = vec![starting_tile];
 
     for _ in 0..size - 1 {
-        for neighbour in graph[current_row[current_row.len() - 1]].keys() {
-            if !visited.contains(neighbour) && graph[neighbour].len() < 4 {
+        for neighbour in graph.get(current_row[current_row.len() - 1])?.keys() {
+            if !visited.contains(neighbour) && graph.get(neighbour)?.len() < 4 {
                 visited.insert(neighbour);
                 current_row.push(neighbour);
                 break;
@@ -266,13 +282,16 @@ fn recover_arrangement_grid<'a>(
         }
     }
 
+    if current_row.len() != size {
+        return None;
+    }
     arrangement.push(current_row);
 
     for _ in 0..size - 1 {
         let mut current_row = vec![];
 
         for &last_elem in arrangement[arrangement.len() - 1].iter() {
-            for &n in graph[last_elem].keys() {
+            for &n in graph.get(last_elem)?.keys() {
                 if !visited.contains(n) {
                     visited.insert(n);
                     current_row.push(n);
@@ -281,6 +300,9 @@ fn recover_arrangement_grid<'a>(
             }
         }
 
+        if current_row.len() != size {
+            return None;
+        }
         arrangement.push(current_row);
     }
 
@@ -308,10 +330,8 @@ fn recover_arrangement_grid<'a>(
 
                     let possible_border = possible_image.borders()[border];
                     let allowed_borders = graph
-                        .get(tile)
-                        .unwrap()
-                        .get(arrangement[nr as usize][nc as usize])
-                        .unwrap();
+                        .get(tile)?
+                        .get(arrangement[nr as usize][nc as usize])?;
 
                     if !allowed_borders.contains(&possible_border) {
                         orientation_found = false;
@@ -330,15 +350,20 @@ fn recover_arrangement_grid<'a>(
             }
         }
 
+        if current_row.len() != size {
+            return None;
+        }
         fixed_arrangement.push(current_row);
     }
 
-    validate_arrangement_grid(size, &fixed_arrangement);
+    if !is_arrangement_grid_valid(size, &fixed_arrangement) {
+        return None;
+    }
 
-    fixed_arrangement
+    Some(fixed_arrangement)
 }
 
-fn validate_arrangement_grid(size: usize, arrangement: &Grid<Tile>) {
+fn is_arrangement_grid_valid(size: usize, arrangement: &Grid<Tile>) -> bool {
     for r in 0..size {
         for c in 0..size {
             let tile = &arrangement[r][c];
@@ -357,18 +382,27 @@ fn validate_arrangement_grid(size: usize, arrangement: &Grid<Tile>) {
 
                 let n_border = &arrangement[nr as usize][nc as usize].image.borders();
 
-                assert!(tile_border[border] == n_border[(border + 2) % 4]);
+                if tile_border[border] != n_border[(border + 2) % 4] {
+                    return false;
+                }
             }
         }
     }
+
+    true
 }
 
-fn recover_from_tiles(size: usize, tiles: Vec<Tile>) -> Grid<Tile> {
+/// Tiles arranged into a square image, if they fit together.
+fn recover_from_tiles(size: usize, tiles: Vec<Tile>) -> Option<Grid<Tile>> {
     let border2tiles = collect_border2tiles(&tiles);
     let graph = recover_tile_graph(&border2tiles);
     let border_tiles = find_corner_tiles(&border2tiles);
+    let starting_tile = match size {
+        1 => tiles.first()?,
+        _ => *border_tiles.first()?,
+    };
 
-    recover_arrangement_grid(size, border_tiles[0], &graph)
+    recover_arrangement_grid(size, starting_tile, &graph)
 }
 
 impl Arrangement {
@@ -401,23 +435,66 @@ impl Arrangement {
         big_tile_image
     }
 
-    fn from_str(tiles_text: &str) -> Arrangement {
-        let tiles = tiles_text
-            .split("\n\n")
-            .map(|t| Tile::from_str(t))
-            .collect::<Vec<_>>();
+    fn from_str(tiles_text: &str) -> Result<Arrangement, Error> {
+        let mut tiles: Vec<Tile> = vec![];
+        let mut ids = HashSet::new();
+        let mut line = 1;
+
+        for tile_text in tiles_text.split("\n\n") {
+            let tile = Tile::from_str(tile_text)
+                .map_err(|(column, message)| Error::parse(DAY, line, column, message))?;
+
+            if !ids.insert(tile.id) {
+                return Err(Error::parse(
+                    DAY,
+                    line,
+                    6,
+                    format!("tile {} is defined twice", tile.id),
+                ));
+            }
+            if let Some(first) = tiles.first() {
+                if first.size() != tile.size() {
+                    return Err(Error::parse(
+                        DAY,
+                        line,
+                        1,
+                        format!(
+                            "tile {} must be {} pixels wide like tile {}",
+                            tile.id,
+                            first.size(),
+                            first.id
+                        ),
+                    ));
+                }
+            }
 
-        let size = (tiles.len() as f64).sqrt() as usize;
-        let grid = recover_from_tiles(size, tiles);
+            line += tile_text.lines().count() + 1;
+            tiles.push(tile);
+        }
 
-        Arrangement { size, grid }
+        let size = (1..)
+            .find(|&size| size * size >= tiles.len())
+            .unwrap_or_default();
+        if size * size != tiles.len() {
+            return Err(Error::no_solution(
+                DAY,
+                format!("{} tiles don't form a square image", tiles.len()),
+            ));
+        }
+
+        let grid = recover_from_tiles(size, tiles)
+            .ok_or_else(|| Error::no_solution(DAY, "tiles don't fit together"))?;
+
+        Ok(Arrangement { size, grid })
     }
 }
 
-fn count_product_of_corners_in_arrangement(arr: &Arrangement) -> usize {
+fn count_product_of_corners_in_arrangement(arr: &Arrangement) -> Option<usize> {
     let border_tiles = arr.find_corner_tiles();
 
-    border_tiles.iter().product()
+    border_tiles
+        .iter()
+        .try_fold(1_usize, |product, &id| product.checked_mul(id))
 }
 
 fn dragon_points() -> Vec<(usize, usize)> {
@@ -503,19 +580,286 @@ fn count_water_roughness(arr: &Arrangement) -> usize {
     count_hashes_in(&marked_image)
 }
 
+const SEA_MONSTER: [&str; 3] = [
+    "                  # ",
+    "#    ##    ##    ###",
+    " #  #  #  #  #  #   ",
+];
+
+/// Image with sea monsters that don't overlap on rough water.
+fn generate_image(rng: &mut Rng, size: usize) -> Vec<Vec<bool>> {
+    let mut image: Vec<Vec<bool>> = (0..size)
+        .map(|_| (0..size).map(|_| rng.chance(25)).collect())
+        .collect();
+    let mut taken = vec![vec![false; size]; size];
+
+    let (height, width) = (SEA_MONSTER.len(), SEA_MONSTER[0].len());
+    for _ in 0..size * size / 400 + 1 {
+        let (r, c) = (rng.index(size - height + 1), rng.index(size - width + 1));
+        if (r..r + height).any(|r| taken[r][c..c + width].contains(&true)) {
+            continue;
+        }
+
+        for (dr, line) in SEA_MONSTER.iter().enumerate() {
+            for (dc, ch) in line.chars().enumerate() {
+                taken[r + dr][c + dc] = true;
+                image[r + dr][c + dc] |= ch == '#';
+            }
+        }
+    }
+
+    image
+}
+
+fn rotate_clockwise(tile: &[Vec<bool>]) -> Vec<Vec<bool>> {
+    (0..tile.len())
+        .map(|r| {
+            (0..tile.len())
+                .map(|c| tile[tile.len() - c - 1][r])
+                .collect()
+        })
+        .collect()
+}
+
+/// Square image cut into tiles of `side` cells. Neighbour tiles share their
+/// border lines, and every border is unique and reads differently from both
+/// ends, so there is a single way to put the tiles back together.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let borders = 2 * size * (size + 1);
+    let side = (10..).find(|&side| borders * 8 <= 1 << side).unwrap();
+    let image = generate_image(rng, size * (side - 2));
+
+    // Tiles overlap with their borders in the full grid.
+    let full_size = size * (side - 1) + 1;
+    let mut grid = vec![vec![false; full_size]; full_size];
+    for (r, row) in grid.iter_mut().enumerate() {
+        for (c, cell) in row.iter_mut().enumerate() {
+            *cell = match (r % (side - 1), c % (side - 1)) {
+                (0, _) | (_, 0) => rng.chance(50),
+                (tr, tc) => {
+                    image[r / (side - 1) * (side - 2) + tr - 1]
+                        [c / (side - 1) * (side - 2) + tc - 1]
+                }
+            };
+        }
+    }
+
+    let mut seen = HashSet::new();
+    for line in 0..=size {
+        for tile in 0..size {
+            for &vertical in &[false, true] {
+                let cell = |t: usize| match vertical {
+                    false => (line * (side - 1), tile * (side - 1) + t),
+                    true => (tile * (side - 1) + t, line * (side - 1)),
+                };
+
+                loop {
+                    let border: Vec<bool> = (0..side)
+                        .map(|t| {
+                            let (r, c) = cell(t);
+                            grid[r][c]
+                        })
+                        .collect();
+                    let reversed: Vec<bool> = border.iter().rev().copied().collect();
+                    if border != reversed && !seen.contains(&reversed) && seen.insert(border) {
+                        break;
+                    }
+
+                    for t in 1..side - 1 {
+                        let (r, c) = cell(t);
+                        grid[r][c] = rng.chance(50);
+                    }
+                }
+            }
+        }
+    }
+
+    let mut ids: Vec<usize> = (1000..10_000).collect();
+    rng.shuffle(&mut ids);
+
+    let mut tiles = vec![];
+    for (idx, &id) in ids.iter().enumerate().take(size * size) {
+        let (r, c) = (idx / size * (side - 1), idx % size * (side - 1));
+        let mut tile: Vec<Vec<bool>> = grid[r..r + side]
+            .iter()
+            .map(|row| row[c..c + side].to_vec())
+            .collect();
+
+        for _ in 0..rng.between(0, 3) {
+            tile = rotate_clockwise(&tile);
+        }
+        if rng.chance(50) {
+            tile.reverse();
+        }
+
+        let lines: Vec<String> = tile
+            .iter()
+            .map(|row| row.iter().map(|&on| if on { '#' } else { '.' }).collect())
+            .collect();
+        tiles.push(format!("Tile {}:\n{}", id, lines.join("\n")));
+    }
+    rng.shuffle(&mut tiles);
+
+    tiles.join("\n\n")
+}
+
 pub struct Day20 {}
 
-impl Solution for Day20 {
-    fn first_task(&self, tiles_text: &str) -> Result<String, Error> {
-        let arrangement = Arrangement::from_str(tiles_text);
+const EXAMPLES: &[Example] = &[Example {
+    input: "Tile 2311:
+..##.#..#.
+##..#.....
+#...##..#.
+####.#...#
+##.##.###.
+##...#.###
+.#.#.#..##
+..#....#..
+###...#.#.
+..###..###
+
+Tile 1951:
+#.##...##.
+#.####...#
+.....#..##
+#...######
+.##.#....#
+.###.#####
+###.##.##.
+.###....#.
+..#.#..#.#
+#...##.#..
+
+Tile 1171:
+####...##.
+#..##.#..#
+##.#..#.#.
+.###.####.
+..###.####
+.##....##.
+.#...####.
+#.##.####.
+####..#...
+.....##...
+
+Tile 1427:
+###.##.#..
+.#..#.##..
+.#.##.#..#
+#.#.#.##.#
+....#...##
+...##..##.
+...#.#####
+.#.####.#.
+..#..###.#
+..##.#..#.
+
+Tile 1489:
+##.#.#....
+..##...#..
+.##..##...
+..#...#...
+#####...#.
+#..#.#.#.#
+...#.#.#..
+##.#...##.
+..##.##.##
+###.##.#..
+
+Tile 2473:
+#....####.
+#..#.##...
+#.##..#...
+######.#.#
+.#...#.#.#
+.#########
+.###.#..#.
+########.#
+##...##.#.
+..###.#.#.
 
-        Ok(count_product_of_corners_in_arrangement(&arrangement).to_string())
+Tile 2971:
+..#.#....#
+#...###...
+#.#.###...
+##.##..#..
+.#####..##
+.#..####.#
+#..#.#..#.
+..####.###
+..#.#.###.
+...#.#.#.#
+
+Tile 2729:
+...#.#.#.#
+####.#....
+..#.#.....
+....#..#.#
+.##..##.#.
+.#.####...
+####.#.#..
+##.####...
+##..#.##..
+#.##...##.
+
+Tile 3079:
+#.#.#####.
+.#..######
+..#.......
+######....
+####.#..#.
+.#...#.##.
+#.#####.##
+..#.###...
+..#.......
+..#.###...",
+    first: Some("20899048083289"),
+    second: Some("273"),
+}];
+
+const FORMAT: InputFormat = InputFormat::Groups(&InputFormat::Header(
+    Pattern::Seq(&[
+        Pattern::Literal("Tile "),
+        Pattern::Number,
+        Pattern::Literal(":"),
+    ]),
+    &InputFormat::Grid(".#"),
+));
+
+const GENERATOR: Generator = Generator {
+    size: "number of tiles along a side of the image",
+    sizes: 3..=30,
+    default_size: 12,
+    generate,
+};
+
+register! {
+    day: DAY,
+    title: "Jurassic Jigsaw",
+    tags: [Graph],
+    solution: Day20,
+    examples: EXAMPLES,
+    format: FORMAT,
+    generator: GENERATOR,
+}
+
+impl Challenge for Day20 {
+    type Input<'a> = Arrangement;
+
+    fn parse<'a>(&self, tiles_text: &'a str) -> Result<Self::Input<'a>, Error> {
+        FORMAT.validate(DAY, tiles_text)?;
+
+        Arrangement::from_str(tiles_text)
     }
 
-    fn second_task(&self, tiles_text: &str) -> Result<String, Error> {
-        let arrangement = Arrangement::from_str(tiles_text);
+    fn first_part(&self, arrangement: &Self::Input<'_>) -> Result<Answer, Error> {
+        count_product_of_corners_in_arrangement(arrangement)
+            .map(|product| product.into())
+            .ok_or_else(|| Error::no_solution(DAY, "product of corner tile IDs is too large"))
+    }
 
-        Ok(count_water_roughness(&arrangement).to_string())
+    fn second_part(&self, arrangement: &Self::Input<'_>) -> Result<Answer, Error> {
+        Ok(count_water_roughness(arrangement).into())
     }
 }
 
@@ -687,10 +1031,10 @@ Tile 3079:
 ..#.......
 ..#.###...";
 
-        let test_arrangement = Arrangement::from_str(test_tiles_text);
+        let test_arrangement = Arrangement::from_str(test_tiles_text).unwrap();
         assert_eq!(
             count_product_of_corners_in_arrangement(&test_arrangement),
-            20899048083289
+            Some(20899048083289)
         );
     }
 
@@ -804,7 +1148,28 @@ Tile 3079:
 ..#.......
 ..#.###...";
 
-        let test_arrangement = Arrangement::from_str(test_tiles_text);
+        let test_arrangement = Arrangement::from_str(test_tiles_text).unwrap();
         assert_eq!(count_water_roughness(&test_arrangement), 273);
     }
+
+    #[test]
+    fn test_tiles_that_cannot_be_arranged() {
+        let tile = |id: usize, rows: &[&str]| format!("Tile {}:\n{}", id, rows.join("\n"));
+        let square = ["#..", "...", "..#"];
+
+        assert!(Arrangement::from_str(&tile(1, &["#..", "..."])).is_err());
+        assert!(Arrangement::from_str(&tile(1, &["#.", ".#"])).is_err());
+        assert!(Arrangement::from_str(&[tile(1, &square), tile(1, &square)].join("\n\n")).is_err());
+        assert!(Arrangement::from_str(&[tile(1, &square), tile(2, &square)].join("\n\n")).is_err());
+        assert!(Arrangement::from_str("Tile 99999999999999999999:\n#..\n...\n..#").is_err());
+
+        let tiles: Vec<String> = (1..=4).map(|id| tile(id, &["#.#", "...", "###"])).collect();
+        assert!(Arrangement::from_str(&tiles.join("\n\n")).is_err());
+
+        let arrangement = Arrangement::from_str(&tile(7, &square)).unwrap();
+        assert_eq!(
+            count_product_of_corners_in_arrangement(&arrangement),
+            Some(2401)
+        );
+    }
 }
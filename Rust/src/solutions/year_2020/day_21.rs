@@ -0,0 +1,384 @@
+use super::format::{InputFormat, Pattern};
+use super::generator::{Generator, Rng};
+use super::registry::Example;
+use super::solution::{Answer, Challenge, Error};
+use std::cmp::Eq;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+const DAY: u8 = 21;
+
+pub struct Food<'a> {
+    ingredients: Vec<&'a str>,
+    allergens: Vec<&'a str>,
+}
+
+impl Food<'_> {
+    /// Food from its description, or the 1-based column of the problem and
+    /// its description.
+    fn new(s: &str) -> Result<Food<'_>, (usize, String)> {
+        let (ingredients_text, allergens_text) = s.split_once(" (contains ").ok_or_else(|| {
+            (
+                s.chars().count() + 1,
+                String::from("expected \" (contains \""),
+            )
+        })?;
+        let allergens_text = allergens_text.trim_end_matches(')');
+
+        Ok(Food {
+            ingredients: ingredients_text.split_whitespace().collect(),
+            allergens: allergens_text.split(", ").collect(),
+        })
+    }
+}
+
+/// Allergens and ingredients are different vertices of the graph even when
+/// they have the same name.
+#[derive(PartialEq, Eq, Hash)]
+enum Item<'a> {
+    Allergen(&'a str),
+    Ingredient(&'a str),
+}
+
+struct Graph<'a, T>
+where
+    T: Eq + Hash,
+{
+    g: HashMap<&'a T, Vec<&'a T>>,
+}
+
+impl<'a, T> Graph<'a, T>
+where
+    T: Eq + Hash,
+{
+    fn new() -> Graph<'a, T> {
+        Graph { g: HashMap::new() }
+    }
+
+    fn add_edge(&'a mut self, u: &'a T, v: &'a T) -> &'a mut Graph<'a, T> {
+        self.g.entry(u).or_default().push(v);
+        self.g.entry(v).or_default().push(u);
+
+        self
+    }
+
+    fn find_matching_using_kuhn_algorithm(&self) -> HashMap<&T, &T> {
+        let mut matching: HashMap<&T, &T> = HashMap::new();
+        let mut visited: HashSet<&T> = HashSet::new();
+        let mut has_augmented = true;
+
+        while has_augmented {
+            has_augmented = false;
+            visited.clear();
+
+            for u in self.g.keys() {
+                if !matching.contains_key(u) && !visited.contains(u) {
+                    has_augmented |= self.augment(u, &mut visited, &mut matching);
+                }
+            }
+        }
+
+        matching.iter().map(|(&l, &r)| (l, r)).collect()
+    }
+
+    /// Looks for an augmenting path from `u` and flips the path when it's
+    /// found. The path is kept on the heap, so long paths can't overflow the
+    /// stack.
+    fn augment(
+        &'a self,
+        u: &'a T,
+        visited: &mut HashSet<&'a T>,
+        matching: &mut HashMap<&'a T, &'a T>,
+    ) -> bool {
+        let no_neighbours = vec![];
+        let neighbours = |u| self.g.get(u).unwrap_or(&no_neighbours);
+
+        // Vertices of the path with the number of their tried neighbours.
+        let mut path: Vec<(&T, usize)> = vec![(u, 0)];
+        visited.insert(u);
+
+        while let Some((u, tried)) = path.last_mut() {
+            let v = match neighbours(*u).get(*tried) {
+                Some(v) => *v,
+                None => {
+                    path.pop();
+                    continue;
+                }
+            };
+            *tried += 1;
+
+            match matching.get(v) {
+                Some(w) if visited.contains(w) => {}
+                Some(&w) => {
+                    visited.insert(w);
+                    path.push((w, 0));
+                }
+                None => {
+                    for &(u, tried) in &path {
+                        let v = neighbours(u)[tried - 1];
+                        matching.insert(v, u);
+                        matching.insert(u, v);
+                    }
+
+                    return true;
+                }
+            }
+        }
+
+        false
+    }
+}
+
+fn parse_food_list(foods_text: &str) -> Result<Vec<Food<'_>>, Error> {
+    foods_text
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            Food::new(line).map_err(|(column, message)| Error::parse(DAY, idx + 1, column, message))
+        })
+        .collect()
+}
+
+fn find_allergen_candidates<'a>(food_list: &[Food<'a>]) -> HashMap<&'a str, HashSet<&'a str>> {
+    let mut allergen_candidates: HashMap<&str, HashSet<&str>> = HashMap::new();
+    for food in food_list.iter() {
+        let food_ingredients: HashSet<&str> = food.ingredients.iter().copied().collect();
+
+        for allergen in food.allergens.iter() {
+            let val = allergen_candidates.entry(allergen).or_default();
+
+            if val.is_empty() {
+                *val = food_ingredients.iter().copied().collect();
+            } else {
+                *val = val.intersection(&food_ingredients).copied().collect();
+            }
+        }
+    }
+
+    allergen_candidates
+}
+
+fn count_allergen_free_ingredients(food_list: &[Food]) -> usize {
+    let possible_allergens: HashSet<&str> = find_allergen_candidates(food_list)
+        .values()
+        .fold(HashSet::new(), |acc, el| acc.union(el).copied().collect());
+
+    food_list
+        .iter()
+        .flat_map(|f| &f.ingredients)
+        .filter(|&i| !possible_allergens.contains(i))
+        .count()
+}
+
+/// Ingredients with the allergens sorted by the allergen, if every allergen
+/// can be in its own ingredient.
+fn find_allergen_list<'a>(food_list: &[Food<'a>]) -> Option<Vec<&'a str>> {
+    let allergen_candidates = find_allergen_candidates(food_list);
+    let edges: Vec<(Item, Item)> = allergen_candidates
+        .iter()
+        .flat_map(|(allergen, candidates)| {
+            candidates
+                .iter()
+                .map(move |c| (Item::Allergen(allergen), Item::Ingredient(c)))
+        })
+        .collect();
+    let mut graph = Graph::new();
+    let matching = edges
+        .iter()
+        .fold(&mut graph, |g, (allergen, candidate)| {
+            g.add_edge(allergen, candidate)
+        })
+        .find_matching_using_kuhn_algorithm();
+
+    let mut foreign_allergens: Vec<&str> = allergen_candidates.keys().copied().collect();
+    foreign_allergens.sort_unstable();
+
+    foreign_allergens
+        .iter()
+        .map(|el| match matching.get(&Item::Allergen(el)) {
+            Some(Item::Ingredient(ingredient)) => Some(*ingredient),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Every allergen has two foods whose other ingredients are different, so
+/// its ingredient can be found after the allergens before it are known.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    const ALLERGENS: [&str; 8] = [
+        "dairy", "eggs", "fish", "nuts", "peanuts", "sesame", "soy", "wheat",
+    ];
+
+    let mut words = HashSet::new();
+    while words.len() < ALLERGENS.len() + 40 + size / 10 {
+        words.insert(rng.word(3, 8));
+    }
+    let mut words: Vec<String> = words.into_iter().collect();
+    words.sort_unstable();
+    rng.shuffle(&mut words);
+    let (dangerous, safe) = words.split_at(ALLERGENS.len());
+
+    let food = |rng: &mut Rng, ingredients: Vec<&String>, allergens: Vec<&str>| {
+        let mut ingredients = ingredients;
+        rng.shuffle(&mut ingredients);
+        let ingredients: Vec<&str> = ingredients.iter().map(|s| s.as_str()).collect();
+
+        format!(
+            "{} (contains {})",
+            ingredients.join(" "),
+            allergens.join(", ")
+        )
+    };
+
+    let mut foods = vec![];
+    let mut order: Vec<usize> = (0..safe.len()).collect();
+    for idx in 0..ALLERGENS.len() {
+        rng.shuffle(&mut order);
+        for half in order[..8].chunks(4) {
+            let mut ingredients: Vec<&String> = half.iter().map(|&i| &safe[i]).collect();
+            ingredients.push(&dangerous[idx]);
+            if idx > 0 {
+                ingredients.push(&dangerous[idx - 1]);
+            }
+            foods.push(food(rng, ingredients, vec![ALLERGENS[idx]]));
+        }
+    }
+
+    while foods.len() < size {
+        let mut ingredients = vec![];
+        let mut allergens = vec![];
+        for (idx, allergen) in ALLERGENS.iter().enumerate() {
+            if rng.chance(20) {
+                ingredients.push(&dangerous[idx]);
+                if rng.chance(75) {
+                    allergens.push(*allergen);
+                }
+            }
+        }
+        if allergens.is_empty() {
+            let idx = rng.index(ALLERGENS.len());
+            if !ingredients.contains(&&dangerous[idx]) {
+                ingredients.push(&dangerous[idx]);
+            }
+            allergens.push(ALLERGENS[idx]);
+        }
+        allergens.sort_unstable();
+
+        rng.shuffle(&mut order);
+        let count = rng.between(3, 15) as usize;
+        ingredients.extend(order[..count].iter().map(|&i| &safe[i]));
+        foods.push(food(rng, ingredients, allergens));
+    }
+    rng.shuffle(&mut foods);
+
+    foods.join("\n")
+}
+
+pub struct Day21 {}
+
+const EXAMPLES: &[Example] = &[Example {
+    input: "mxmxvkd kfcds sqjhc nhms (contains dairy, fish)
+trh fvjkl sbzzf mxmxvkd (contains dairy)
+sqjhc fvjkl (contains soy)
+sqjhc mxmxvkd sbzzf (contains fish)",
+    first: Some("5"),
+    second: Some("mxmxvkd,sqjhc,fvjkl"),
+}];
+
+const FORMAT: InputFormat = InputFormat::Lines(Pattern::Seq(&[
+    Pattern::List(&Pattern::Word, " "),
+    Pattern::Literal(" (contains "),
+    Pattern::List(&Pattern::Word, ", "),
+    Pattern::Literal(")"),
+]));
+
+const GENERATOR: Generator = Generator {
+    size: "number of foods",
+    sizes: 16..=100_000,
+    default_size: 500,
+    generate,
+};
+
+register! {
+    day: DAY,
+    title: "Allergen Assessment",
+    tags: [Graph, Parsing],
+    solution: Day21,
+    examples: EXAMPLES,
+    format: FORMAT,
+    generator: GENERATOR,
+}
+
+impl Challenge for Day21 {
+    type Input<'a> = Vec<Food<'a>>;
+
+    fn parse<'a>(&self, foods_text: &'a str) -> Result<Self::Input<'a>, Error> {
+        FORMAT.validate(DAY, foods_text)?;
+
+        parse_food_list(foods_text)
+    }
+
+    fn first_part(&self, food_list: &Self::Input<'_>) -> Result<Answer, Error> {
+        Ok(count_allergen_free_ingredients(food_list).into())
+    }
+
+    fn second_part(&self, food_list: &Self::Input<'_>) -> Result<Answer, Error> {
+        let allergen_list = find_allergen_list(food_list).ok_or_else(|| {
+            Error::no_solution(DAY, "allergens can't be in different ingredients")
+        })?;
+
+        Ok(Answer::list(allergen_list, ","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_task() {
+        let test_ingredients_text = "mxmxvkd kfcds sqjhc nhms (contains dairy, fish)
+trh fvjkl sbzzf mxmxvkd (contains dairy)
+sqjhc fvjkl (contains soy)
+sqjhc mxmxvkd sbzzf (contains fish)";
+
+        let test_ingredients = parse_food_list(test_ingredients_text).unwrap();
+
+        assert_eq!(count_allergen_free_ingredients(&test_ingredients), 5);
+    }
+
+    #[test]
+    fn test_second_task() {
+        let test_ingredients_text = "mxmxvkd kfcds sqjhc nhms (contains dairy, fish)
+trh fvjkl sbzzf mxmxvkd (contains dairy)
+sqjhc fvjkl (contains soy)
+sqjhc mxmxvkd sbzzf (contains fish)";
+
+        let test_ingredients = parse_food_list(test_ingredients_text).unwrap();
+
+        assert_eq!(
+            find_allergen_list(&test_ingredients),
+            Some(vec!["mxmxvkd", "sqjhc", "fvjkl"])
+        );
+    }
+
+    #[test]
+    fn test_allergens_without_own_ingredient() {
+        let test_ingredients = parse_food_list("a b (contains dairy, fish, soy)").unwrap();
+
+        assert_eq!(find_allergen_list(&test_ingredients), None);
+    }
+
+    #[test]
+    fn test_ingredients_named_like_allergens() {
+        let test_ingredients = parse_food_list(
+            "fish dairy (contains dairy)\nfish soy (contains fish)\nfish (contains fish)",
+        )
+        .unwrap();
+
+        assert_eq!(
+            find_allergen_list(&test_ingredients),
+            Some(vec!["dairy", "fish"])
+        );
+    }
+}
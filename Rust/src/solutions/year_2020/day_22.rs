@@ -0,0 +1,350 @@
+use super::cancel::Steps;
+use super::format::{InputFormat, Pattern};
+use super::generator::{Generator, Rng};
+use super::registry::Example;
+use super::solution::{Answer, Challenge, Error};
+use itertools::Itertools;
+use std::collections::{hash_map, HashSet, VecDeque};
+use std::hash::{Hash, Hasher};
+
+const DAY: u8 = 22;
+
+type Deck = VecDeque<u8>;
+
+/// Deck whose "Player ..." line is the `line` of the input.
+fn parse_deck(deck_text: &str, line: usize) -> Result<Deck, Error> {
+    deck_text
+        .lines()
+        .enumerate()
+        .skip(1) // Skip "Player ..." line
+        .map(|(idx, el)| {
+            el.parse().map_err(|_| {
+                Error::parse(
+                    DAY,
+                    line + idx,
+                    1,
+                    format!("card must be from 0 to {}, got \"{}\"", u8::MAX, el),
+                )
+            })
+        })
+        .collect()
+}
+
+fn parse_decks(cards_text: &str) -> Result<(Deck, Deck), Error> {
+    let mut line = 1;
+    let mut decks = vec![];
+    for deck_text in cards_text.split("\n\n") {
+        decks.push(parse_deck(deck_text, line)?);
+        line += deck_text.lines().count() + 1;
+    }
+
+    decks
+        .into_iter()
+        .collect_tuple()
+        .ok_or_else(|| Error::parse(DAY, line, 1, "expected decks of two players"))
+}
+
+fn count_deck_score(deck: &Deck) -> usize {
+    deck.iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &v)| (i + 1) * v as usize)
+        .sum()
+}
+
+/// Decks at the end of the game, if the game ends. Without the recursion
+/// rule, the same decks can come back and the game goes on forever.
+/// Every round is a step.
+fn simulate_combat_game<'a>(
+    first_deck: &'a mut Deck,
+    second_deck: &'a mut Deck,
+    steps: &mut Steps,
+) -> Result<Option<(&'a Deck, &'a Deck)>, Error> {
+    let mut seen: HashSet<u64> = HashSet::new();
+    while let (Some(&f), Some(&s)) = (first_deck.front(), second_deck.front()) {
+        steps.step()?;
+
+        if !seen.insert(generate_cache_key(first_deck, second_deck)) {
+            return Ok(None);
+        }
+
+        first_deck.pop_front();
+        second_deck.pop_front();
+
+        if f > s {
+            first_deck.push_back(f);
+            first_deck.push_back(s);
+        } else {
+            second_deck.push_back(s);
+            second_deck.push_back(f);
+        }
+    }
+
+    Ok(Some((first_deck, second_deck)))
+}
+
+fn generate_cache_key(first_deck: &Deck, second_deck: &Deck) -> u64 {
+    let mut hasher = hash_map::DefaultHasher::new();
+    first_deck.hash(&mut hasher);
+    second_deck.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Whether the first player wins, with both decks at the end of the game.
+/// Every round is a step, including the rounds of sub-games.
+fn simulate_recursive_combat_game<'a>(
+    first_deck: &'a mut Deck,
+    second_deck: &'a mut Deck,
+    steps: &mut Steps,
+) -> Result<(bool, &'a Deck, &'a Deck), Error> {
+    let mut round_cache: HashSet<u64> = HashSet::new();
+    while !first_deck.is_empty() && !second_deck.is_empty() {
+        steps.step()?;
+
+        let round_cache_key = generate_cache_key(first_deck, second_deck);
+        if !round_cache.insert(round_cache_key) {
+            return Ok((true, first_deck, second_deck));
+        }
+
+        let (f, s) = match (first_deck.pop_front(), second_deck.pop_front()) {
+            (Some(f), Some(s)) => (f as usize, s as usize),
+            _ => break,
+        };
+
+        let mut res = f > s;
+
+        if (f <= first_deck.len()) && (s <= second_deck.len()) {
+            let mut first_sub_deck = (0..f).map(|i| first_deck[i]).collect::<Deck>();
+            let mut second_sub_deck = (0..s).map(|i| second_deck[i]).collect::<Deck>();
+
+            res =
+                simulate_recursive_combat_game(&mut first_sub_deck, &mut second_sub_deck, steps)?.0;
+        }
+
+        if res {
+            first_deck.push_back(f as u8);
+            first_deck.push_back(s as u8);
+        } else {
+            second_deck.push_back(s as u8);
+            second_deck.push_back(f as u8);
+        }
+    }
+
+    let game_result = !first_deck.is_empty();
+
+    Ok((game_result, first_deck, second_deck))
+}
+
+fn find_winning_score_in_combat(
+    first_deck: &mut Deck,
+    second_deck: &mut Deck,
+) -> Result<Option<usize>, Error> {
+    let mut steps = Steps::new(DAY);
+    let score = simulate_combat_game(first_deck, second_deck, &mut steps)?.map(|(fd, sd)| {
+        if !fd.is_empty() {
+            count_deck_score(fd)
+        } else {
+            count_deck_score(sd)
+        }
+    });
+
+    Ok(score)
+}
+
+fn find_winning_score_in_recursive_combat(
+    first_deck: &mut Deck,
+    second_deck: &mut Deck,
+) -> Result<usize, Error> {
+    let mut steps = Steps::new(DAY);
+    let (res, fd, sd) = simulate_recursive_combat_game(first_deck, second_deck, &mut steps)?;
+
+    if res {
+        Ok(count_deck_score(fd))
+    } else {
+        Ok(count_deck_score(sd))
+    }
+}
+
+/// Whether the plain game ends. Without the recursion rule, some decks are
+/// played forever.
+fn finishes(first: &[u64], second: &[u64]) -> bool {
+    let mut first: VecDeque<u64> = first.iter().copied().collect();
+    let mut second: VecDeque<u64> = second.iter().copied().collect();
+    let mut seen = HashSet::new();
+
+    while !first.is_empty() && !second.is_empty() {
+        if !seen.insert((first.clone(), second.clone())) {
+            return false;
+        }
+
+        let (f, s) = (first.pop_front().unwrap(), second.pop_front().unwrap());
+        if f > s {
+            first.extend(&[f, s]);
+        } else {
+            second.extend(&[s, f]);
+        }
+    }
+
+    true
+}
+
+/// Shuffled cards dealt to two players.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut cards: Vec<u64> = (1..=2 * size as u64).collect();
+    loop {
+        rng.shuffle(&mut cards);
+        let (first, second) = cards.split_at(size);
+        if !finishes(first, second) {
+            continue;
+        }
+
+        let deck = |cards: &[u64]| {
+            cards
+                .iter()
+                .map(u64::to_string)
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        return format!("Player 1:\n{}\n\nPlayer 2:\n{}", deck(first), deck(second));
+    }
+}
+
+pub struct Day22 {}
+
+const EXAMPLES: &[Example] = &[Example {
+    input: "Player 1:
+9
+2
+6
+3
+1
+
+Player 2:
+5
+8
+4
+7
+10",
+    first: Some("306"),
+    second: Some("291"),
+}];
+
+const FORMAT: InputFormat = InputFormat::Sections(&[
+    InputFormat::Header(
+        Pattern::Literal("Player 1:"),
+        &InputFormat::Lines(Pattern::Number),
+    ),
+    InputFormat::Header(
+        Pattern::Literal("Player 2:"),
+        &InputFormat::Lines(Pattern::Number),
+    ),
+]);
+
+const GENERATOR: Generator = Generator {
+    size: "number of cards in each deck",
+    sizes: 1..=50,
+    default_size: 25,
+    generate,
+};
+
+register! {
+    day: DAY,
+    title: "Crab Combat",
+    tags: [Simulation],
+    solution: Day22,
+    examples: EXAMPLES,
+    format: FORMAT,
+    generator: GENERATOR,
+}
+
+impl Challenge for Day22 {
+    type Input<'a> = (Deck, Deck);
+
+    fn parse<'a>(&self, cards_text: &'a str) -> Result<Self::Input<'a>, Error> {
+        FORMAT.validate(DAY, cards_text)?;
+
+        parse_decks(cards_text)
+    }
+
+    fn first_part(&self, decks: &Self::Input<'_>) -> Result<Answer, Error> {
+        let (mut first_deck, mut second_deck) = decks.clone();
+
+        find_winning_score_in_combat(&mut first_deck, &mut second_deck)?
+            .map(|score| score.into())
+            .ok_or_else(|| Error::no_solution(DAY, "the game never ends"))
+    }
+
+    fn second_part(&self, decks: &Self::Input<'_>) -> Result<Answer, Error> {
+        let (mut first_deck, mut second_deck) = decks.clone();
+
+        Ok(find_winning_score_in_recursive_combat(&mut first_deck, &mut second_deck)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_task() {
+        let test_cards_text = "Player 1:
+9
+2
+6
+3
+1
+
+Player 2:
+5
+8
+4
+7
+10";
+
+        let (mut fd, mut sd) = parse_decks(test_cards_text).unwrap();
+
+        assert_eq!(
+            find_winning_score_in_combat(&mut fd, &mut sd).unwrap(),
+            Some(306)
+        );
+    }
+
+    #[test]
+    fn test_second_task() {
+        let test_cards_text = "Player 1:
+9
+2
+6
+3
+1
+
+Player 2:
+5
+8
+4
+7
+10";
+
+        let (mut fd, mut sd) = parse_decks(test_cards_text).unwrap();
+
+        assert_eq!(
+            find_winning_score_in_recursive_combat(&mut fd, &mut sd).unwrap(),
+            291
+        );
+    }
+
+    #[test]
+    fn test_game_without_end() {
+        let (mut fd, mut sd) = parse_decks("Player 1:\n43\n19\n\nPlayer 2:\n2\n29\n14").unwrap();
+
+        assert_eq!(
+            find_winning_score_in_combat(&mut fd, &mut sd).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_reject_large_cards() {
+        assert!(parse_decks("Player 1:\n1\n\nPlayer 2:\n256").is_err());
+    }
+}
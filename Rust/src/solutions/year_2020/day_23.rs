@@ -0,0 +1,297 @@
+use super::cancel::Steps;
+use super::format::{InputFormat, Pattern};
+use super::generator::{Generator, Rng};
+use super::registry::Example;
+use super::solution::{Answer, Challenge, Error};
+
+const DAY: u8 = 23;
+
+/// Fewer cups can't have three cups picked up and a different destination.
+const MIN_CUPS: usize = 5;
+
+/// Cups labeled from 1 to the number of cups, or the 1-based column of the
+/// problem and its description. The only line may end with "\n".
+fn parse_cups(cups_text: &str) -> Result<Vec<usize>, (usize, String)> {
+    let cups_text = cups_text.strip_suffix('\n').unwrap_or(cups_text);
+    let size = cups_text.chars().count();
+    let mut cups = vec![];
+    let mut seen = vec![false; size + 1];
+
+    for (idx, ch) in cups_text.chars().enumerate() {
+        let cup = match ch.to_digit(10) {
+            Some(cup) if (1..=size).contains(&(cup as usize)) => cup as usize,
+            _ => {
+                return Err((
+                    idx + 1,
+                    format!("cups must be labeled from 1 to {}, got '{}'", size, ch),
+                ))
+            }
+        };
+        if seen[cup] {
+            return Err((idx + 1, format!("cup {} appears twice", cup)));
+        }
+
+        seen[cup] = true;
+        cups.push(cup);
+    }
+
+    if size < MIN_CUPS {
+        return Err((
+            size + 1,
+            format!("expected at least {} cups, got {}", MIN_CUPS, size),
+        ));
+    }
+
+    Ok(cups)
+}
+
+fn build_cups_buffer(cups: &[usize], size: usize) -> Vec<usize> {
+    let mut buffer = vec![0; size + 1];
+    let last = (1..size).fold(cups[0], |curr, i| {
+        buffer[curr] = if i < cups.len() { cups[i] } else { i + 1 };
+        buffer[curr]
+    });
+    buffer[last] = cups[0];
+    buffer
+}
+
+fn simulate_move(cups_buffer: &mut [usize], current_cup: usize) -> usize {
+    let size = cups_buffer.len() - 1;
+    let (a, b, c) = (
+        cups_buffer[current_cup],
+        cups_buffer[cups_buffer[current_cup]],
+        cups_buffer[cups_buffer[cups_buffer[current_cup]]],
+    );
+
+    let mut destination = if current_cup == 1 {
+        size
+    } else {
+        current_cup - 1
+    };
+
+    while destination == a || destination == b || destination == c {
+        destination = if destination == 1 {
+            size
+        } else {
+            destination - 1
+        };
+    }
+
+    cups_buffer[current_cup] = cups_buffer[c];
+    cups_buffer[c] = cups_buffer[destination];
+    cups_buffer[destination] = a;
+
+    cups_buffer[current_cup]
+}
+
+fn simulate_game(start_cup: usize, buffer: &mut [usize], moves: usize) -> Result<(), Error> {
+    let mut steps = Steps::new(DAY);
+    let mut curr = start_cup;
+    for _ in 0..moves {
+        steps.step()?;
+        curr = simulate_move(buffer, curr);
+    }
+
+    Ok(())
+}
+
+fn find_1_based_label(buffer: &[usize]) -> Vec<usize> {
+    let mut one_idx = 1;
+    let mut label = vec![];
+
+    for _i in 1..buffer.len() - 1 {
+        label.push(buffer[one_idx]);
+        one_idx = buffer[one_idx];
+    }
+
+    label
+}
+
+fn count_1_based_label_after(cups: &[usize], moves: usize) -> Result<Vec<usize>, Error> {
+    let mut buffer = build_cups_buffer(cups, cups.len());
+    simulate_game(cups[0], &mut buffer, moves)?;
+
+    Ok(find_1_based_label(&buffer))
+}
+
+fn count_product_of_two_labels_after_1(cups: &[usize]) -> Result<usize, Error> {
+    let mut buffer = build_cups_buffer(cups, 1_000_000);
+    simulate_game(cups[0], &mut buffer, 10_000_000)?;
+
+    Ok(buffer[1] * buffer[buffer[1]])
+}
+
+/// Cups labeled from 1 to the number of cups in a random order.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut cups: Vec<u64> = (1..=size as u64).collect();
+    rng.shuffle(&mut cups);
+
+    cups.iter().map(u64::to_string).collect()
+}
+
+pub struct Day23 {}
+
+const EXAMPLES: &[Example] = &[Example {
+    input: "389125467",
+    first: Some("67384529"),
+    second: Some("149245887792"),
+}];
+
+const FORMAT: InputFormat = InputFormat::Line(Pattern::Chars("123456789"));
+
+const GENERATOR: Generator = Generator {
+    size: "number of cups",
+    sizes: 5..=9,
+    default_size: 9,
+    generate,
+};
+
+register! {
+    day: DAY,
+    title: "Crab Cups",
+    tags: [Simulation],
+    solution: Day23,
+    examples: EXAMPLES,
+    format: FORMAT,
+    generator: GENERATOR,
+}
+
+impl Challenge for Day23 {
+    type Input<'a> = Vec<usize>;
+
+    fn parse<'a>(&self, cups_text: &'a str) -> Result<Self::Input<'a>, Error> {
+        FORMAT.validate(DAY, cups_text)?;
+
+        parse_cups(cups_text).map_err(|(column, message)| Error::parse(DAY, 1, column, message))
+    }
+
+    fn first_part(&self, cups: &Self::Input<'_>) -> Result<Answer, Error> {
+        Ok(Answer::list(count_1_based_label_after(cups, 100)?, ""))
+    }
+
+    fn second_part(&self, cups: &Self::Input<'_>) -> Result<Answer, Error> {
+        Ok(count_product_of_two_labels_after_1(cups)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn labels(text: &str) -> Vec<usize> {
+        text.chars()
+            .map(|c| c.to_digit(10).unwrap() as usize)
+            .collect()
+    }
+
+    #[test]
+    fn test_test_1_based_label() {
+        let test_cups_text = "389125467";
+        let test_cups = parse_cups(test_cups_text).unwrap();
+        let test_buffer = build_cups_buffer(&test_cups, test_cups.len());
+
+        assert_eq!(find_1_based_label(&test_buffer), labels("25467389"));
+    }
+
+    #[test]
+    fn test_some_next_moves() {
+        let test_cups_text = "389125467";
+        let test_cups = parse_cups(test_cups_text).unwrap();
+
+        for (moves, label) in &[(1, "54673289"), (2, "32546789"), (3, "34672589")] {
+            assert_eq!(
+                count_1_based_label_after(&test_cups, *moves as usize).unwrap(),
+                labels(label)
+            );
+        }
+    }
+
+    #[test]
+    fn test_first_task() {
+        let test_cups_text = "389125467";
+        let test_cups = parse_cups(test_cups_text).unwrap();
+
+        assert_eq!(
+            count_1_based_label_after(&test_cups, 10).unwrap(),
+            labels("92658374")
+        );
+        assert_eq!(
+            count_1_based_label_after(&test_cups, 100).unwrap(),
+            labels("67384529")
+        );
+    }
+
+    #[test]
+    fn test_second_task() {
+        let test_cups_text = "389125467";
+        let test_cups = parse_cups(test_cups_text).unwrap();
+
+        assert_eq!(
+            count_product_of_two_labels_after_1(&test_cups).unwrap(),
+            149245887792
+        );
+    }
+
+    #[test]
+    fn test_cups_must_be_labeled_in_order() {
+        assert_eq!(parse_cups("12345"), Ok(vec![1, 2, 3, 4, 5]));
+        assert_eq!(parse_cups("12345\n"), Ok(vec![1, 2, 3, 4, 5]));
+        assert!(parse_cups("12346").is_err());
+        assert!(parse_cups("12344").is_err());
+        assert!(parse_cups("1234").is_err());
+        assert!(parse_cups("").is_err());
+    }
+
+    /// Cups after cup 1 when cups are moved around in a plain vector.
+    fn brute_force_labels(cups: &[usize], size: usize, moves: usize) -> Vec<usize> {
+        let mut circle: Vec<usize> = cups.iter().copied().chain(cups.len() + 1..=size).collect();
+        let mut current = 0;
+
+        for _ in 0..moves {
+            let label = circle[current];
+            let picked: Vec<usize> = (1..=3).map(|i| circle[(current + i) % size]).collect();
+            circle.retain(|cup| !picked.contains(cup));
+
+            let mut destination = label;
+            loop {
+                destination = if destination == 1 {
+                    size
+                } else {
+                    destination - 1
+                };
+                if !picked.contains(&destination) {
+                    break;
+                }
+            }
+
+            let idx = circle.iter().position(|&cup| cup == destination).unwrap();
+            circle.splice(idx + 1..idx + 1, picked);
+            current = (circle.iter().position(|&cup| cup == label).unwrap() + 1) % size;
+        }
+
+        let one = circle.iter().position(|&cup| cup == 1).unwrap();
+        (1..size).map(|i| circle[(one + i) % size]).collect()
+    }
+
+    /// Labeled cups in a random order, the total number of cups and moves.
+    fn game() -> impl Strategy<Value = (Vec<usize>, usize, usize)> {
+        (5..=9_usize).prop_flat_map(|len| {
+            (
+                Just((1..=len).collect::<Vec<_>>()).prop_shuffle(),
+                len..=20,
+                0..100_usize,
+            )
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_linked_cups_match_brute_force((cups, size, moves) in game()) {
+            let mut buffer = build_cups_buffer(&cups, size);
+            simulate_game(cups[0], &mut buffer, moves).unwrap();
+
+            prop_assert_eq!(find_1_based_label(&buffer), brute_force_labels(&cups, size, moves));
+        }
+    }
+}
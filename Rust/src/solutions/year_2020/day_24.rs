@@ -0,0 +1,407 @@
+use super::cancel::Steps;
+use super::format::{InputFormat, Pattern};
+use super::generator::{Generator, Rng};
+use super::registry::Example;
+use super::solution::{Answer, Challenge, Error};
+use num_complex::Complex;
+use std::collections::{HashMap, HashSet};
+
+const DAY: u8 = 24;
+
+/// Coordinates are wide enough for any number of steps in an input.
+type Point = Complex<i64>;
+
+static OFFSETS: [Point; 6] = [
+    Point::new(-2, 0),
+    Point::new(2, 0),
+    Point::new(-1, -3),
+    Point::new(-1, 3),
+    Point::new(1, -3),
+    Point::new(1, 3),
+];
+
+/// Position after following the instructions, or the 1-based column of the
+/// problem and its description.
+fn result_position(instructions: &str) -> Result<Point, (usize, String)> {
+    let mut position = Point::new(0, 0);
+    let mut chars = instructions.chars().enumerate();
+
+    loop {
+        match chars.next() {
+            Some((_, 'e')) => {
+                position += Point::new(-2, 0);
+            }
+            Some((_, 'w')) => {
+                position += Point::new(2, 0);
+            }
+            Some((idx, 'n')) => match chars.next() {
+                Some((_, 'e')) => position += Point::new(-1, 3),
+                Some((_, 'w')) => position += Point::new(1, 3),
+                _ => return Err((idx + 1, String::from("expected \"ne\" or \"nw\""))),
+            },
+            Some((idx, 's')) => match chars.next() {
+                Some((_, 'e')) => position += Point::new(-1, -3),
+                Some((_, 'w')) => position += Point::new(1, -3),
+                _ => return Err((idx + 1, String::from("expected \"se\" or \"sw\""))),
+            },
+            Some((idx, n)) => return Err((idx + 1, format!("unsupported instruction '{}'", n))),
+
+            None => return Ok(position),
+        };
+    }
+}
+
+#[derive(Clone)]
+pub struct TileFloor {
+    floor: HashSet<Point>,
+    neighbours: HashMap<Point, usize>,
+    buffer: HashSet<Point>,
+    buffer_neighbours: HashMap<Point, usize>,
+}
+
+impl TileFloor {
+    fn from_instructions(instructions_text: &str) -> Result<TileFloor, Error> {
+        let positions = instructions_text
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| {
+                result_position(line)
+                    .map_err(|(column, message)| Error::parse(DAY, idx + 1, column, message))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let floor = positions
+            .into_iter()
+            .fold(&mut HashMap::new(), |acc, el| {
+                *acc.entry(el).or_insert(0) += 1;
+                acc
+            })
+            .iter()
+            .filter(|(_, &c)| c % 2 == 1)
+            .map(|(p, _)| p)
+            .copied()
+            .collect::<HashSet<_>>();
+
+        let mut neighbours: HashMap<Point, usize> = HashMap::new();
+        for &point in floor.iter() {
+            for offset in OFFSETS.iter() {
+                *neighbours.entry(point + offset).or_insert(0) += 1;
+            }
+        }
+
+        Ok(TileFloor {
+            floor,
+            neighbours,
+            buffer: HashSet::new(),
+            buffer_neighbours: HashMap::new(),
+        })
+    }
+
+    fn black_tiles(&self) -> usize {
+        self.floor.len()
+    }
+
+    /// Black tiles after the day. Every tile next to a black one is a step
+    /// of `steps`.
+    fn simulate_day(&mut self, steps: &mut Steps) -> Result<usize, Error> {
+        let mut black_tiles = 0;
+        self.buffer.clear();
+        self.buffer_neighbours.clear();
+
+        for (&point, &active_neighbours) in self.neighbours.iter() {
+            steps.step()?;
+
+            let is_black = self.floor.contains(&point);
+            if (is_black && active_neighbours == 1) || (active_neighbours == 2) {
+                black_tiles += 1;
+
+                self.buffer.insert(point);
+                for offset in OFFSETS.iter() {
+                    *self.buffer_neighbours.entry(point + offset).or_insert(0) += 1;
+                }
+            }
+        }
+
+        std::mem::swap(&mut self.floor, &mut self.buffer);
+        std::mem::swap(&mut self.neighbours, &mut self.buffer_neighbours);
+
+        Ok(black_tiles)
+    }
+}
+
+fn black_tiles_after_n_days(tile_floor: &mut TileFloor, days: usize) -> Result<usize, Error> {
+    let mut steps = Steps::new(DAY);
+    (0..days).try_fold(tile_floor.black_tiles(), |_, _| {
+        tile_floor.simulate_day(&mut steps)
+    })
+}
+
+/// Paths of random steps. Paths are short, so many of them end at the
+/// same tile.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    const DIRECTIONS: [&str; 6] = ["e", "se", "sw", "w", "nw", "ne"];
+
+    (0..size)
+        .map(|_| {
+            let steps = rng.between(5, 25);
+            (0..steps)
+                .map(|_| *rng.pick(&DIRECTIONS))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub struct Day24 {}
+
+const EXAMPLES: &[Example] = &[Example {
+    input: "sesenwnenenewseeswwswswwnenewsewsw
+neeenesenwnwwswnenewnwwsewnenwseswesw
+seswneswswsenwwnwse
+nwnwneseeswswnenewneswwnewseswneseene
+swweswneswnenwsewnwneneseenw
+eesenwseswswnenwswnwnwsewwnwsene
+sewnenenenesenwsewnenwwwse
+wenwwweseeeweswwwnwwe
+wsweesenenewnwwnwsenewsenwwsesesenwne
+neeswseenwwswnwswswnw
+nenwswwsewswnenenewsenwsenwnesesenew
+enewnwewneswsewnwswenweswnenwsenwsw
+sweneswneswneneenwnewenewwneswswnese
+swwesenesewenwneswnwwneseswwne
+enesenwswwswneneswsenwnewswseenwsese
+wnwnesenesenenwwnenwsewesewsesesew
+nenewswnwewswnenesenwnesewesw
+eneswnwswnwsenenwnwnwwseeswneewsenese
+neswnwewnwnwseenwseesewsenwsweewe
+wseweeenwnesenwwwswnew",
+    first: Some("10"),
+    second: Some("2208"),
+}];
+
+const FORMAT: InputFormat = InputFormat::Lines(Pattern::Chars("nsew"));
+
+const GENERATOR: Generator = Generator {
+    size: "number of tiles to flip",
+    sizes: 1..=100_000,
+    default_size: 500,
+    generate,
+};
+
+register! {
+    day: DAY,
+    title: "Lobby Layout",
+    tags: [Simulation, Parsing],
+    solution: Day24,
+    examples: EXAMPLES,
+    format: FORMAT,
+    generator: GENERATOR,
+}
+
+impl Challenge for Day24 {
+    type Input<'a> = TileFloor;
+
+    fn parse<'a>(&self, instructions_text: &'a str) -> Result<Self::Input<'a>, Error> {
+        FORMAT.validate(DAY, instructions_text)?;
+
+        TileFloor::from_instructions(instructions_text)
+    }
+
+    fn first_part(&self, tile_floor: &Self::Input<'_>) -> Result<Answer, Error> {
+        Ok(tile_floor.black_tiles().into())
+    }
+
+    fn second_part(&self, tile_floor: &Self::Input<'_>) -> Result<Answer, Error> {
+        Ok(black_tiles_after_n_days(&mut tile_floor.clone(), 100)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_result_position() {
+        assert_eq!(result_position("nwwswee"), Ok(Point::new(0, 0)));
+        assert_eq!(
+            result_position("esen"),
+            Err((4, String::from("expected \"ne\" or \"nw\"")))
+        );
+        assert_eq!(
+            result_position("sn"),
+            Err((1, String::from("expected \"se\" or \"sw\"")))
+        );
+    }
+
+    #[test]
+    fn test_first_task() {
+        let test_instructions = "sesenwnenenewseeswwswswwnenewsewsw
+neeenesenwnwwswnenewnwwsewnenwseswesw
+seswneswswsenwwnwse
+nwnwneseeswswnenewneswwnewseswneseene
+swweswneswnenwsewnwneneseenw
+eesenwseswswnenwswnwnwsewwnwsene
+sewnenenenesenwsewnenwwwse
+wenwwweseeeweswwwnwwe
+wsweesenenewnwwnwsenewsenwwsesesenwne
+neeswseenwwswnwswswnw
+nenwswwsewswnenenewsenwsenwnesesenew
+enewnwewneswsewnwswenweswnenwsenwsw
+sweneswneswneneenwnewenewwneswswnese
+swwesenesewenwneswnwwneseswwne
+enesenwswwswneneswsenwnewswseenwsese
+wnwnesenesenenwwnenwsewesewsesesew
+nenewswnwewswnenesenwnesewesw
+eneswnwswnwsenenwnwnwwseeswneewsenese
+neswnwewnwnwseenwseesewsenwsweewe
+wseweeenwnesenwwwswnew";
+
+        assert_eq!(
+            TileFloor::from_instructions(test_instructions)
+                .unwrap()
+                .black_tiles(),
+            10
+        );
+    }
+
+    #[test]
+    fn test_simulate_day() {
+        let test_instructions = "sesenwnenenewseeswwswswwnenewsewsw
+neeenesenwnwwswnenewnwwsewnenwseswesw
+seswneswswsenwwnwse
+nwnwneseeswswnenewneswwnewseswneseene
+swweswneswnenwsewnwneneseenw
+eesenwseswswnenwswnwnwsewwnwsene
+sewnenenenesenwsewnenwwwse
+wenwwweseeeweswwwnwwe
+wsweesenenewnwwnwsenewsenwwsesesenwne
+neeswseenwwswnwswswnw
+nenwswwsewswnenenewsenwsenwnesesenew
+enewnwewneswsewnwswenweswnenwsenwsw
+sweneswneswneneenwnewenewwneswswnese
+swwesenesewenwneswnwwneseswwne
+enesenwswwswneneswsenwnewswseenwsese
+wnwnesenesenenwwnenwsewesewsesesew
+nenewswnwewswnenesenwnesewesw
+eneswnwswnwsenenwnwnwwseeswneewsenese
+neswnwewnwnwseenwseesewsenwsweewe
+wseweeenwnesenwwwswnew";
+
+        let mut test_floor = TileFloor::from_instructions(test_instructions).unwrap();
+
+        let mut steps = Steps::new(DAY);
+        for &black_tiles in &[15, 12, 25, 14, 23, 28, 41, 37, 49, 37] {
+            assert_eq!(
+                test_floor.simulate_day(&mut steps).unwrap(),
+                black_tiles as usize
+            );
+        }
+    }
+
+    #[test]
+    fn test_second_task() {
+        let test_instructions = "sesenwnenenewseeswwswswwnenewsewsw
+neeenesenwnwwswnenewnwwsewnenwseswesw
+seswneswswsenwwnwse
+nwnwneseeswswnenewneswwnewseswneseene
+swweswneswnenwsewnwneneseenw
+eesenwseswswnenwswnwnwsewwnwsene
+sewnenenenesenwsewnenwwwse
+wenwwweseeeweswwwnwwe
+wsweesenenewnwwnwsenewsenwwsesesenwne
+neeswseenwwswnwswswnw
+nenwswwsewswnenenewsenwsenwnesesenew
+enewnwewneswsewnwswenweswnenwsenwsw
+sweneswneswneneenwnewenewwneswswnese
+swwesenesewenwneswnwwneseswwne
+enesenwswwswneneswsenwnewswseenwsese
+wnwnesenesenenwwnenwsewesewsesesew
+nenewswnwewswnenesenwnesewesw
+eneswnwswnwsenenwnwnwwseeswneewsenese
+neswnwewnwnwseenwseesewsenwsweewe
+wseweeenwnesenwwwswnew";
+
+        let mut test_floor = TileFloor::from_instructions(test_instructions).unwrap();
+
+        assert_eq!(
+            black_tiles_after_n_days(&mut test_floor, 100).unwrap(),
+            2208
+        );
+    }
+
+    const DIRECTIONS: [(&str, (i32, i32)); 6] = [
+        ("e", (1, 0)),
+        ("w", (-1, 0)),
+        ("ne", (1, -1)),
+        ("nw", (0, -1)),
+        ("se", (0, 1)),
+        ("sw", (-1, 1)),
+    ];
+
+    /// Black tiles at the start and after each day, in axial coordinates
+    /// and counting neighbours of every tile next to a black one from
+    /// scratch.
+    fn brute_force_black_tiles(instructions_text: &str, days: usize) -> Vec<usize> {
+        let mut black: HashSet<(i32, i32)> = HashSet::new();
+        for line in instructions_text.lines() {
+            let (mut tile, mut rest) = ((0, 0), line);
+            while !rest.is_empty() {
+                let &(name, (dq, dr)) = DIRECTIONS
+                    .iter()
+                    .find(|(name, _)| rest.starts_with(name))
+                    .unwrap();
+                tile = (tile.0 + dq, tile.1 + dr);
+                rest = &rest[name.len()..];
+            }
+
+            if !black.remove(&tile) {
+                black.insert(tile);
+            }
+        }
+
+        let neighbours =
+            |(q, r): (i32, i32)| DIRECTIONS.iter().map(move |(_, (dq, dr))| (q + dq, r + dr));
+
+        let mut counts = vec![black.len()];
+        for _ in 0..days {
+            let candidates: HashSet<(i32, i32)> =
+                black.iter().flat_map(|&tile| neighbours(tile)).collect();
+
+            black = candidates
+                .into_iter()
+                .filter(|&tile| {
+                    let around = neighbours(tile)
+                        .filter(|other| black.contains(other))
+                        .count();
+                    around == 2 || (around == 1 && black.contains(&tile))
+                })
+                .collect();
+            counts.push(black.len());
+        }
+
+        counts
+    }
+
+    fn instructions() -> impl Strategy<Value = String> {
+        let direction =
+            prop::sample::select(DIRECTIONS.iter().map(|(name, _)| *name).collect::<Vec<_>>());
+        let path = prop::collection::vec(direction, 1..8).prop_map(|path| path.concat());
+
+        prop::collection::vec(path, 1..20).prop_map(|paths| paths.join("\n"))
+    }
+
+    proptest! {
+        #[test]
+        fn test_neighbour_counts_match_brute_force(
+            instructions_text in instructions(),
+            days in 0..10_usize
+        ) {
+            let mut floor = TileFloor::from_instructions(&instructions_text).unwrap();
+            let mut counts = vec![floor.black_tiles()];
+            let mut steps = Steps::new(DAY);
+            counts.extend((0..days).map(|_| floor.simulate_day(&mut steps).unwrap()));
+
+            prop_assert_eq!(counts, brute_force_black_tiles(&instructions_text, days));
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use super::cancel::Steps;
+use super::format::{InputFormat, Pattern};
+use super::generator::{Generator, Rng};
+use super::registry::Example;
+use super::solution::{Answer, Challenge, Error};
+use itertools::Itertools;
+
+const DAY: u8 = 25;
+
+const MODULO: u64 = 20201227;
+
+fn parse_public_keys(keys_text: &str) -> Result<(u64, u64), Error> {
+    let keys = keys_text
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| match line.parse() {
+            Ok(key) if key < MODULO => Ok(key),
+            _ => Err(Error::parse(
+                DAY,
+                idx + 1,
+                1,
+                format!("public key must be less than {}, got {}", MODULO, line),
+            )),
+        })
+        .collect::<Result<Vec<u64>, _>>()?;
+    let line = keys.len() + 1;
+
+    keys.into_iter()
+        .collect_tuple()
+        .ok_or_else(|| Error::parse(DAY, line, 1, "expected two public keys"))
+}
+
+fn transforms(subject: u64) -> impl Iterator<Item = u64> {
+    itertools::iterate(subject, move |current| current * subject % MODULO)
+}
+
+/// Loop size of the public key, if any loop size gives it. Values repeat
+/// after `MODULO` steps, so the search stops there.
+fn find_loop_size(public_key: u64) -> Result<Option<usize>, Error> {
+    let mut steps = Steps::new(DAY);
+    for (loop_size, value) in transforms(7).take(MODULO as usize).enumerate() {
+        if value == public_key {
+            return Ok(Some(loop_size));
+        }
+        steps.step()?;
+    }
+
+    Ok(None)
+}
+
+fn find_encryption_key(door_public_key: u64, card_public_key: u64) -> Result<Option<u64>, Error> {
+    let key = find_loop_size(door_public_key)?
+        .and_then(|loop_size| transforms(card_public_key).nth(loop_size));
+
+    Ok(key)
+}
+
+fn transform(subject: u64, loop_size: u64) -> u64 {
+    let (mut value, mut power, mut loop_size) = (1, subject, loop_size);
+
+    while loop_size > 0 {
+        if loop_size % 2 == 1 {
+            value = value * power % MODULO;
+        }
+        power = power * power % MODULO;
+        loop_size /= 2;
+    }
+
+    value
+}
+
+/// Public keys of the card and the door with loop sizes up to the size.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let card = transform(7, rng.between(1, size as u64));
+    let door = transform(7, rng.between(1, size as u64));
+
+    format!("{}\n{}", card, door)
+}
+
+pub struct Day25 {}
+
+const EXAMPLES: &[Example] = &[Example {
+    input: "5764801
+17807724",
+    first: Some("14897079"),
+    second: None,
+}];
+
+const FORMAT: InputFormat =
+    InputFormat::Header(Pattern::Number, &InputFormat::Line(Pattern::Number));
+
+const GENERATOR: Generator = Generator {
+    size: "largest loop size",
+    sizes: 1..=20_201_226,
+    default_size: 10_000_000,
+    generate,
+};
+
+register! {
+    day: DAY,
+    title: "Combo Breaker",
+    tags: [NumberTheory],
+    solution: Day25,
+    examples: EXAMPLES,
+    format: FORMAT,
+    generator: GENERATOR,
+}
+
+impl Challenge for Day25 {
+    type Input<'a> = (u64, u64);
+
+    fn parse<'a>(&self, keys_text: &'a str) -> Result<Self::Input<'a>, Error> {
+        FORMAT.validate(DAY, keys_text)?;
+
+        parse_public_keys(keys_text)
+    }
+
+    fn first_part(&self, keys: &Self::Input<'_>) -> Result<Answer, Error> {
+        let &(first_key, second_key) = keys;
+
+        find_encryption_key(first_key, second_key)?
+            .map(|key| key.into())
+            .ok_or_else(|| Error::no_solution(DAY, "no loop size gives the public key"))
+    }
+
+    fn second_part(&self, _: &Self::Input<'_>) -> Result<Answer, Error> {
+        Ok("I did it!!!".into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_loop_size() {
+        assert_eq!(find_loop_size(5764801).unwrap(), Some(7));
+        assert_eq!(find_loop_size(17807724).unwrap(), Some(10));
+    }
+
+    #[test]
+    fn test_first_task() {
+        assert_eq!(
+            find_encryption_key(5764801, 17807724).unwrap(),
+            Some(14897079)
+        );
+    }
+
+    #[test]
+    fn test_public_keys_without_loop_size() {
+        assert_eq!(find_encryption_key(0, 17807724).unwrap(), None);
+        assert!(parse_public_keys("5764801\n20201227").is_err());
+        assert!(parse_public_keys("5764801").is_err());
+    }
+}
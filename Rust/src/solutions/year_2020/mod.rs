@@ -0,0 +1,86 @@
+//! Solutions of [Advent of Code 2020](https://adventofcode.com/2020).
+
+// Days of every year use the shared building blocks of `solutions`
+// through `super`, the same way in every year module.
+use super::{cancel, format, generator, registry, solution};
+
+mod day_01;
+mod day_02;
+mod day_03;
+mod day_04;
+mod day_05;
+mod day_06;
+mod day_07;
+mod day_08;
+mod day_09;
+mod day_10;
+mod day_11;
+mod day_12;
+mod day_13;
+mod day_14;
+mod day_15;
+mod day_16;
+mod day_17;
+mod day_18;
+mod day_19;
+mod day_20;
+mod day_21;
+mod day_22;
+mod day_23;
+mod day_24;
+mod day_25;
+
+days! {
+    year: 2020,
+    day_01,
+    day_02,
+    day_03,
+    day_04,
+    day_05,
+    day_06,
+    day_07,
+    day_08,
+    day_09,
+    day_10,
+    day_11,
+    day_12,
+    day_13,
+    day_14,
+    day_15,
+    day_16,
+    day_17,
+    day_18,
+    day_19,
+    day_20,
+    day_21,
+    day_22,
+    day_23,
+    day_24,
+    day_25,
+}
+
+pub use day_01::Day01;
+pub use day_02::Day02;
+pub use day_03::Day03;
+pub use day_04::Day04;
+pub use day_05::Day05;
+pub use day_06::Day06;
+pub use day_07::Day07;
+pub use day_08::Day08;
+pub use day_09::Day09;
+pub use day_10::Day10;
+pub use day_11::Day11;
+pub use day_12::Day12;
+pub use day_13::Day13;
+pub use day_14::Day14;
+pub use day_15::Day15;
+pub use day_16::Day16;
+pub use day_17::Day17;
+pub use day_18::Day18;
+pub use day_19::Day19;
+pub use day_20::Day20;
+pub use day_21::Day21;
+pub use day_22::Day22;
+pub use day_23::Day23;
+pub use day_24::Day24;
+pub use day_25::Day25;
@@ -0,0 +1,423 @@
+use crate::fetch::Client;
+use crate::solutions::{Error, FIRST_YEAR};
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// File with every submitted answer, looked up in the input directory.
+pub const STORE_FILE: &str = "submissions.json";
+
+/// What the server said about a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Verdict {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// The answer was submitted too soon after the previous one and wasn't
+    /// checked.
+    RateLimited,
+    /// The part was solved before, so the answer wasn't checked.
+    AlreadySolved,
+    Unknown,
+}
+
+impl Verdict {
+    /// Whether the answer is known to be wrong.
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow)
+    }
+}
+
+lazy_static! {
+    static ref ARTICLE: Regex = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap();
+    static ref TAG: Regex = Regex::new(r"<[^>]*>").unwrap();
+    static ref SPACES: Regex = Regex::new(r"\s+").unwrap();
+}
+
+/// Text of the message on the page the server responded with.
+pub fn message(page: &str) -> String {
+    let article = ARTICLE
+        .captures(page)
+        .and_then(|captures| captures.get(1))
+        .map_or(page, |article| article.as_str());
+    let text = TAG.replace_all(article, "");
+
+    SPACES.replace_all(text.trim(), " ").into_owned()
+}
+
+/// Verdict from the message on the page the server responded with.
+pub fn verdict(message: &str) -> Verdict {
+    if message.contains("That's the right answer") {
+        Verdict::Right
+    } else if message.contains("That's not the right answer") {
+        if message.contains("too high") {
+            Verdict::TooHigh
+        } else if message.contains("too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        }
+    } else if message.contains("You gave an answer too recently") {
+        Verdict::RateLimited
+    } else if message.contains("Did you already complete it?") {
+        Verdict::AlreadySolved
+    } else {
+        Verdict::Unknown
+    }
+}
+
+/// Attempts recorded before there were other years are attempts of 2020.
+fn first_year() -> u16 {
+    FIRST_YEAR
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Attempt {
+    #[serde(default = "first_year")]
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the Unix epoch.
+    pub submitted_at: u64,
+}
+
+/// Every answer submitted before, kept in a JSON file.
+pub struct Store {
+    path: PathBuf,
+    pub attempts: Vec<Attempt>,
+}
+
+impl Store {
+    /// Attempts from the `path`. A missing file has no attempts.
+    pub fn load(path: &Path) -> Result<Store, Error> {
+        let attempts = match fs::read_to_string(path) {
+            Ok(text) => serde_json::from_str(&text).map_err(|err| {
+                Error::invalid_argument(format!("malformed {}: {}", path.display(), err))
+            })?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => vec![],
+            Err(err) => {
+                return Err(Error::invalid_argument(format!(
+                    "cannot read {}: {}",
+                    path.display(),
+                    err
+                )))
+            }
+        };
+
+        Ok(Store {
+            path: path.to_path_buf(),
+            attempts,
+        })
+    }
+
+    pub fn save(&self) -> Result<(), Error> {
+        let cannot_write =
+            |err: String| Error::internal(format!("cannot write {}: {}", self.path.display(), err));
+
+        let text = serde_json::to_string_pretty(&self.attempts)
+            .map_err(|err| cannot_write(err.to_string()))?;
+        fs::write(&self.path, text + "\n").map_err(|err| cannot_write(err.to_string()))
+    }
+
+    fn attempts_for(&self, year: u16, day: u8, part: u8) -> impl Iterator<Item = &Attempt> {
+        self.attempts
+            .iter()
+            .filter(move |a| a.year == year && a.day == day && a.part == part)
+    }
+
+    /// Warnings about submitting the `answer` for a part of a day of the
+    /// `year`, or why it must not be submitted: the part is solved already
+    /// or the answer is known to be wrong.
+    pub fn check(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<Vec<String>, Error> {
+        if let Some(right) = self
+            .attempts_for(year, day, part)
+            .find(|a| a.verdict == Verdict::Right)
+        {
+            return Err(Error::invalid_argument(format!(
+                "day {} part {} is solved already, the answer is {}",
+                day, part, right.answer
+            )));
+        }
+
+        if let Some(wrong) = self
+            .attempts_for(year, day, part)
+            .find(|a| a.answer == answer && a.verdict.is_wrong())
+        {
+            return Err(Error::invalid_argument(format!(
+                "answer {} for day {} part {} is known to be wrong ({})",
+                answer,
+                day,
+                part,
+                describe(wrong.verdict)
+            )));
+        }
+
+        let value = match answer.parse::<i128>() {
+            Ok(value) => value,
+            Err(_) => return Ok(vec![]),
+        };
+        let bounds = |verdict| {
+            self.attempts_for(year, day, part)
+                .filter(move |a| a.verdict == verdict)
+                .filter_map(|a| a.answer.parse::<i128>().ok())
+        };
+
+        let mut warnings = vec![];
+        if let Some(high) = bounds(Verdict::TooHigh).min().filter(|&high| value >= high) {
+            warnings.push(format!(
+                "answer {} is not lower than {}, which is too high",
+                value, high
+            ));
+        }
+        if let Some(low) = bounds(Verdict::TooLow).max().filter(|&low| value <= low) {
+            warnings.push(format!(
+                "answer {} is not higher than {}, which is too low",
+                value, low
+            ));
+        }
+
+        Ok(warnings)
+    }
+
+    /// Add the attempt and save the store.
+    pub fn record(&mut self, attempt: Attempt) -> Result<(), Error> {
+        self.attempts.push(attempt);
+        self.save()
+    }
+}
+
+pub fn describe(verdict: Verdict) -> &'static str {
+    match verdict {
+        Verdict::Right => "right",
+        Verdict::Wrong => "wrong",
+        Verdict::TooHigh => "too high",
+        Verdict::TooLow => "too low",
+        Verdict::RateLimited => "rate limited",
+        Verdict::AlreadySolved => "solved already",
+        Verdict::Unknown => "unknown response",
+    }
+}
+
+/// Result of a submitted answer.
+#[derive(Debug, PartialEq)]
+pub struct Outcome {
+    pub verdict: Verdict,
+    /// Message from the server.
+    pub message: String,
+}
+
+/// Submit the `answer` for a day of the `year` unless the `store` knows it's
+/// pointless, and record the attempt. Attempts of every year are kept
+/// apart, even when years share a store. Warnings about the answer are
+/// passed to `warn` before it's submitted.
+pub fn submit(
+    client: &mut Client,
+    store: &mut Store,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+    warn: impl Fn(&str),
+) -> Result<Outcome, Error> {
+    for warning in store.check(year, day, part, answer)? {
+        warn(&warning);
+    }
+
+    let message = message(&client.submit(year, day, part, answer)?);
+    let verdict = verdict(&message);
+    let submitted_at = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs());
+
+    store.record(Attempt {
+        year,
+        day,
+        part,
+        answer: answer.to_string(),
+        verdict,
+        submitted_at,
+    })?;
+
+    Ok(Outcome { verdict, message })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use std::time::Duration;
+    use tiny_http::{Response, Server};
+
+    fn page(message: &str) -> String {
+        format!(
+            "<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>",
+            message
+        )
+    }
+
+    #[test]
+    fn test_verdict_of_responses() {
+        let responses = [
+            ("That's the right answer! You are <em>one gold star</em> closer to saving your vacation. <a href=\"/2020/day/1#part2\">[Continue to Part Two]</a>", Verdict::Right),
+            ("That's not the right answer. If you're stuck, make sure you're using the full input data. <a href=\"/2020/day/1\">[Return to Day 1]</a>", Verdict::Wrong),
+            ("That's not the right answer; your answer is too high. Please wait one minute before trying again.", Verdict::TooHigh),
+            ("That's not the right answer; your answer is too low.", Verdict::TooLow),
+            ("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 42s left to wait.", Verdict::RateLimited),
+            ("You don't seem to be solving the right level.  Did you already complete it?", Verdict::AlreadySolved),
+            ("Something else", Verdict::Unknown),
+        ];
+
+        for (text, expected) in &responses {
+            assert_eq!(verdict(&message(&page(text))), *expected, "{}", text);
+        }
+        assert_eq!(
+            message(&page(
+                "That's the right answer! You are <em>one gold star</em>\n closer."
+            )),
+            "That's the right answer! You are one gold star closer."
+        );
+    }
+
+    /// Server checking answers to day 1 part 1, where the right answer is
+    /// 42. The first answer 7 comes too soon after the previous one. Bodies
+    /// of the requests are recorded.
+    fn stub_server() -> (String, Arc<Mutex<Vec<String>>>) {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let requests = Arc::new(Mutex::new(vec![]));
+
+        let recorded = Arc::clone(&requests);
+        thread::spawn(move || {
+            let mut rate_limited = false;
+            for mut request in server.incoming_requests() {
+                let mut body = String::new();
+                request.as_reader().read_to_string(&mut body).unwrap();
+                recorded.lock().unwrap().push(body.clone());
+
+                let answer: i64 = body.rsplit('=').next().unwrap().parse().unwrap();
+                let message = match answer {
+                    42 => "That's the right answer!",
+                    7 if !rate_limited => {
+                        rate_limited = true;
+                        "You gave an answer too recently. You have 30s left to wait."
+                    }
+                    a if a > 42 => "That's not the right answer; your answer is too high.",
+                    _ => "That's not the right answer; your answer is too low.",
+                };
+                let _ = request.respond(Response::from_string(page(message)));
+            }
+        });
+
+        (base_url, requests)
+    }
+
+    #[test]
+    fn test_submit_and_track_attempts() {
+        let (base_url, requests) = stub_server();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(STORE_FILE);
+        let mut client = Client::new(&base_url, "secret", Duration::default());
+        let mut store = Store::load(&path).unwrap();
+        let warnings = RefCell::new(vec![]);
+        let warn = |warning: &str| warnings.borrow_mut().push(warning.to_string());
+
+        let mut attempt = |answer| submit(&mut client, &mut store, 2020, 1, 1, answer, warn);
+
+        assert_eq!(attempt("100").unwrap().verdict, Verdict::TooHigh);
+        assert_eq!(attempt("7").unwrap().verdict, Verdict::RateLimited);
+        assert_eq!(attempt("7").unwrap().verdict, Verdict::TooLow);
+        assert!(attempt("100")
+            .unwrap_err()
+            .to_string()
+            .contains("known to be wrong (too high)"));
+        assert_eq!(attempt("120").unwrap().verdict, Verdict::TooHigh);
+        assert_eq!(attempt("42").unwrap().verdict, Verdict::Right);
+        assert!(attempt("43")
+            .unwrap_err()
+            .to_string()
+            .contains("solved already, the answer is 42"));
+
+        assert_eq!(
+            *warnings.borrow(),
+            vec!["answer 120 is not lower than 100, which is too high"]
+        );
+        assert_eq!(
+            *requests.lock().unwrap(),
+            vec![
+                "level=1&answer=100",
+                "level=1&answer=7",
+                "level=1&answer=7",
+                "level=1&answer=120",
+                "level=1&answer=42"
+            ]
+        );
+
+        let verdicts: Vec<Verdict> = Store::load(&path)
+            .unwrap()
+            .attempts
+            .iter()
+            .map(|a| a.verdict)
+            .collect();
+        assert_eq!(
+            verdicts,
+            vec![
+                Verdict::TooHigh,
+                Verdict::RateLimited,
+                Verdict::TooLow,
+                Verdict::TooHigh,
+                Verdict::Right
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bounds_apply_to_their_part_only() {
+        let attempt = |part, answer: &str, verdict| Attempt {
+            year: 2020,
+            day: 9,
+            part,
+            answer: answer.to_string(),
+            verdict,
+            submitted_at: 0,
+        };
+        let store = Store {
+            path: PathBuf::from("unused.json"),
+            attempts: vec![
+                attempt(1, "10", Verdict::TooLow),
+                attempt(1, "20", Verdict::TooHigh),
+                attempt(2, "5", Verdict::Wrong),
+            ],
+        };
+
+        assert_eq!(store.check(2020, 9, 1, "15").unwrap(), Vec::<String>::new());
+        assert_eq!(
+            store.check(2020, 9, 1, "8").unwrap(),
+            vec!["answer 8 is not higher than 10, which is too low"]
+        );
+        assert_eq!(store.check(2020, 9, 2, "25").unwrap(), Vec::<String>::new());
+        assert!(store.check(2020, 9, 2, "5").is_err());
+        assert!(store.check(2020, 9, 1, "abc").unwrap().is_empty());
+
+        assert!(store.check(2021, 9, 2, "5").is_ok());
+        assert!(store.check(2021, 9, 1, "8").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_attempts_without_year_are_of_2020() {
+        let attempts: Vec<Attempt> = serde_json::from_str(
+            r#"[{"day": 1, "part": 1, "answer": "42", "verdict": "right", "submitted_at": 0}]"#,
+        )
+        .unwrap();
+
+        assert_eq!(attempts[0].year, 2020);
+    }
+}
@@ -0,0 +1,193 @@
+use crate::output;
+use crate::runner::{DayReport, PartReport};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+
+/// How often the watched file is checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Changes closer to each other than this are handled as one change, so an
+/// editor saving a file in several writes re-runs the day once.
+pub const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// What is known about a file without reading it. A missing file has no
+/// stamp.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Stamp {
+    modified: Option<SystemTime>,
+    len: u64,
+}
+
+fn stamp(path: &Path) -> Option<Stamp> {
+    let metadata = fs::metadata(path).ok()?;
+
+    Some(Stamp {
+        modified: metadata.modified().ok(),
+        len: metadata.len(),
+    })
+}
+
+/// Watches a file by polling its modification time and length.
+pub struct Watcher {
+    path: PathBuf,
+    debounce: Duration,
+    last: Option<Stamp>,
+    /// When the latest change not reported yet was seen.
+    changed_at: Option<Instant>,
+}
+
+impl Watcher {
+    pub fn new(path: &Path, debounce: Duration) -> Watcher {
+        Watcher {
+            path: path.to_path_buf(),
+            debounce,
+            last: stamp(path),
+            changed_at: None,
+        }
+    }
+
+    /// Whether the file changed and then stayed the same for the debounce
+    /// time by `now`. Every change is reported once.
+    pub fn poll(&mut self, now: Instant) -> bool {
+        let current = stamp(&self.path);
+        if current != self.last {
+            self.last = current;
+            self.changed_at = Some(now);
+
+            return false;
+        }
+
+        match self.changed_at {
+            Some(changed_at) if now.duration_since(changed_at) >= self.debounce => {
+                self.changed_at = None;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Block until the file changes.
+    pub fn wait_for_change(&mut self) {
+        while !self.poll(Instant::now()) {
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+}
+
+fn describe_answer(part: &PartReport) -> String {
+    match &part.answer {
+        Ok(answer) => answer.to_string(),
+        Err(err) => format!("FAILED {}", output::describe(err)),
+    }
+}
+
+/// Lines with the answers and timings of the `report`. Every answer is
+/// compared with the one from the `previous` report of the same day.
+pub fn describe_changes(previous: Option<&DayReport>, report: &DayReport) -> Vec<String> {
+    let mut lines = vec![match previous {
+        Some(previous) => format!(
+            "Day {} input parsed in {:.2?} (was {:.2?})",
+            report.day, report.parse, previous.parse
+        ),
+        None => format!("Day {} input parsed in {:.2?}", report.day, report.parse),
+    }];
+
+    for part in &report.parts {
+        let answer = describe_answer(part);
+        let before = previous.and_then(|p| p.parts.iter().find(|b| b.part == part.part));
+
+        lines.push(match before {
+            Some(before) => {
+                let before_answer = describe_answer(before);
+                let answer = if before_answer == answer {
+                    format!("{} (unchanged)", answer)
+                } else {
+                    format!("{} -> {}", before_answer, answer)
+                };
+
+                format!(
+                    "Day {}-{}: {} (solved in {:.2?}, was {:.2?})",
+                    report.day, part.part, answer, part.duration, before.duration
+                )
+            }
+            None => format!(
+                "Day {}-{}: {} (solved in {:.2?})",
+                report.day, part.part, answer, part.duration
+            ),
+        });
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::Error;
+
+    fn report(answers: &[Result<u64, Error>]) -> DayReport {
+        DayReport {
+            day: 17,
+            read: Duration::default(),
+            parse: Duration::from_millis(1),
+            parts: answers
+                .iter()
+                .enumerate()
+                .map(|(idx, answer)| PartReport {
+                    part: idx as u8 + 1,
+                    answer: answer.clone().map(|answer| answer.into()),
+                    duration: Duration::from_millis(2),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_describe_changes() {
+        let first = report(&[Ok(271), Ok(2064)]);
+        let second = report(&[Ok(271), Err(Error::no_solution(17, "no cubes"))]);
+
+        assert_eq!(
+            describe_changes(None, &first),
+            vec![
+                "Day 17 input parsed in 1.00ms",
+                "Day 17-1: 271 (solved in 2.00ms)",
+                "Day 17-2: 2064 (solved in 2.00ms)",
+            ]
+        );
+        assert_eq!(
+            describe_changes(Some(&first), &second),
+            vec![
+                "Day 17 input parsed in 1.00ms (was 1.00ms)",
+                "Day 17-1: 271 (unchanged) (solved in 2.00ms, was 2.00ms)",
+                "Day 17-2: 2064 -> FAILED day 17: no solution found: no cubes (solved in 2.00ms, was 2.00ms)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rapid_changes_are_reported_once() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("day-17.input");
+        fs::write(&file, "#").unwrap();
+
+        let start = Instant::now();
+        let at = |millis| start + Duration::from_millis(millis);
+        let mut watcher = Watcher::new(&file, Duration::from_millis(300));
+        assert!(!watcher.poll(at(0)));
+
+        fs::write(&file, "#.").unwrap();
+        assert!(!watcher.poll(at(10)));
+        fs::write(&file, "#..").unwrap();
+        assert!(!watcher.poll(at(100)));
+        assert!(!watcher.poll(at(300)));
+        assert!(watcher.poll(at(400)));
+        assert!(!watcher.poll(at(1000)));
+
+        fs::remove_file(&file).unwrap();
+        assert!(!watcher.poll(at(1100)));
+        assert!(watcher.poll(at(1400)));
+    }
+}